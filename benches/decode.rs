@@ -26,8 +26,7 @@ macro_rules! group_decode {
         });
         group.bench_function("decode_bsx", |b| {
             b.iter(|| {
-                bsx::decode($encoded)
-                    .with_alphabet(bsx::Alphabet::BITCOIN)
+                bsx::decode_with_alphabet($encoded, bsx::StaticAlphabet::BITCOIN)
                     .into_vec()
                     .unwrap()
             })
@@ -35,8 +34,7 @@ macro_rules! group_decode {
         group.bench_function("decode_bsx_noalloc_slice", |b| {
             let mut output = [0; $decoded_length];
             b.iter(|| {
-                bsx::decode($encoded)
-                    .with_alphabet(bsx::Alphabet::BITCOIN)
+                bsx::decode_with_alphabet($encoded, bsx::StaticAlphabet::BITCOIN)
                     .into(&mut output[..])
                     .unwrap()
             });
@@ -44,8 +42,7 @@ macro_rules! group_decode {
         group.bench_function("decode_bsx_noalloc_array", |b| {
             let mut output = [0; $decoded_length];
             b.iter(|| {
-                bsx::decode($encoded)
-                    .with_alphabet(bsx::Alphabet::BITCOIN)
+                bsx::decode_with_alphabet($encoded, bsx::StaticAlphabet::BITCOIN)
                     .into(&mut output)
                     .unwrap()
             });
@@ -76,8 +73,7 @@ macro_rules! group_decode_long {
         });
         group.bench_function("decode_bsx", |b| {
             b.iter(|| {
-                bsx::decode($encoded)
-                    .with_alphabet(bsx::Alphabet::BITCOIN)
+                bsx::decode_with_alphabet($encoded, bsx::StaticAlphabet::BITCOIN)
                     .into_vec()
                     .unwrap()
             })
@@ -85,8 +81,7 @@ macro_rules! group_decode_long {
         group.bench_function("decode_bsx_noalloc_slice", |b| {
             let mut output = [0; $decoded_length];
             b.iter(|| {
-                bsx::decode($encoded)
-                    .with_alphabet(bsx::Alphabet::BITCOIN)
+                bsx::decode_with_alphabet($encoded, bsx::StaticAlphabet::BITCOIN)
                     .into(&mut output[..])
                     .unwrap()
             });
@@ -94,8 +89,7 @@ macro_rules! group_decode_long {
         group.bench_function("decode_bsx_noalloc_array", |b| {
             let mut output = [0; $decoded_length];
             b.iter(|| {
-                bsx::decode($encoded)
-                    .with_alphabet(bsx::Alphabet::BITCOIN)
+                bsx::decode_with_alphabet($encoded, bsx::StaticAlphabet::BITCOIN)
                     .into(&mut output)
                     .unwrap()
             });
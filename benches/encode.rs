@@ -1,5 +1,9 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 
+const BITCOIN: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE36: &str = "0123456789abcdefghijklmnopqrstuvwxyz";
+const BASE62: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
 macro_rules! group_encode {
     ($criterion:ident, $name:expr, $decoded:expr => $encoded:expr) => {{
         let mut group = $criterion.benchmark_group($name);
@@ -20,18 +24,51 @@ macro_rules! group_encode {
             let mut output = String::with_capacity($encoded.len());
             b.iter(|| bs58::encode($decoded).into(&mut output));
         });
+        group.bench_function("encode_base_x", |b| {
+            let temp = $decoded;
+            b.iter(|| base_x::encode(BITCOIN, &temp))
+        });
         group.bench_function("encode_bsx", |b| {
             b.iter(|| {
-                bsx::encode($decoded)
-                    .with_alphabet(bsx::Alphabet::BITCOIN)
-                    .into_string()
+                bsx::encode_with_alphabet($decoded, bsx::StaticAlphabet::BITCOIN).into_string()
             })
         });
         group.bench_function("encode_bsx_noalloc", |b| {
             let mut output = String::with_capacity($encoded.len());
             b.iter(|| {
-                bsx::encode($decoded)
-                    .with_alphabet(bsx::Alphabet::BITCOIN)
+                bsx::encode_with_alphabet($decoded, bsx::StaticAlphabet::BITCOIN).into(&mut output)
+            });
+        });
+        group.finish();
+    }};
+}
+
+macro_rules! group_encode_base {
+    ($criterion:ident, $name:expr, $alphabet:expr, $bsx_alphabet:expr, $len:expr) => {{
+        let mut group = $criterion.benchmark_group($name);
+        let decoded: Vec<u8> = (0..$len).map(|i| (i * 7919 % 251) as u8).collect();
+        let encoded = base_x::encode($alphabet, &decoded);
+        assert_eq!(
+            encoded,
+            bsx::encode(&decoded)
+                .with_alphabet($bsx_alphabet)
+                .into_string()
+        );
+        group.bench_function("encode_base_x", |b| {
+            b.iter(|| base_x::encode($alphabet, &decoded))
+        });
+        group.bench_function("encode_bsx", |b| {
+            b.iter(|| {
+                bsx::encode(&decoded)
+                    .with_alphabet($bsx_alphabet)
+                    .into_string()
+            })
+        });
+        group.bench_function("encode_bsx_noalloc", |b| {
+            let mut output = String::with_capacity(encoded.len());
+            b.iter(|| {
+                bsx::encode(&decoded)
+                    .with_alphabet($bsx_alphabet)
                     .into(&mut output)
             });
         });
@@ -86,5 +123,24 @@ fn bench_encode(c: &mut Criterion) {
     );
 }
 
-criterion_group!(benches, bench_encode);
+fn bench_encode_base(c: &mut Criterion) {
+    for &len in &[10, 32, 256] {
+        group_encode_base!(
+            c,
+            format!("base36_{}_bytes", len),
+            BASE36,
+            bsx::StaticAlphabet::BASE36,
+            len
+        );
+        group_encode_base!(
+            c,
+            format!("base62_{}_bytes", len),
+            BASE62,
+            bsx::StaticAlphabet::BASE62,
+            len
+        );
+    }
+}
+
+criterion_group!(benches, bench_encode, bench_encode_base);
 criterion_main!(benches);
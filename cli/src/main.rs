@@ -1,86 +1,169 @@
-use anyhow::anyhow;
+use anyhow::Context;
+use sha2::{Digest, Sha256};
 use std::{
+    fs,
     io::{self, Read, Write},
-    str::FromStr,
+    path::{Path, PathBuf},
 };
 use structopt::StructOpt;
 
-#[derive(Debug)]
-enum Alphabet {
-    Bitcoin,
-    Monero,
-    Ripple,
-    Flickr,
-    Custom(bsx::DynamicAlphabet<Vec<u8>>),
+macro_rules! alphabet_help {
+    ($help:literal) => {
+        concat!(
+            $help,
+            " [possible values: bitcoin, monero, ripple, flickr, crockford, ",
+            "zbase32, base32hex, geohash, bech32, base16 (hex), base16upper (HEX), ",
+            "base36, base45, base62, base64, base64url, ascii85, z85, base91 or ",
+            "custom(abc...xyz)]",
+        )
+    };
 }
 
-impl Alphabet {
-    fn as_alphabet(&self) -> &dyn bsx::Alphabet {
-        match self {
-            Alphabet::Bitcoin => bsx::Alphabet::BITCOIN,
-            Alphabet::Monero => bsx::Alphabet::MONERO,
-            Alphabet::Ripple => bsx::Alphabet::RIPPLE,
-            Alphabet::Flickr => bsx::Alphabet::FLICKR,
-            Alphabet::Custom(custom) => custom,
-        }
-    }
+#[derive(Debug, StructOpt)]
+#[structopt(name = "bsx", global_setting = structopt::clap::AppSettings::ColoredHelp)]
+/// A utility for encoding/decoding arbitrary base encoded data.
+struct Args {
+    /// Read input from FILE instead of stdin
+    #[structopt(
+        long,
+        short = "i",
+        value_name = "FILE",
+        global = true,
+        parse(from_os_str)
+    )]
+    input: Option<PathBuf>,
+
+    /// Write output to FILE instead of stdout
+    #[structopt(
+        long,
+        short = "o",
+        value_name = "FILE",
+        global = true,
+        parse(from_os_str)
+    )]
+    output: Option<PathBuf>,
+
+    #[structopt(subcommand)]
+    command: Command,
 }
 
-impl FromStr for Alphabet {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(match s {
-            "bitcoin" => Alphabet::Bitcoin,
-            "monero" => Alphabet::Monero,
-            "ripple" => Alphabet::Ripple,
-            "flickr" => Alphabet::Flickr,
-            custom if custom.starts_with("custom(") && custom.ends_with(')') => {
-                let alpha = custom.trim_start_matches("custom(").trim_end_matches(')');
-                Alphabet::Custom(bsx::DynamicAlphabet::new(alpha.into())?)
-            }
-            other => {
-                return Err(anyhow!("'{}' is not a known alphabet", other));
-            }
-        })
-    }
+// Only parsed once at startup, boxing the alphabets would just add noise
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Encode binary input
+    Encode {
+        #[structopt(flatten)]
+        alphabet: Alphabet,
+    },
+
+    /// Decode encoded input to binary
+    Decode {
+        #[structopt(flatten)]
+        alphabet: Alphabet,
+    },
+
+    /// Re-encode input from one alphabet to another
+    Transcode {
+        #[structopt(
+            long,
+            short = "f",
+            help = alphabet_help!("Which alphabet the input is encoded with")
+        )]
+        from: bsx::DynamicAlphabet<Vec<u8>>,
+
+        #[structopt(
+            long,
+            short = "t",
+            help = alphabet_help!("Which alphabet to encode the output with")
+        )]
+        to: bsx::DynamicAlphabet<Vec<u8>>,
+    },
+
+    /// Decode Base58Check style input, verifying and stripping the trailing 4 byte
+    /// double-SHA256 checksum
+    Check {
+        #[structopt(flatten)]
+        alphabet: Alphabet,
+    },
 }
 
 #[derive(Debug, StructOpt)]
-#[structopt(name = "bsx", setting = structopt::clap::AppSettings::ColoredHelp)]
-/// A utility for encoding/decoding arbitrary base encoded data.
-struct Args {
-    /// Decode input
-    #[structopt(long, short = "d")]
-    decode: bool,
-
-    /// Which alphabet to decode/encode with [possible values: bitcoin, monero,
-    /// ripple, flickr or custom(abc...xyz)]
-    #[structopt(long, short = "a", default_value = "bitcoin")]
-    alphabet: Alphabet,
+struct Alphabet {
+    #[structopt(
+        long,
+        short = "a",
+        default_value = "bitcoin",
+        help = alphabet_help!("Which alphabet to decode/encode with")
+    )]
+    alphabet: bsx::DynamicAlphabet<Vec<u8>>,
 }
 
 const INITIAL_INPUT_CAPACITY: usize = 4096;
 
+const CHECKSUM_LEN: usize = 4;
+
+fn read_input(path: Option<&Path>) -> anyhow::Result<Vec<u8>> {
+    match path {
+        Some(path) => {
+            fs::read(path).with_context(|| format!("failed to read input from {}", path.display()))
+        }
+        None => {
+            let mut input = Vec::with_capacity(INITIAL_INPUT_CAPACITY);
+            io::stdin()
+                .read_to_end(&mut input)
+                .context("failed to read input from stdin")?;
+            Ok(input)
+        }
+    }
+}
+
+fn write_output(path: Option<&Path>, output: &[u8]) -> anyhow::Result<()> {
+    match path {
+        Some(path) => fs::write(path, output)
+            .with_context(|| format!("failed to write output to {}", path.display())),
+        None => io::stdout()
+            .write_all(output)
+            .context("failed to write output to stdout"),
+    }
+}
+
 fn main() -> anyhow::Result<()> {
     let args = Args::from_iter_safe(std::env::args_os())?;
 
-    if args.decode {
-        let mut input = String::with_capacity(INITIAL_INPUT_CAPACITY);
-        io::stdin().read_to_string(&mut input)?;
-        let trimmed = input.trim_end();
-        let output = bsx::decode(trimmed)
-            .with_alphabet(args.alphabet.as_alphabet())
-            .into_vec()?;
-        io::stdout().write_all(&output)?;
-    } else {
-        let mut input = Vec::with_capacity(INITIAL_INPUT_CAPACITY);
-        io::stdin().read_to_end(&mut input)?;
-        let output = bsx::encode(input)
-            .with_alphabet(args.alphabet.as_alphabet())
-            .into_string();
-        io::stdout().write_all(output.as_bytes())?;
-    }
+    let input = read_input(args.input.as_deref())?;
+    let output = match args.command {
+        Command::Encode { alphabet } => bsx::encode(input)
+            .with_alphabet(&alphabet.alphabet)
+            .into_vec(),
+        Command::Decode { alphabet } => bsx::decode(input)
+            .with_alphabet(&alphabet.alphabet)
+            .trim_whitespace()
+            .into_vec()?,
+        Command::Transcode { from, to } => {
+            let input = std::str::from_utf8(&input).context("input was not valid UTF-8")?;
+            bsx::transcode(input.trim())
+                .from_alphabet(&from)
+                .to_alphabet(&to)
+                .into_vec()?
+        }
+        Command::Check { alphabet } => {
+            let mut decoded = bsx::decode(input)
+                .with_alphabet(&alphabet.alphabet)
+                .trim_whitespace()
+                .into_vec()?;
+            if decoded.len() < CHECKSUM_LEN {
+                anyhow::bail!("decoded input was too short to contain a checksum");
+            }
+            let (payload, checksum) = decoded.split_at(decoded.len() - CHECKSUM_LEN);
+            let expected = Sha256::digest(Sha256::digest(payload));
+            if checksum != &expected[..CHECKSUM_LEN] {
+                anyhow::bail!("invalid checksum");
+            }
+            decoded.truncate(decoded.len() - CHECKSUM_LEN);
+            decoded
+        }
+    };
 
-    Ok(())
+    write_output(args.output.as_deref(), &output)
 }
@@ -0,0 +1,69 @@
+//! Decode arbitrary input with an arbitrary valid alphabet and options, which should only ever
+//! return an error, never panic.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use bsx::{encode::LeadingZeros, DynamicAlphabet};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+struct Input<'a> {
+    alphabet: Vec<u8>,
+    extended: bool,
+    case_insensitive: bool,
+    lenient: bool,
+    zeros: u8,
+    buffer_len: u16,
+    input: &'a [u8],
+}
+
+fuzz_target!(|input: Input<'_>| {
+    let mut alphabet = input.alphabet;
+    if !input.extended {
+        alphabet.retain(u8::is_ascii);
+    }
+    let mut seen = [false; 256];
+    alphabet.retain(|&c| !std::mem::replace(&mut seen[c as usize], true));
+    let alpha = match if input.extended {
+        DynamicAlphabet::new_extended(alphabet)
+    } else {
+        DynamicAlphabet::new(alphabet)
+    } {
+        Ok(alpha) => alpha,
+        Err(_) => return,
+    };
+
+    let zeros = match input.zeros % 3 {
+        0 => LeadingZeros::Preserve,
+        1 => LeadingZeros::Ignore,
+        _ => LeadingZeros::CountPrefix,
+    };
+    let builder = bsx::decode(input.input)
+        .with_alphabet(&alpha)
+        .with_leading_zeros(zeros);
+    let builder = if input.case_insensitive {
+        builder.case_insensitive()
+    } else {
+        builder
+    };
+    let builder = if input.lenient {
+        builder.lenient()
+    } else {
+        builder
+    };
+
+    let max_len = builder.max_decoded_len();
+    if let Ok(vec) = builder.clone().into_vec() {
+        assert!(vec.len() <= max_len);
+    }
+
+    let mut buffer = vec![0; usize::from(input.buffer_len)];
+    match builder.clone().into(&mut buffer[..]) {
+        Ok(len) => assert!(len <= buffer.len()),
+        Err(bsx::decode::Error::BufferTooSmall { required }) => assert!(required > buffer.len()),
+        Err(_) => {}
+    }
+
+    let _ = builder.into_u128();
+});
@@ -0,0 +1,58 @@
+//! Encode arbitrary bytes with an arbitrary valid alphabet, then check that decoding gives back
+//! the same bytes.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use bsx::{encode::LeadingZeros, DynamicAlphabet};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Arbitrary, Debug)]
+struct Input<'a> {
+    alphabet: Vec<u8>,
+    extended: bool,
+    count_prefix: bool,
+    input: &'a [u8],
+}
+
+fuzz_target!(|input: Input<'_>| {
+    let mut alphabet = input.alphabet;
+    if !input.extended {
+        alphabet.retain(u8::is_ascii);
+    }
+    let mut seen = [false; 256];
+    alphabet.retain(|&c| !std::mem::replace(&mut seen[c as usize], true));
+    let alpha = match if input.extended {
+        DynamicAlphabet::new_extended(alphabet)
+    } else {
+        DynamicAlphabet::new(alphabet)
+    } {
+        Ok(alpha) => alpha,
+        Err(_) => return,
+    };
+
+    let zeros = if input.count_prefix {
+        LeadingZeros::CountPrefix
+    } else {
+        LeadingZeros::Preserve
+    };
+
+    let encoder = bsx::encode(input.input)
+        .with_alphabet(&alpha)
+        .with_leading_zeros(zeros);
+    let max_len = encoder.max_encoded_len();
+    let mut encoded = Vec::new();
+    match encoder.into(&mut encoded) {
+        Ok(_) => {}
+        Err(bsx::encode::Error::TooManyLeadingZeros) if input.count_prefix => return,
+        Err(err) => panic!("encoding failed: {}", err),
+    }
+    assert!(encoded.len() <= max_len);
+
+    let decoded = bsx::decode(&encoded)
+        .with_alphabet(&alpha)
+        .with_leading_zeros(zeros)
+        .into_vec()
+        .unwrap();
+    assert_eq!(input.input, &decoded[..]);
+});
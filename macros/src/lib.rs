@@ -0,0 +1,184 @@
+#![warn(missing_copy_implementations)]
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+#![warn(trivial_casts)]
+#![warn(trivial_numeric_casts)]
+#![warn(unused_extern_crates)]
+#![warn(unused_import_braces)]
+#![warn(variant_size_differences)]
+#![warn(rust_2018_idioms)]
+
+//! Procedural macros for [`bsx`](https://docs.rs/bsx), use these through the `macros` feature of
+//! that crate rather than depending on this crate directly.
+
+use core::ops::Range;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, Span};
+use quote::quote;
+use syn::{parse_macro_input, Lit};
+
+/// Define a `bsx::StaticAlphabet` from a string or byte string literal, checking at compile time
+/// that it is pure ASCII and contains no duplicate characters.
+///
+/// Unlike `StaticAlphabet::new_unwrap` the compile errors point at the offending character in the
+/// literal, where the compiler supports pointing inside literals, and otherwise at the whole
+/// literal with the offending index in the message.
+///
+/// The expansion refers to the `bsx` crate by name, so it must be available as `::bsx`.
+///
+/// # Examples
+///
+/// ```rust
+/// const BASE10: bsx::StaticAlphabet<10> = bsx_macros::alphabet!("0123456789");
+/// assert_eq!("258", bsx::encode([0x01, 0x02]).with_alphabet(&BASE10).into_string());
+/// ```
+///
+/// ```compile_fail
+/// const BASE10: bsx::StaticAlphabet<10> = bsx_macros::alphabet!("0123456788");
+/// ```
+///
+/// ```compile_fail
+/// const BASE10: bsx::StaticAlphabet<10> = bsx_macros::alphabet!("012345678é");
+/// ```
+///
+/// ```compile_fail
+/// const BASE1: bsx::StaticAlphabet<1> = bsx_macros::alphabet!("0");
+/// ```
+#[proc_macro]
+pub fn alphabet(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as Lit);
+    match alphabet_impl(&lit) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => {
+            // There may be multiple errors, so wrap them in a block that still type checks as an
+            // expression
+            let errors = err.to_compile_error();
+            quote!({ #errors ::core::unreachable!() }).into()
+        }
+    }
+}
+
+fn alphabet_impl(lit: &Lit) -> syn::Result<proc_macro2::TokenStream> {
+    let (bytes, token) = match lit {
+        Lit::Str(lit) => (lit.value().into_bytes(), lit.token()),
+        Lit::ByteStr(lit) => (lit.value(), lit.token()),
+        _ => {
+            return Err(syn::Error::new(
+                lit.span(),
+                "expected a string or byte string literal",
+            ))
+        }
+    };
+
+    let ranges = byte_ranges(&token.to_string());
+    let span_of = |index: usize| {
+        ranges
+            .get(index)
+            .filter(|_| ranges.len() == bytes.len())
+            .and_then(|range| token.subspan(range.clone()))
+            .unwrap_or_else(|| lit.span())
+    };
+
+    let mut errors: Option<syn::Error> = None;
+    let mut push = |span: Span, message: String| {
+        let error = syn::Error::new(span, message);
+        match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        }
+    };
+
+    let mut seen = [None; 128];
+    for (i, &c) in bytes.iter().enumerate() {
+        if c >= 128 {
+            // Only report the first byte of multi-byte characters
+            if i == 0 || ranges.get(i) != ranges.get(i - 1) {
+                push(
+                    span_of(i),
+                    format!("alphabet contained a non-ascii character at {}", i),
+                );
+            }
+            continue;
+        }
+        match seen[c as usize] {
+            Some(first) => push(
+                span_of(i),
+                format!(
+                    "alphabet contained a duplicate character `{}` at indexes {} and {}",
+                    c as char, first, i,
+                ),
+            ),
+            None => seen[c as usize] = Some(i),
+        }
+    }
+
+    if bytes.len() < 2 {
+        push(
+            lit.span(),
+            "alphabet contained fewer than 2 characters".to_owned(),
+        );
+    }
+
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    let len = Literal::usize_unsuffixed(bytes.len());
+    let bytes = Literal::byte_string(&bytes);
+    Ok(quote!(::bsx::StaticAlphabet::<#len>::new_unwrap(#bytes)))
+}
+
+/// The source range of each byte of a string or byte string literal's value, given its source
+/// text.
+fn byte_ranges(source: &str) -> Vec<Range<usize>> {
+    let (start, end) = match (source.find('"'), source.rfind('"')) {
+        (Some(start), Some(end)) if start < end => (start + 1, end),
+        _ => return Vec::new(),
+    };
+    let raw = source[..start].contains('r');
+
+    let mut ranges = Vec::new();
+    let mut chars = source[start..end]
+        .char_indices()
+        .map(|(i, c)| (start + i, c))
+        .peekable();
+    while let Some((i, c)) = chars.next() {
+        if raw || c != '\\' {
+            for _ in 0..c.len_utf8() {
+                ranges.push(i..i + c.len_utf8());
+            }
+            continue;
+        }
+        match chars.next() {
+            Some((_, 'x')) => {
+                let end = chars.nth(1).map_or(end, |(j, _)| j + 1);
+                ranges.push(i..end);
+            }
+            Some((_, 'u')) => {
+                let mut value = 0;
+                let mut end = end;
+                for (j, c) in &mut chars {
+                    if c == '}' {
+                        end = j + 1;
+                        break;
+                    }
+                    if let Some(digit) = c.to_digit(16) {
+                        value = value * 16 + digit;
+                    }
+                }
+                let len = char::from_u32(value).map_or(1, char::len_utf8);
+                for _ in 0..len {
+                    ranges.push(i..end);
+                }
+            }
+            Some((_, '\n')) | Some((_, '\r')) => {
+                // A line continuation, skipping the following whitespace
+                while chars.next_if(|&(_, c)| c.is_whitespace()).is_some() {}
+            }
+            Some((j, c)) => ranges.push(i..j + c.len_utf8()),
+            None => {}
+        }
+    }
+    ranges
+}
@@ -0,0 +1,316 @@
+//! Support for the [Ascii85](https://en.wikipedia.org/wiki/Ascii85) and
+//! [Z85](https://rfc.zeromq.org/spec/32/) block encodings.
+//!
+//! Rather than treating the whole input as an integer, these encode each 4 byte block as 5
+//! characters. A final partial block of `n` bytes is zero padded and encoded as its first `n + 1`
+//! characters, strict Z85 only allows inputs that are a multiple of 4 bytes so it will never
+//! produce these.
+//!
+//! ```rust
+//! use bsx::a85::Variant;
+//!
+//! let input = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+//! assert_eq!("HelloWorld", bsx::a85::encode(input, Variant::Z85));
+//! assert_eq!(input.to_vec(), bsx::a85::decode("HelloWorld", Variant::Z85)?);
+//! # Ok::<(), bsx::a85::Error>(())
+//! ```
+
+use core::{convert::TryFrom, fmt};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::{encode as encoding, Alphabet, StaticAlphabet};
+
+/// The block encoding variants.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Variant {
+    /// The original btoa/Adobe encoding using the characters `!` to `u`.
+    ///
+    /// An all zero block is encoded as `z`, and whitespace is ignored when decoding. The Adobe
+    /// `<~` and `~>` delimiters are not added or removed.
+    Ascii85,
+
+    /// ZeroMQ's Z85, using an alphabet that is safe to embed in source code and XML.
+    Z85,
+}
+
+impl Variant {
+    /// The alphabet used by this variant.
+    ///
+    /// ```rust
+    /// use bsx::Alphabet;
+    ///
+    /// assert_eq!(b'!', bsx::a85::Variant::Ascii85.alphabet().encode()[0]);
+    /// ```
+    pub const fn alphabet(self) -> &'static StaticAlphabet<85> {
+        match self {
+            Variant::Ascii85 => StaticAlphabet::ASCII85,
+            Variant::Z85 => StaticAlphabet::Z85,
+        }
+    }
+}
+
+/// A specialized [`Result`](core::result::Result) type for [`bsx::a85`](module@crate::a85)
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Errors that could occur when decoding an Ascii85 or Z85 string.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The output buffer was too small to contain the entire input.
+    BufferTooSmall {
+        /// A buffer length that is large enough for the decoded output, counting every `z` as 4 bytes, so may be more
+        /// than the exact decoded length.
+        required: usize,
+    },
+
+    /// The input ended with a single character, which can't encode a byte.
+    InvalidLength {
+        /// The length of the input.
+        len: usize,
+    },
+
+    /// The input contained a character that was not part of the alphabet.
+    InvalidCharacter {
+        /// The unexpected character.
+        character: char,
+        /// The (byte) index in the input string the character was at.
+        index: usize,
+    },
+
+    /// The input contained a multi-byte (or non-utf8) character.
+    NonAsciiCharacter {
+        /// The (byte) index in the input string the start of the character was at.
+        index: usize,
+    },
+
+    /// A block of characters represented a value larger than 32 bits.
+    Overflow {
+        /// The (byte) index in the input string the block started at.
+        index: usize,
+    },
+}
+
+/// An upper bound on the length of the encoding of `len` bytes.
+///
+/// ```rust
+/// assert_eq!(10, bsx::a85::encoded_len(8));
+/// assert_eq!(13, bsx::a85::encoded_len(10));
+/// ```
+pub const fn encoded_len(len: usize) -> usize {
+    let partial = len % 4;
+    len / 4 * 5 + if partial > 0 { partial + 1 } else { 0 }
+}
+
+/// Encode `input` into `output`, returning the length written.
+///
+/// ```rust
+/// use bsx::a85::Variant;
+///
+/// let mut output = [0; 10];
+/// assert_eq!(6, bsx::a85::encode_into(&[0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff], &mut output, Variant::Ascii85)?);
+/// assert_eq!(b"zs8W-!", &output[..6]);
+/// # Ok::<(), bsx::encode::Error>(())
+/// ```
+pub fn encode_into(input: &[u8], output: &mut [u8], variant: Variant) -> encoding::Result<usize> {
+    let encode = variant.alphabet().encode();
+    let mut len = 0;
+    for chunk in input.chunks(4) {
+        let mut block = [0; 4];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let mut value = u32::from_be_bytes(block);
+        let mut chars = [0; 5];
+        for c in chars.iter_mut().rev() {
+            *c = encode[(value % 85) as usize];
+            value /= 85;
+        }
+        let chars = match variant {
+            Variant::Ascii85 if chunk == [0; 4] => &b"z"[..],
+            _ => &chars[..chunk.len() + 1],
+        };
+        output
+            .get_mut(len..len + chars.len())
+            .ok_or(encoding::Error::BufferTooSmall {
+                required: encoded_len(input.len()),
+            })?
+            .copy_from_slice(chars);
+        len += chars.len();
+    }
+    Ok(len)
+}
+
+/// Encode `input` into a new owned string.
+///
+/// ```rust
+/// use bsx::a85::Variant;
+///
+/// assert_eq!("87cURDZ", bsx::a85::encode(b"Hello", Variant::Ascii85));
+/// assert_eq!("nm=QNzV", bsx::a85::encode(b"Hello", Variant::Z85));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub fn encode(input: impl AsRef<[u8]>, variant: Variant) -> String {
+    let input = input.as_ref();
+    let mut output = alloc::vec![0; encoded_len(input.len())];
+    let len = encode_into(input, &mut output, variant).unwrap();
+    output.truncate(len);
+    // Both alphabets are pure ASCII so this is always valid
+    String::from_utf8(output).unwrap()
+}
+
+/// Decode `input` into `output`, returning the length written.
+///
+/// ## Errors
+///
+/// ### Invalid Length
+///
+/// ```rust
+/// assert_eq!(
+///     bsx::a85::Error::InvalidLength { len: 6 },
+///     bsx::a85::decode_into("HelloW", &mut [0; 8], bsx::a85::Variant::Z85).unwrap_err());
+/// ```
+///
+/// ### Overflow
+///
+/// ```rust
+/// assert_eq!(
+///     bsx::a85::Error::Overflow { index: 0 },
+///     bsx::a85::decode_into("uuuuu", &mut [0; 4], bsx::a85::Variant::Ascii85).unwrap_err());
+/// ```
+pub fn decode_into(input: impl AsRef<[u8]>, output: &mut [u8], variant: Variant) -> Result<usize> {
+    let input = input.as_ref();
+    let decode = variant.alphabet().decode();
+    let mut len = 0;
+    let mut write = |bytes: &[u8]| -> Result<()> {
+        output
+            .get_mut(len..len + bytes.len())
+            .ok_or(Error::BufferTooSmall {
+                required: max_decoded_len(input, variant),
+            })?
+            .copy_from_slice(bytes);
+        len += bytes.len();
+        Ok(())
+    };
+
+    let mut digits = [0; 5];
+    let mut count = 0;
+    let mut start = 0;
+    for (index, &c) in input.iter().enumerate() {
+        if variant == Variant::Ascii85 {
+            if c.is_ascii_whitespace() {
+                continue;
+            }
+            if c == b'z' && count == 0 {
+                write(&[0; 4])?;
+                continue;
+            }
+        }
+        let digit = decode[c as usize];
+        if digit == 0xFF && c > 127 {
+            return Err(Error::NonAsciiCharacter { index });
+        }
+        if digit == 0xFF {
+            return Err(Error::InvalidCharacter {
+                character: c as char,
+                index,
+            });
+        }
+        if count == 0 {
+            start = index;
+        }
+        digits[count] = digit;
+        count += 1;
+        if count == 5 {
+            write(&decode_block(digits, start)?)?;
+            count = 0;
+        }
+    }
+
+    match count {
+        0 => {}
+        1 => return Err(Error::InvalidLength { len: input.len() }),
+        _ => {
+            // The final partial block is padded with the largest digit, so that truncating the
+            // decoded value gives back the original bytes
+            digits[count..].fill(84);
+            write(&decode_block(digits, start)?[..count - 1])?;
+        }
+    }
+
+    Ok(len)
+}
+
+/// An upper bound on the length of the bytes decoded from `input`.
+fn max_decoded_len(input: &[u8], variant: Variant) -> usize {
+    // Each `z` expands to 4 bytes, every other character is at most one byte
+    match variant {
+        Variant::Ascii85 => input.iter().map(|&c| if c == b'z' { 4 } else { 1 }).sum(),
+        Variant::Z85 => input.len(),
+    }
+}
+
+/// Decode a block of 5 digits, which started at `index` in the input.
+fn decode_block(digits: [u8; 5], index: usize) -> Result<[u8; 4]> {
+    let value = digits
+        .iter()
+        .fold(0u64, |value, &digit| value * 85 + u64::from(digit));
+    let value = u32::try_from(value).map_err(|_| Error::Overflow { index })?;
+    Ok(value.to_be_bytes())
+}
+
+/// Decode `input` into a new vector of bytes.
+///
+/// ```rust
+/// use bsx::a85::Variant;
+///
+/// assert_eq!(b"Hello", &*bsx::a85::decode("87cUR DZ", Variant::Ascii85)?);
+/// assert_eq!(vec![0; 8], bsx::a85::decode("zz", Variant::Ascii85)?);
+/// assert_eq!(b"Hello", &*bsx::a85::decode("nm=QNzV", Variant::Z85)?);
+/// # Ok::<(), bsx::a85::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub fn decode(input: impl AsRef<[u8]>, variant: Variant) -> Result<Vec<u8>> {
+    let input = input.as_ref();
+    let mut output = alloc::vec![0; max_decoded_len(input, variant)];
+    let len = decode_into(input, &mut output, variant)?;
+    output.truncate(len);
+    Ok(output)
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::BufferTooSmall { required } => write!(
+                f,
+                "buffer provided to decode string into was too small, {} bytes are required",
+                required
+            ),
+            Error::InvalidLength { len } => write!(
+                f,
+                "provided string was {} characters long, ending with a single character block",
+                len
+            ),
+            Error::InvalidCharacter { character, index } => write!(
+                f,
+                "provided string contained invalid character {:?} at byte {}",
+                character, index
+            ),
+            Error::NonAsciiCharacter { index } => write!(
+                f,
+                "provided string contained non-ascii character starting at byte {}",
+                index
+            ),
+            Error::Overflow { index } => write!(
+                f,
+                "provided string contained a block larger than 32 bits at byte {}",
+                index
+            ),
+        }
+    }
+}
@@ -1,9 +1,82 @@
 //! Support for configurable alphabets
 
-use core::fmt;
+use core::{fmt, mem::MaybeUninit};
 
-mod sealed {
-    pub trait Sealed {}
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::{bits, decode, encode};
+
+pub(crate) mod sealed {
+    use core::mem::MaybeUninit;
+
+    use crate::{decode, encode};
+
+    pub trait Sealed {
+        /// Encode `input` into `output`, returning the length written.
+        fn encode_into(
+            &self,
+            input: &[u8],
+            output: &mut [MaybeUninit<u8>],
+        ) -> encode::Result<usize>;
+
+        /// Decode `input` into `output`, returning the length written.
+        fn decode_into(&self, input: &[u8], output: &mut [u8]) -> decode::Result<usize>;
+
+        /// Decode the characters of `input`, paired with their index in the original input, into
+        /// `output`, returning the length written.
+        fn decode_iter(
+            &self,
+            input: &mut dyn Iterator<Item = (usize, u8)>,
+            output: &mut [u8],
+        ) -> decode::Result<usize>;
+
+        /// Whether leading zero bytes are packed into the output bits like any others, as in RFC
+        /// 4648, rather than each becoming a zero character.
+        fn bit_packed(&self) -> bool {
+            false
+        }
+    }
+}
+
+/// The length of an alphabet, the codecs are generic over this so that when it is known at
+/// compile time the divisions by it and its powers can be specialized into multiply-shift
+/// sequences.
+pub(crate) trait Len: Copy {
+    /// The alphabet length.
+    fn get(self) -> usize;
+
+    /// The largest power of the length that fits in a `u32` limb, along with how many digits that
+    /// is.
+    fn limb_base(self) -> (u64, usize);
+}
+
+impl Len for usize {
+    fn get(self) -> usize {
+        self
+    }
+
+    fn limb_base(self) -> (u64, usize) {
+        encode::limb_base(self)
+    }
+}
+
+/// An alphabet length known at compile time.
+#[derive(Copy, Clone)]
+pub(crate) struct ConstLen<const LEN: usize>;
+
+impl<const LEN: usize> ConstLen<LEN> {
+    const LIMB_BASE: (u64, usize) = encode::limb_base(LEN);
+}
+
+impl<const LEN: usize> Len for ConstLen<LEN> {
+    fn get(self) -> usize {
+        LEN
+    }
+
+    fn limb_base(self) -> (u64, usize) {
+        Self::LIMB_BASE
+    }
 }
 
 /// A value that can be used as an alphabet for
@@ -14,11 +87,128 @@ pub trait Alphabet: sealed::Sealed {
     /// The alphabet length.
     fn len(&self) -> usize;
 
-    /// The mapping from numeric value to ASCII character while encoding.
+    /// The mapping from numeric value to character byte while encoding.
     fn encode(&self) -> &[u8];
 
-    /// The mapping from ASCII character to numeric value while decoding.
+    /// The mapping from character to numeric value while decoding, a 256 entry table where bytes
+    /// that are not part of the alphabet map to `0xFF`.
     fn decode(&self) -> &[u8];
+
+    /// Whether the alphabet length is a power of two, so that each character encodes a whole
+    /// number of bits.
+    ///
+    /// ```rust
+    /// use bsx::Alphabet;
+    ///
+    /// assert!(bsx::StaticAlphabet::BASE64.is_power_of_two());
+    /// assert!(!bsx::StaticAlphabet::BITCOIN.is_power_of_two());
+    /// ```
+    fn is_power_of_two(&self) -> bool {
+        self.len().is_power_of_two()
+    }
+
+    /// The number of bits of information each character encodes, `log2(len)`.
+    ///
+    /// This is exact for power of two lengths and otherwise a lower bound accurate to 16
+    /// fractional bits.
+    ///
+    /// ```rust
+    /// use bsx::Alphabet;
+    ///
+    /// assert_eq!(6.0, bsx::StaticAlphabet::BASE64.bits_per_symbol());
+    /// assert!((5.8579..5.8580).contains(&bsx::StaticAlphabet::BITCOIN.bits_per_symbol()));
+    /// ```
+    fn bits_per_symbol(&self) -> f64 {
+        crate::log2_fixed(self.len()) as f64 / f64::from(1u32 << crate::LOG2_FRAC_BITS)
+    }
+
+    /// The asymptotic number of characters produced per byte of input, `8 / log2(len)`.
+    ///
+    /// ```rust
+    /// use bsx::Alphabet;
+    ///
+    /// assert_eq!(2.0, bsx::StaticAlphabet::BASE16.expansion_ratio());
+    /// assert!((1.365..1.366).contains(&bsx::StaticAlphabet::BITCOIN.expansion_ratio()));
+    /// ```
+    fn expansion_ratio(&self) -> f64 {
+        8.0 / self.bits_per_symbol()
+    }
+
+    /// An upper bound on the length of the encoding of `input_len` bytes with this alphabet, see
+    /// [`bsx::encoded_len`](crate::encoded_len).
+    ///
+    /// ```rust
+    /// use bsx::Alphabet;
+    ///
+    /// assert_eq!(44, bsx::StaticAlphabet::BITCOIN.encoded_len(32));
+    /// ```
+    fn encoded_len(&self, input_len: usize) -> usize {
+        crate::encoded_len(input_len, self.len())
+    }
+
+    /// An upper bound on the length of the bytes decoded from `input_len` characters with this
+    /// alphabet when there are no leading zero characters, see
+    /// [`bsx::decoded_len`](crate::decoded_len).
+    ///
+    /// ```rust
+    /// use bsx::Alphabet;
+    ///
+    /// assert_eq!(33, bsx::StaticAlphabet::BITCOIN.decoded_len(44));
+    /// ```
+    fn decoded_len(&self, input_len: usize) -> usize {
+        crate::decoded_len(input_len, self.len())
+    }
+
+    /// The characters of the alphabet in value order as a string, or `None` for an extended
+    /// alphabet containing non-ASCII bytes.
+    ///
+    /// ```rust
+    /// use bsx::Alphabet;
+    ///
+    /// assert_eq!(Some("0123456789abcdef"), bsx::StaticAlphabet::BASE16.as_str());
+    ///
+    /// let extended = bsx::DynamicAlphabet::new_extended([b'a', 0xFF])?;
+    /// assert_eq!(None, extended.as_str());
+    /// # Ok::<(), bsx::alphabet::Error>(())
+    /// ```
+    fn as_str(&self) -> Option<&str> {
+        let encode = self.encode();
+        if encode.is_ascii() {
+            core::str::from_utf8(encode).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Whether the character byte will be accepted while decoding, including any characters
+    /// accepted through case-insensitivity or aliases.
+    ///
+    /// ```rust
+    /// use bsx::Alphabet;
+    ///
+    /// assert!(bsx::StaticAlphabet::BITCOIN.contains(b'z'));
+    /// assert!(!bsx::StaticAlphabet::BITCOIN.contains(b'0'));
+    /// assert!(bsx::StaticAlphabet::CROCKFORD.contains(b'o'));
+    /// ```
+    fn contains(&self, c: u8) -> bool {
+        self.index_of(c).is_some()
+    }
+
+    /// The numeric value the character byte decodes to, if it is accepted while decoding.
+    ///
+    /// ```rust
+    /// use bsx::Alphabet;
+    ///
+    /// assert_eq!(Some(57), bsx::StaticAlphabet::BITCOIN.index_of(b'z'));
+    /// assert_eq!(None, bsx::StaticAlphabet::BITCOIN.index_of(b'0'));
+    /// assert_eq!(Some(0), bsx::StaticAlphabet::CROCKFORD.index_of(b'o'));
+    /// ```
+    fn index_of(&self, c: u8) -> Option<usize> {
+        match self.decode()[c as usize] {
+            0xFF => None,
+            value => Some(value.into()),
+        }
+    }
 }
 
 /// Statically sized prepared Alphabet for
@@ -27,7 +217,10 @@ pub trait Alphabet: sealed::Sealed {
 #[derive(Clone, Copy)]
 pub struct StaticAlphabet<const LEN: usize> {
     pub(crate) encode: [u8; LEN],
-    pub(crate) decode: [u8; 128],
+    pub(crate) decode: [u8; 256],
+    /// Whether the input bits are packed from the start as in RFC 4648, rather than the input
+    /// being treated as an integer.
+    pub(crate) bit_packed: bool,
 }
 
 /// Dynamically sized prepared Alphabet for
@@ -36,7 +229,27 @@ pub struct StaticAlphabet<const LEN: usize> {
 #[derive(Clone)]
 pub struct DynamicAlphabet<A> {
     pub(crate) encode: A,
-    pub(crate) decode: [u8; 128],
+    pub(crate) decode: [u8; 256],
+    /// Whether the input bits are packed from the start as in RFC 4648, rather than the input
+    /// being treated as an integer.
+    pub(crate) bit_packed: bool,
+}
+
+/// Prepared alphabet of arbitrary [`char`]s, such as Cyrillic or CJK symbols, for
+/// [`CharAlphabet::encode`] and [`CharAlphabet::decode`].
+///
+/// Because the symbols are not single bytes this can't be used with the
+/// [`EncodeBuilder`](crate::encode::EncodeBuilder) and
+/// [`DecodeBuilder`](crate::decode::DecodeBuilder), instead it encodes to digit values with an
+/// internal byte alphabet and maps them to and from the symbols.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+#[derive(Clone)]
+pub struct CharAlphabet<A> {
+    encode: A,
+    /// The symbols along with their values, sorted by symbol.
+    decode: Vec<(char, u8)>,
+    digits: DynamicAlphabet<&'static [u8]>,
 }
 
 /// A placeholder for [`EncodeBuilder`](crate::encode::EncodeBuilder) and
@@ -48,6 +261,7 @@ pub struct Unspecified;
 /// Errors that could occur when preparing an [`StaticAlphabet`].
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error {
     /// The alphabet contained a duplicate character at at least 2 indexes.
     DuplicateCharacter {
@@ -64,6 +278,94 @@ pub enum Error {
         /// The index at which the non-ASCII character was seen.
         index: usize,
     },
+
+    /// The alphabet contained more than 255 characters.
+    TooLong,
+
+    /// The alphabet contained fewer than 2 characters.
+    TooShort,
+
+    /// An alias was itself part of the alphabet, or the character it aliased was not.
+    InvalidAlias {
+        /// The alias character.
+        alias: char,
+        /// The character it was an alias for.
+        canonical: char,
+    },
+
+    /// The name was neither a built-in alphabet nor `custom(...)`.
+    UnknownName,
+}
+
+/// The kinds of decoding table that can be built for an alphabet.
+#[derive(Copy, Clone)]
+enum Kind {
+    /// Only ASCII characters, decoded exactly.
+    Ascii,
+    /// Any bytes, decoded exactly.
+    Extended,
+    /// Only ASCII characters, with letters decoded in either case.
+    CaseInsensitive,
+}
+
+/// Build the decoding table for an alphabet, checking that there are no duplicate characters and
+/// only allowing non-ASCII bytes for an extended alphabet.
+const fn decode_table(base: &[u8], kind: Kind) -> Result<[u8; 256], Error> {
+    // There's no positional notation with fewer than 2 digits
+    if base.len() < 2 {
+        return Err(Error::TooShort);
+    }
+
+    let mut decode = [0xFF; 256];
+
+    let mut i = 0;
+    while i < base.len() {
+        let c = base[i];
+        if !matches!(kind, Kind::Extended) && c >= 128 {
+            return Err(Error::NonAsciiCharacter { index: i });
+        }
+        if decode[c as usize] != 0xFF {
+            return Err(Error::DuplicateCharacter {
+                character: c as char,
+                first: decode[c as usize] as usize,
+                second: i,
+            });
+        }
+        decode[c as usize] = i as u8;
+        if matches!(kind, Kind::CaseInsensitive) && c.is_ascii_alphabetic() {
+            // Flipping this bit swaps the case of an ASCII letter
+            decode[(c ^ 0x20) as usize] = i as u8;
+        }
+        i += 1;
+    }
+
+    // `0xFF` marks characters that are not part of the alphabet, so it can't be used as a value
+    if base.len() > 255 {
+        return Err(Error::TooLong);
+    }
+
+    Ok(decode)
+}
+
+/// Add the `(alias, canonical)` pairs to a decoding table, checking that each alias is not already
+/// part of the alphabet and each canonical character is.
+const fn alias_table(mut decode: [u8; 256], aliases: &[(u8, u8)]) -> Result<[u8; 256], Error> {
+    let original = decode;
+
+    let mut i = 0;
+    while i < aliases.len() {
+        let (alias, canonical) = aliases[i];
+        if decode[alias as usize] != 0xFF || original[canonical as usize] == 0xFF {
+            return Err(Error::InvalidAlias {
+                alias: alias as char,
+                canonical: canonical as char,
+            });
+        }
+        decode[alias as usize] = original[canonical as usize];
+        i += 1;
+    }
+
+    Ok(decode)
 }
 
 impl<const LEN: usize> StaticAlphabet<LEN> {
@@ -102,33 +404,111 @@ impl<const LEN: usize> StaticAlphabet<LEN> {
     ///     bsx::alphabet::Error::NonAsciiCharacter { index: 1 },
     ///     bsx::StaticAlphabet::new(&[b'a', 255]).unwrap_err());
     /// ```
+    ///
+    /// ### Too Short
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     bsx::alphabet::Error::TooShort,
+    ///     bsx::StaticAlphabet::new(b"a").unwrap_err());
+    /// ```
     pub const fn new(base: &[u8; LEN]) -> Result<Self, Error> {
-        let mut encode = [0x00; LEN];
-        let mut decode = [0xFF; 128];
+        match decode_table(base, Kind::Ascii) {
+            Ok(decode) => Ok(Self {
+                encode: *base,
+                decode,
+                bit_packed: false,
+            }),
+            Err(err) => Err(err),
+        }
+    }
 
-        let mut i = 0;
-        while i < encode.len() {
-            if base[i] >= 128 {
-                return Err(Error::NonAsciiCharacter { index: i });
-            }
-            if decode[base[i] as usize] != 0xFF {
-                return Err(Error::DuplicateCharacter {
-                    character: base[i] as char,
-                    first: decode[base[i] as usize] as usize,
-                    second: i,
-                });
-            }
-            encode[i] = base[i];
-            decode[base[i] as usize] = i as u8;
-            i += 1;
+    /// Create prepared alphabet that may contain non-ASCII bytes, allowing up to 255 characters,
+    /// checks that there are no duplicate characters.
+    ///
+    /// Encoding with an alphabet containing non-ASCII bytes will generally not produce valid
+    /// UTF-8, so should be done into byte outputs such as
+    /// [`EncodeBuilder::into_vec`](crate::encode::EncodeBuilder::into_vec), string outputs will
+    /// give an [`encode::Error::InvalidUtf8`] error if the output is not valid UTF-8.
+    ///
+    /// ```rust
+    /// let mut base = [0; 200];
+    /// for (i, c) in base.iter_mut().enumerate() {
+    ///     *c = i as u8 + 56;
+    /// }
+    /// let extended = bsx::StaticAlphabet::new_extended(&base)?;
+    ///
+    /// let encoded = bsx::encode([0xFF, 0xFF]).with_alphabet(&extended).into_vec();
+    /// assert_eq!(vec![0x39, 0xB7, 0xBF], encoded);
+    /// assert_eq!(
+    ///     vec![0xFF, 0xFF],
+    ///     bsx::decode(encoded).with_alphabet(&extended).into_vec()?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// ### Too Long
+    ///
+    /// ```rust
+    /// let mut base = [0; 256];
+    /// for (i, c) in base.iter_mut().enumerate() {
+    ///     *c = i as u8;
+    /// }
+    /// assert_eq!(
+    ///     bsx::alphabet::Error::TooLong,
+    ///     bsx::StaticAlphabet::new_extended(&base).unwrap_err());
+    /// ```
+    pub const fn new_extended(base: &[u8; LEN]) -> Result<Self, Error> {
+        match decode_table(base, Kind::Extended) {
+            Ok(decode) => Ok(Self {
+                encode: *base,
+                decode,
+                bit_packed: false,
+            }),
+            Err(err) => Err(err),
         }
+    }
 
-        Ok(Self { encode, decode })
+    /// Create prepared alphabet that decodes letters in either case, checks that the alphabet is
+    /// pure ASCII and that there are no duplicate characters when ignoring case.
+    ///
+    /// Encoding uses the characters exactly as given.
+    ///
+    /// ```rust
+    /// let base32 = bsx::StaticAlphabet::new_case_insensitive(b"abcdefghijklmnopqrstuvwxyz234567")?;
+    ///
+    /// assert_eq!("h7", bsx::encode([0xFF]).with_alphabet(&base32).into_string());
+    /// assert_eq!(vec![0xFF], bsx::decode("H7").with_alphabet(&base32).into_vec()?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// ### Duplicate Character
+    ///
+    /// Both cases of the same letter are a duplicate, as they would decode ambiguously.
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     bsx::alphabet::Error::DuplicateCharacter { character: 'A', first: 0, second: 2 },
+    ///     bsx::StaticAlphabet::new_case_insensitive(b"abAB").unwrap_err());
+    /// ```
+    pub const fn new_case_insensitive(base: &[u8; LEN]) -> Result<Self, Error> {
+        match decode_table(base, Kind::CaseInsensitive) {
+            Ok(decode) => Ok(Self {
+                encode: *base,
+                decode,
+                bit_packed: false,
+            }),
+            Err(err) => Err(err),
+        }
     }
 
     /// Same as [`Self::new`], but gives a panic instead of an [`Err`] on bad input.
     ///
-    /// Intended to support usage in `const` context until [`Result::unwrap`] is able to be called.
+    /// Intended to support usage in `const` context where [`Result::unwrap`] is not able to be
+    /// called.
     ///
     /// ```rust
     /// const SYMBOLIC: &'static bsx::StaticAlphabet<33> = &bsx::StaticAlphabet::new_unwrap(
@@ -153,12 +533,79 @@ impl<const LEN: usize> StaticAlphabet<LEN> {
     /// );
     /// ```
     pub const fn new_unwrap(base: &[u8; LEN]) -> Self {
-        let result = Self::new(base);
-        #[allow(unconditional_panic)] // https://github.com/rust-lang/rust/issues/78803
-        [][match result {
-            Ok(alphabet) => return alphabet,
-            Err(_) => 0,
-        }]
+        match Self::new(base) {
+            Ok(alphabet) => alphabet,
+            Err(Error::DuplicateCharacter { .. }) => {
+                panic!("alphabet contained a duplicate character")
+            }
+            Err(Error::NonAsciiCharacter { .. }) => {
+                panic!("alphabet contained a non-ascii character")
+            }
+            Err(Error::TooLong) => panic!("alphabet contained more than 255 characters"),
+            Err(Error::TooShort) => panic!("alphabet contained fewer than 2 characters"),
+            Err(Error::InvalidAlias { .. } | Error::UnknownName) => unreachable!(),
+        }
+    }
+
+    /// Same as [`Self::new_case_insensitive`], but panics on bad input, for the built-in
+    /// constants.
+    const fn new_case_insensitive_unwrap(base: &[u8; LEN]) -> Self {
+        match Self::new_case_insensitive(base) {
+            Ok(alphabet) => alphabet,
+            Err(_) => panic!("invalid alphabet"),
+        }
+    }
+
+    /// Pack the input bits from the start as in RFC 4648 rather than treating the input as an
+    /// integer, for the built-in constants of a power of two length.
+    const fn packed(self) -> Self {
+        Self {
+            bit_packed: true,
+            ..self
+        }
+    }
+
+    /// Add aliases that decode the same as a character of the alphabet, such as visually
+    /// confusable characters, the `(alias, canonical)` pairs only affect decoding.
+    ///
+    /// ```rust
+    /// const BITCOIN: bsx::StaticAlphabet<58> = match bsx::StaticAlphabet::BITCOIN
+    ///     .with_aliases(&[(b'0', b'o'), (b'O', b'o'), (b'I', b'1'), (b'l', b'1')])
+    /// {
+    ///     Ok(alphabet) => alphabet,
+    ///     Err(_) => panic!(),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bsx::decode("heIlOwor1d").with_alphabet(&BITCOIN).into_vec()?);
+    /// assert_eq!(
+    ///     "he11owor1d",
+    ///     bsx::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]).with_alphabet(&BITCOIN).into_string());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// ### Invalid Alias
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     bsx::alphabet::Error::InvalidAlias { alias: 'o', canonical: '1' },
+    ///     bsx::StaticAlphabet::BITCOIN.with_aliases(&[(b'o', b'1')]).unwrap_err());
+    /// assert_eq!(
+    ///     bsx::alphabet::Error::InvalidAlias { alias: 'I', canonical: 'l' },
+    ///     bsx::StaticAlphabet::BITCOIN.with_aliases(&[(b'I', b'l')]).unwrap_err());
+    /// ```
+    pub const fn with_aliases(&self, aliases: &[(u8, u8)]) -> Result<Self, Error> {
+        match alias_table(self.decode, aliases) {
+            Ok(decode) => Ok(Self {
+                encode: self.encode,
+                decode,
+                bit_packed: self.bit_packed,
+            }),
+            Err(err) => Err(err),
+        }
     }
 }
 
@@ -199,27 +646,243 @@ impl<A: AsRef<[u8]>> DynamicAlphabet<A> {
     ///     bsx::DynamicAlphabet::new(&[b'a', 255]).unwrap_err());
     /// ```
     pub fn new(base: A) -> Result<Self, Error> {
-        let encode = base;
-        let mut decode = [0xFF; 128];
+        let decode = decode_table(base.as_ref(), Kind::Ascii)?;
+        Ok(Self {
+            encode: base,
+            decode,
+            bit_packed: false,
+        })
+    }
 
-        for (i, &c) in encode.as_ref().iter().enumerate() {
-            if c >= 128 {
-                return Err(Error::NonAsciiCharacter { index: i });
-            }
-            if decode[c as usize] != 0xFF {
-                return Err(Error::DuplicateCharacter {
-                    character: c as char,
-                    first: decode[c as usize] as usize,
-                    second: i,
-                });
+    /// Create prepared alphabet that may contain non-ASCII bytes, allowing up to 255 characters,
+    /// checks that there are no duplicate characters.
+    ///
+    /// See [`StaticAlphabet::new_extended`] for the caveats of encoding with non-ASCII bytes.
+    ///
+    /// ```rust
+    /// let base: Vec<u8> = (56..=255).collect();
+    /// let extended = bsx::DynamicAlphabet::new_extended(base)?;
+    ///
+    /// let encoded = bsx::encode([0xFF, 0xFF]).with_alphabet(&extended).into_vec();
+    /// assert_eq!(vec![0x39, 0xB7, 0xBF], encoded);
+    /// assert_eq!(
+    ///     bsx::encode::Error::InvalidUtf8,
+    ///     bsx::encode([0xFF, 0xFF]).with_alphabet(&extended).into(&mut String::new()).unwrap_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new_extended(base: A) -> Result<Self, Error> {
+        let decode = decode_table(base.as_ref(), Kind::Extended)?;
+        Ok(Self {
+            encode: base,
+            decode,
+            bit_packed: false,
+        })
+    }
+
+    /// Create prepared alphabet that decodes letters in either case, checks that the alphabet is
+    /// pure ASCII and that there are no duplicate characters when ignoring case.
+    ///
+    /// See [`StaticAlphabet::new_case_insensitive`] for more details.
+    ///
+    /// ```rust
+    /// let base36 = bsx::DynamicAlphabet::new_case_insensitive("0123456789abcdefghijklmnopqrstuvwxyz")?;
+    ///
+    /// assert_eq!("hello", bsx::encode([0x01, 0xbe, 0x15, 0xdc]).with_alphabet(&base36).into_string());
+    /// assert_eq!(vec![0x01, 0xbe, 0x15, 0xdc], bsx::decode("HeLLo").with_alphabet(&base36).into_vec()?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new_case_insensitive(base: A) -> Result<Self, Error> {
+        let decode = decode_table(base.as_ref(), Kind::CaseInsensitive)?;
+        Ok(Self {
+            encode: base,
+            decode,
+            bit_packed: false,
+        })
+    }
+
+    /// Add aliases that decode the same as a character of the alphabet, such as visually
+    /// confusable characters, the `(alias, canonical)` pairs only affect decoding.
+    ///
+    /// See [`StaticAlphabet::with_aliases`] for more details.
+    ///
+    /// ```rust
+    /// let base32 = bsx::DynamicAlphabet::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ234567")?
+    ///     .with_aliases(&[(b'0', b'O'), (b'1', b'I'), (b'8', b'B')])?;
+    ///
+    /// assert_eq!(
+    ///     bsx::decode("BOIB").with_alphabet(&base32).into_vec()?,
+    ///     bsx::decode("8018").with_alphabet(&base32).into_vec()?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_aliases(self, aliases: &[(u8, u8)]) -> Result<Self, Error> {
+        let decode = alias_table(self.decode, aliases)?;
+        Ok(Self {
+            encode: self.encode,
+            decode,
+            bit_packed: self.bit_packed,
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+impl DynamicAlphabet<Vec<u8>> {
+    /// Parse an alphabet specification, either the name of a built-in alphabet as accepted by
+    /// [`by_name`] or `custom(...)` wrapping the characters of an ASCII alphabet.
+    ///
+    /// Built-in alphabets keep their decoding behavior, such as case-insensitivity and aliases.
+    ///
+    /// ```rust
+    /// let bitcoin = bsx::DynamicAlphabet::parse("bitcoin")?;
+    /// let custom = bsx::DynamicAlphabet::parse("custom(0123456789)")?;
+    ///
+    /// assert_eq!("he11owor1d", bsx::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]).with_alphabet(&bitcoin).into_string());
+    /// assert_eq!("1234", bsx::encode_int(1234u32).with_alphabet(&custom).into_string());
+    /// assert_eq!(vec![0xca, 0xfe], bsx::decode("CAFE").with_alphabet(&bsx::DynamicAlphabet::parse("hex")?).into_vec()?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// ### Unknown Name
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     bsx::alphabet::Error::UnknownName,
+    ///     bsx::DynamicAlphabet::parse("base58").unwrap_err());
+    /// ```
+    ///
+    /// ### Invalid Custom Alphabet
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     bsx::alphabet::Error::DuplicateCharacter { character: 'a', first: 0, second: 1 },
+    ///     bsx::DynamicAlphabet::parse("custom(aa)").unwrap_err());
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        if let Some(alpha) = by_name(s) {
+            let mut decode = [0; 256];
+            decode.copy_from_slice(alpha.decode());
+            return Ok(Self {
+                encode: alpha.encode().to_vec(),
+                decode,
+                bit_packed: alpha.bit_packed(),
+            });
+        }
+        match s.strip_prefix("custom(").and_then(|s| s.strip_suffix(')')) {
+            Some(custom) => Self::new(custom.as_bytes().to_vec()),
+            None => Err(Error::UnknownName),
+        }
+    }
+
+    /// Create a deterministic permutation of `base` from `seed`, for IDs that don't look
+    /// sequential while staying stable across deployments that share the seed.
+    ///
+    /// The shuffle is a Fisher–Yates driven by SplitMix64, the permutation produced for a given
+    /// base and seed will not change between releases. Any case-insensitivity or aliases of the
+    /// base alphabet are kept.
+    ///
+    /// This is obfuscation only, the permutation can be recovered from enough encoded values.
+    ///
+    /// ```rust
+    /// use bsx::Alphabet;
+    ///
+    /// let shuffled = bsx::DynamicAlphabet::shuffled(bsx::StaticAlphabet::BITCOIN, 42);
+    /// assert_eq!(
+    ///     Some("n4KdSFQ2ARCH3vcf7s1VXLMW9GexwEtNUk5aiPqzgJYZBTopD6mrhuyjb8"),
+    ///     shuffled.as_str());
+    /// assert_eq!("ChZWV", bsx::encode_int(123456789u32).with_alphabet(&shuffled).into_string());
+    /// assert_eq!(123456789, bsx::decode("ChZWV").with_alphabet(&shuffled).into_u128()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn shuffled(base: impl Alphabet, seed: u64) -> Self {
+        let mut encode = base.encode().to_vec();
+        let mut state = seed;
+        for i in (1..encode.len()).rev() {
+            // SplitMix64
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^= z >> 31;
+            encode.swap(i, (z % (i as u64 + 1)) as usize);
+        }
+
+        // Map each accepted byte to the new position of the character it originally decoded as
+        let mut positions = [0xFF; 256];
+        for (i, &c) in encode.iter().enumerate() {
+            positions[base.decode()[c as usize] as usize] = i as u8;
+        }
+        let mut decode = [0xFF; 256];
+        for (d, &value) in decode.iter_mut().zip(base.decode()) {
+            if value != 0xFF {
+                *d = positions[value as usize];
             }
-            decode[c as usize] = i as u8;
         }
 
-        Ok(Self { encode, decode })
+        Self {
+            encode,
+            decode,
+            bit_packed: base.bit_packed(),
+        }
     }
 }
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+impl core::str::FromStr for DynamicAlphabet<Vec<u8>> {
+    type Err = Error;
+
+    /// See [`DynamicAlphabet::parse`].
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Self::parse(s)
+    }
+}
+
+/// The built-in alphabets by name, see [`by_name`].
+pub(crate) const NAMED: &[(&str, &dyn Alphabet)] = &[
+    ("bitcoin", <dyn Alphabet>::BITCOIN),
+    ("monero", <dyn Alphabet>::MONERO),
+    ("ripple", <dyn Alphabet>::RIPPLE),
+    ("flickr", <dyn Alphabet>::FLICKR),
+    ("crockford", <dyn Alphabet>::CROCKFORD),
+    ("zbase32", <dyn Alphabet>::ZBASE32),
+    ("base32hex", <dyn Alphabet>::BASE32HEX),
+    ("geohash", <dyn Alphabet>::GEOHASH),
+    ("bech32", <dyn Alphabet>::BECH32),
+    ("base16", <dyn Alphabet>::BASE16),
+    ("hex", <dyn Alphabet>::BASE16),
+    ("base16upper", <dyn Alphabet>::BASE16_UPPER),
+    ("HEX", <dyn Alphabet>::BASE16_UPPER),
+    ("base36", <dyn Alphabet>::BASE36),
+    ("base45", <dyn Alphabet>::BASE45),
+    ("base62", <dyn Alphabet>::BASE62),
+    ("base64", <dyn Alphabet>::BASE64),
+    ("base64url", <dyn Alphabet>::BASE64URL),
+    ("ascii85", <dyn Alphabet>::ASCII85),
+    ("z85", <dyn Alphabet>::Z85),
+    ("base91", <dyn Alphabet>::BASE91),
+];
+
+/// Lookup one of the built-in alphabets by name, for resolving alphabets from configuration.
+///
+/// The names are the constants on [`dyn Alphabet`](trait@Alphabet) lowercased without separators
+/// (e.g. `"base16upper"` for `BASE16_UPPER`), along with `"hex"` and `"HEX"` as aliases for the
+/// base16 alphabets.
+///
+/// ```rust
+/// let alpha = bsx::alphabet::by_name("flickr").unwrap();
+/// assert_eq!("rTu1dk6cWsRYjYu", bsx::encode(b"hello world").with_alphabet(alpha).into_string());
+///
+/// assert!(bsx::alphabet::by_name("base58").is_none());
+/// ```
+pub fn by_name(name: &str) -> Option<&'static dyn Alphabet> {
+    NAMED
+        .iter()
+        .find(|&&(n, _)| n == name)
+        .map(|&(_, alphabet)| alphabet)
+}
+
 impl dyn Alphabet {
     /// Bitcoin's alphabet as defined in their Base58Check encoding.
     ///
@@ -240,6 +903,73 @@ impl dyn Alphabet {
     ///
     /// See <https://www.flickr.com/groups/api/discuss/72157616713786392/>
     pub const FLICKR: &'static Self = &StaticAlphabet::FLICKR;
+
+    /// Crockford's base32 alphabet, decoding case-insensitively with `O` as `0` and `I` and `L`
+    /// as `1`.
+    ///
+    /// See <https://www.crockford.com/base32.html>
+    pub const CROCKFORD: &'static Self = &StaticAlphabet::CROCKFORD;
+
+    /// Zooko's z-base-32 alphabet, ordered so that the easiest characters to read and pronounce
+    /// are the most common.
+    ///
+    /// See <https://philzimmermann.com/docs/human-oriented-base-32-encoding.txt>
+    pub const ZBASE32: &'static Self = &StaticAlphabet::ZBASE32;
+
+    /// The RFC 4648 "base32hex" alphabet, which preserves the sort order of fixed width values.
+    ///
+    /// See <https://datatracker.ietf.org/doc/html/rfc4648#section-7>
+    pub const BASE32HEX: &'static Self = &StaticAlphabet::BASE32HEX;
+
+    /// The Geohash base32 alphabet.
+    ///
+    /// See <https://en.wikipedia.org/wiki/Geohash>
+    pub const GEOHASH: &'static Self = &StaticAlphabet::GEOHASH;
+
+    /// The BIP-173 bech32 character set.
+    ///
+    /// See <https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki>
+    pub const BECH32: &'static Self = &StaticAlphabet::BECH32;
+
+    /// The RFC 4648 base64 alphabet.
+    ///
+    /// See <https://datatracker.ietf.org/doc/html/rfc4648#section-4>
+    pub const BASE64: &'static Self = &StaticAlphabet::BASE64;
+
+    /// The RFC 4648 URL and filename safe base64 alphabet.
+    ///
+    /// See <https://datatracker.ietf.org/doc/html/rfc4648#section-5>
+    pub const BASE64URL: &'static Self = &StaticAlphabet::BASE64URL;
+
+    /// The lowercase hexadecimal alphabet, decoding case-insensitively.
+    pub const BASE16: &'static Self = &StaticAlphabet::BASE16;
+
+    /// The uppercase hexadecimal alphabet, decoding case-insensitively.
+    pub const BASE16_UPPER: &'static Self = &StaticAlphabet::BASE16_UPPER;
+
+    /// The common base36 alphabet of digits then lowercase letters, decoding case-insensitively.
+    pub const BASE36: &'static Self = &StaticAlphabet::BASE36;
+
+    /// The RFC 9285 base45 alphabet.
+    ///
+    /// See <https://datatracker.ietf.org/doc/html/rfc9285>
+    pub const BASE45: &'static Self = &StaticAlphabet::BASE45;
+
+    /// The common base62 alphabet of digits, uppercase then lowercase letters.
+    pub const BASE62: &'static Self = &StaticAlphabet::BASE62;
+
+    /// The Ascii85 alphabet of the characters `!` to `u`.
+    pub const ASCII85: &'static Self = &StaticAlphabet::ASCII85;
+
+    /// ZeroMQ's Z85 alphabet.
+    ///
+    /// See <https://rfc.zeromq.org/spec/32/>
+    pub const Z85: &'static Self = &StaticAlphabet::Z85;
+
+    /// The basE91 alphabet.
+    ///
+    /// See <http://base91.sourceforge.net/>
+    pub const BASE91: &'static Self = &StaticAlphabet::BASE91;
 }
 
 impl StaticAlphabet<58> {
@@ -268,7 +998,313 @@ impl StaticAlphabet<58> {
         &Self::new_unwrap(b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ");
 }
 
-impl<const LEN: usize> sealed::Sealed for StaticAlphabet<LEN> {}
+impl StaticAlphabet<32> {
+    /// Crockford's base32 alphabet, decoding case-insensitively with `O` as `0` and `I` and `L`
+    /// as `1`.
+    ///
+    /// Use [`EncodeBuilder::with_check_symbol`](crate::encode::EncodeBuilder::with_check_symbol)
+    /// and [`DecodeBuilder::with_check_symbol`](crate::decode::DecodeBuilder::with_check_symbol)
+    /// for the optional check symbol.
+    ///
+    /// See <https://www.crockford.com/base32.html>
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::CROCKFORD;
+    /// assert_eq!("3NQK8N", bsx::encode_int(123456789u32).with_alphabet(alpha).into_string());
+    /// assert_eq!(123456789, bsx::decode("3nqk8n").with_alphabet(alpha).into_u128()?);
+    /// assert_eq!(0x400, bsx::decode("lOo").with_alphabet(alpha).into_u128()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const CROCKFORD: &'static Self = &{
+        let aliases = [
+            (b'O', b'0'),
+            (b'o', b'0'),
+            (b'I', b'1'),
+            (b'i', b'1'),
+            (b'L', b'1'),
+            (b'l', b'1'),
+        ];
+        match Self::new_case_insensitive_unwrap(b"0123456789ABCDEFGHJKMNPQRSTVWXYZ")
+            .with_aliases(&aliases)
+        {
+            Ok(alphabet) => alphabet,
+            Err(_) => panic!("invalid alias"),
+        }
+    };
+
+    /// Zooko's z-base-32 alphabet, ordered so that the easiest characters to read and pronounce
+    /// are the most common.
+    ///
+    /// See <https://philzimmermann.com/docs/human-oriented-base-32-encoding.txt>
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::ZBASE32;
+    /// assert_eq!("dizuei", bsx::encode_int(123456789u32).with_alphabet(alpha).into_string());
+    /// assert_eq!(123456789, bsx::decode("dizuei").with_alphabet(alpha).into_u128()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const ZBASE32: &'static Self = &Self::new_unwrap(b"ybndrfg8ejkmcpqxot1uwisza345h769");
+
+    /// The RFC 4648 "base32hex" alphabet, which preserves the sort order of fixed width values.
+    ///
+    /// The characters are in ASCII order, so encoding with
+    /// [`EncodeBuilder::with_min_width`](crate::encode::EncodeBuilder::with_min_width) gives
+    /// strings that sort the same as the values, useful for keys in ordered stores.
+    ///
+    /// See <https://datatracker.ietf.org/doc/html/rfc4648#section-7>
+    ///
+    /// ```rust
+    /// let key = |n: u32| {
+    ///     bsx::encode_int(n)
+    ///         .with_alphabet(bsx::StaticAlphabet::BASE32HEX)
+    ///         .with_min_width(7)
+    ///         .into_string()
+    /// };
+    /// assert_eq!("000007V", key(255));
+    /// assert_eq!("0000080", key(256));
+    /// assert_eq!("03LNJ8L", key(123456789));
+    /// assert!(key(255) < key(256));
+    /// ```
+    pub const BASE32HEX: &'static Self = &Self::new_unwrap(b"0123456789ABCDEFGHIJKLMNOPQRSTUV");
+
+    /// The Geohash base32 alphabet, digits and lowercase letters without `a`, `i`, `l` and `o`.
+    ///
+    /// A geohash is the interleaved longitude and latitude bits, so encoding one with a fixed
+    /// number of characters uses
+    /// [`EncodeBuilder::with_min_width`](crate::encode::EncodeBuilder::with_min_width).
+    ///
+    /// See <https://en.wikipedia.org/wiki/Geohash>
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::GEOHASH;
+    /// assert_eq!("3prm8p", bsx::encode_int(123456789u32).with_alphabet(alpha).into_string());
+    /// assert_eq!("0003prm8p", bsx::encode_int(123456789u32).with_alphabet(alpha).with_min_width(9).into_string());
+    /// assert_eq!(123456789, bsx::decode("0003prm8p").with_alphabet(alpha).into_u128()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const GEOHASH: &'static Self = &Self::new_unwrap(b"0123456789bcdefghjkmnpqrstuvwxyz");
+
+    /// The BIP-173 bech32 character set, decoding case-insensitively.
+    ///
+    /// Strings with a human-readable part and checksum use [`bsx::bech32`](mod@crate::bech32).
+    ///
+    /// See <https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki>
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::BECH32;
+    /// assert_eq!("r4hng4", bsx::encode_int(123456789u32).with_alphabet(alpha).into_string());
+    /// assert_eq!(123456789, bsx::decode("R4HNG4").with_alphabet(alpha).into_u128()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const BECH32: &'static Self =
+        &Self::new_case_insensitive_unwrap(b"qpzry9x8gf2tvdw0s3jn54khce6mua7l");
+}
+
+impl StaticAlphabet<16> {
+    /// The lowercase hexadecimal alphabet, decoding case-insensitively.
+    ///
+    /// Like every other alphabet this treats the input as an integer, with only leading zero bytes
+    /// preserved, so bytes are not always encoded as two characters each as in a hex dump.
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::BASE16;
+    /// assert_eq!("75bcd15", bsx::encode_int(123456789u32).with_alphabet(alpha).into_string());
+    /// assert_eq!("f", bsx::encode([0x0f]).with_alphabet(alpha).into_string());
+    /// assert_eq!("0f", bsx::encode([0x00, 0x0f]).with_alphabet(alpha).into_string());
+    /// assert_eq!("f00", bsx::encode([0x0f, 0x00]).with_alphabet(alpha).into_string());
+    /// assert_eq!(vec![0x07, 0x5b, 0xcd, 0x15], bsx::decode("75BCD15").with_alphabet(alpha).into_vec()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const BASE16: &'static Self = &Self::new_case_insensitive_unwrap(b"0123456789abcdef");
+
+    /// The uppercase hexadecimal alphabet, decoding case-insensitively.
+    ///
+    /// See [`Self::BASE16`] for how this differs from a hex dump.
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::BASE16_UPPER;
+    /// assert_eq!("75BCD15", bsx::encode_int(123456789u32).with_alphabet(alpha).into_string());
+    /// assert_eq!(vec![0x07, 0x5b, 0xcd, 0x15], bsx::decode("75bcd15").with_alphabet(alpha).into_vec()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const BASE16_UPPER: &'static Self = &Self::new_case_insensitive_unwrap(b"0123456789ABCDEF");
+}
+
+impl StaticAlphabet<36> {
+    /// The common base36 alphabet of digits then lowercase letters, decoding case-insensitively,
+    /// as used for human-readable order numbers and DNS-safe identifiers.
+    ///
+    /// Use [`EncodeBuilder::uppercase`](crate::encode::EncodeBuilder::uppercase) for uppercase
+    /// output.
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::BASE36;
+    /// assert_eq!("21i3v9", bsx::encode_int(123456789u32).with_alphabet(alpha).into_string());
+    /// assert_eq!("21I3V9", bsx::encode_int(123456789u32).with_alphabet(alpha).uppercase().into_string());
+    /// assert_eq!(123456789, bsx::decode("21I3v9").with_alphabet(alpha).into_u128()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const BASE36: &'static Self =
+        &Self::new_case_insensitive_unwrap(b"0123456789abcdefghijklmnopqrstuvwxyz");
+}
+
+impl StaticAlphabet<45> {
+    /// The RFC 9285 base45 alphabet, designed to fit the QR code alphanumeric mode.
+    ///
+    /// The RFC's 2 byte to 3 character blocks are in [`bsx::base45`](mod@crate::base45).
+    ///
+    /// See <https://datatracker.ietf.org/doc/html/rfc9285>
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::BASE45;
+    /// assert_eq!("1F/L", bsx::encode_int(123456u32).with_alphabet(alpha).into_string());
+    /// assert_eq!(123456, bsx::decode("1F/L").with_alphabet(alpha).into_u128()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const BASE45: &'static Self =
+        &Self::new_unwrap(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:");
+}
+
+impl StaticAlphabet<62> {
+    /// The common base62 alphabet of digits, uppercase then lowercase letters, as used by many
+    /// URL shorteners and API keys.
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::BASE62;
+    /// assert_eq!("8M0kX", bsx::encode_int(123456789u32).with_alphabet(alpha).into_string());
+    /// assert_eq!(u64::MAX, bsx::decode("LygHa16AHYF").with_alphabet(alpha).into_u64()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const BASE62: &'static Self =
+        &Self::new_unwrap(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz");
+}
+
+impl StaticAlphabet<64> {
+    /// The RFC 4648 base64 alphabet.
+    ///
+    /// Unlike the other alphabets this packs the bits from the start of the input as RFC 4648
+    /// does, rather than treating the input as a big-endian integer. Use
+    /// [`EncodeBuilder::with_padding`](crate::encode::EncodeBuilder::with_padding) and
+    /// [`DecodeBuilder::with_padding`](crate::decode::DecodeBuilder::with_padding) for `=`
+    /// padded strings.
+    ///
+    /// See <https://datatracker.ietf.org/doc/html/rfc4648#section-4>
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::BASE64;
+    /// assert_eq!("aGVsbG8", bsx::encode(b"hello").with_alphabet(alpha).into_string());
+    /// assert_eq!(b"hello", &*bsx::decode("aGVsbG8").with_alphabet(alpha).into_vec()?);
+    /// assert_eq!("+/+/", bsx::encode([0xfb, 0xff, 0xbf]).with_alphabet(alpha).into_string());
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const BASE64: &'static Self =
+        &Self::new_unwrap(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/")
+            .packed();
+
+    /// The RFC 4648 URL and filename safe base64 alphabet.
+    ///
+    /// This packs the bits from the start of the input the same as [`Self::BASE64`].
+    ///
+    /// See <https://datatracker.ietf.org/doc/html/rfc4648#section-5>
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::BASE64URL;
+    /// assert_eq!("-_-_", bsx::encode([0xfb, 0xff, 0xbf]).with_alphabet(alpha).into_string());
+    /// assert_eq!(vec![0xfb, 0xff, 0xbf], bsx::decode("-_-_").with_alphabet(alpha).into_vec()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const BASE64URL: &'static Self =
+        &Self::new_unwrap(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_")
+            .packed();
+}
+
+impl StaticAlphabet<85> {
+    /// The original btoa/Adobe Ascii85 alphabet of the characters `!` to `u`.
+    ///
+    /// btoa style output with 4 byte blocks and `z` for zeros is [`bsx::a85`](mod@crate::a85).
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::ASCII85;
+    /// assert_eq!("#@#Ff", bsx::encode_int(123456789u32).with_alphabet(alpha).into_string());
+    /// assert_eq!(u64::MAX, bsx::decode("pW[#knfs9!").with_alphabet(alpha).into_u64()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const ASCII85: &'static Self = &Self::new_unwrap(&{
+        let mut base = [0; 85];
+        let mut i = 0;
+        while i < 85 {
+            base[i] = b'!' + i as u8;
+            i += 1;
+        }
+        base
+    });
+
+    /// ZeroMQ's Z85 alphabet, avoiding quotes and backslashes so it is safe to embed in source
+    /// code and XML.
+    ///
+    /// ZeroMQ's 4 byte blocks are [`bsx::a85`](mod@crate::a85) with `Variant::Z85`.
+    ///
+    /// See <https://rfc.zeromq.org/spec/32/>
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::Z85;
+    /// assert_eq!("2v2B/", bsx::encode_int(123456789u32).with_alphabet(alpha).into_string());
+    /// assert_eq!(u64::MAX, bsx::decode("{SW2>[/%o0").with_alphabet(alpha).into_u64()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const Z85: &'static Self = &Self::new_unwrap(
+        b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#",
+    );
+}
+
+impl StaticAlphabet<91> {
+    /// The basE91 alphabet of all printable ASCII except space, `-`, `\` and `'`.
+    ///
+    /// The 13 and 14 bit grouped encoding itself is [`bsx::base91`](mod@crate::base91).
+    ///
+    /// See <http://base91.sourceforge.net/>
+    ///
+    /// ```rust
+    /// let alpha = bsx::StaticAlphabet::BASE91;
+    /// assert_eq!("B.;oB", bsx::encode_int(123456789u32).with_alphabet(alpha).into_string());
+    /// assert_eq!(u64::MAX, bsx::decode("rJ&z5M]?zP").with_alphabet(alpha).into_u64()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub const BASE91: &'static Self = &Self::new_unwrap(
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!#$%&()*+,./:;<=>?@[]^_`{|}~\"",
+    );
+}
+
+impl<const LEN: usize> sealed::Sealed for StaticAlphabet<LEN> {
+    fn encode_into(&self, input: &[u8], output: &mut [MaybeUninit<u8>]) -> encode::Result<usize> {
+        if self.bit_packed {
+            return bits::encode_packed(input, output, &self.encode);
+        }
+        encode::encode_into(input, output, ConstLen::<LEN>, &self.encode)
+    }
+
+    fn decode_into(&self, input: &[u8], output: &mut [u8]) -> decode::Result<usize> {
+        if self.bit_packed {
+            return self.decode_iter(&mut input.iter().copied().enumerate(), output);
+        }
+        decode::decode_into(input, output, ConstLen::<LEN>, &self.decode)
+    }
+
+    fn decode_iter(
+        &self,
+        input: &mut dyn Iterator<Item = (usize, u8)>,
+        output: &mut [u8],
+    ) -> decode::Result<usize> {
+        if self.bit_packed {
+            return bits::decode_packed(input, output, &self.decode, LEN.trailing_zeros());
+        }
+        decode::decode_iter(input, output, ConstLen::<LEN>, &self.decode)
+    }
+
+    fn bit_packed(&self) -> bool {
+        self.bit_packed
+    }
+}
 
 impl<const LEN: usize> Alphabet for StaticAlphabet<LEN> {
     fn len(&self) -> usize {
@@ -289,12 +1325,47 @@ impl<const LEN: usize> fmt::Debug for StaticAlphabet<LEN> {
         if let Ok(s) = core::str::from_utf8(&self.encode) {
             f.debug_tuple("StaticAlphabet").field(&s).finish()
         } else {
-            unreachable!()
+            f.debug_tuple("StaticAlphabet")
+                .field(&format_args!("b\"{}\"", self.encode.escape_ascii()))
+                .finish()
         }
     }
 }
 
-impl<A> sealed::Sealed for DynamicAlphabet<A> {}
+impl<A: AsRef<[u8]>> sealed::Sealed for DynamicAlphabet<A> {
+    fn encode_into(&self, input: &[u8], output: &mut [MaybeUninit<u8>]) -> encode::Result<usize> {
+        let encode = self.encode.as_ref();
+        if self.bit_packed {
+            return bits::encode_packed(input, output, encode);
+        }
+        encode::encode_into(input, output, encode.len(), encode)
+    }
+
+    fn decode_into(&self, input: &[u8], output: &mut [u8]) -> decode::Result<usize> {
+        if self.bit_packed {
+            return self.decode_iter(&mut input.iter().copied().enumerate(), output);
+        }
+        let encode = self.encode.as_ref();
+        decode::decode_into(input, output, encode.len(), &self.decode)
+    }
+
+    fn decode_iter(
+        &self,
+        input: &mut dyn Iterator<Item = (usize, u8)>,
+        output: &mut [u8],
+    ) -> decode::Result<usize> {
+        let encode = self.encode.as_ref();
+        if self.bit_packed {
+            let bits = encode.len().trailing_zeros();
+            return crate::bits::decode_packed(input, output, &self.decode, bits);
+        }
+        decode::decode_iter(input, output, encode.len(), &self.decode)
+    }
+
+    fn bit_packed(&self) -> bool {
+        self.bit_packed
+    }
+}
 
 impl<A: AsRef<[u8]>> Alphabet for DynamicAlphabet<A> {
     fn len(&self) -> usize {
@@ -315,8 +1386,204 @@ impl<A: AsRef<[u8]>> fmt::Debug for DynamicAlphabet<A> {
         if let Ok(s) = core::str::from_utf8(self.encode.as_ref()) {
             f.debug_tuple("DynamicAlphabet").field(&s).finish()
         } else {
-            unreachable!()
+            f.debug_tuple("DynamicAlphabet")
+                .field(&format_args!(
+                    "b\"{}\"",
+                    self.encode.as_ref().escape_ascii()
+                ))
+                .finish()
+        }
+    }
+}
+
+/// Serializes as the alphabet string, failing for extended alphabets that are not valid UTF-8.
+///
+/// ```rust
+/// let alpha = bsx::DynamicAlphabet::new("0123456789")?;
+/// assert_eq!(r#""0123456789""#, serde_json::to_string(&alpha)?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<A: AsRef<[u8]>> serde::Serialize for DynamicAlphabet<A> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let s = core::str::from_utf8(self.encode.as_ref()).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(s)
+    }
+}
+
+/// Deserializes from the alphabet string with the same validation as [`DynamicAlphabet::new`].
+///
+/// Only the characters are serialized, so alphabets created with
+/// [`DynamicAlphabet::new_case_insensitive`] or [`DynamicAlphabet::with_aliases`] will decode
+/// exactly after a round trip.
+///
+/// ```rust
+/// let alpha: bsx::DynamicAlphabet<Vec<u8>> = serde_json::from_str(r#""0123456789""#)?;
+/// assert_eq!("1234", bsx::encode_int(1234u32).with_alphabet(&alpha).into_string());
+///
+/// let err = serde_json::from_str::<bsx::DynamicAlphabet<Vec<u8>>>(r#""aa""#).unwrap_err();
+/// assert!(err.to_string().starts_with("alphabet contained a duplicate character `a`"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for DynamicAlphabet<Vec<u8>> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = DynamicAlphabet<Vec<u8>>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an alphabet string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                DynamicAlphabet::new(v.as_bytes().to_vec()).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+/// Each digit value mapped to itself, the internal alphabet used by [`CharAlphabet`].
+#[cfg(feature = "alloc")]
+const DIGITS: [u8; 256] = {
+    let mut digits = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        digits[i] = i as u8;
+        i += 1;
+    }
+    digits
+};
+
+#[cfg(feature = "alloc")]
+impl<A: AsRef<[char]>> CharAlphabet<A> {
+    /// Create prepared alphabet, checks that there are no duplicate characters and that there are
+    /// at most 255 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let cyrillic = bsx::CharAlphabet::new(['а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з', 'и', 'й'])?;
+    /// assert_eq!("абее", cyrillic.encode([0x00, 0x9b]));
+    /// # Ok::<(), bsx::alphabet::Error>(())
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// ### Duplicate Character
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     bsx::alphabet::Error::DuplicateCharacter { character: 'あ', first: 0, second: 2 },
+    ///     bsx::CharAlphabet::new(['あ', 'い', 'あ']).unwrap_err());
+    /// ```
+    pub fn new(base: A) -> Result<Self, Error> {
+        let symbols = base.as_ref();
+        if symbols.len() > 255 {
+            return Err(Error::TooLong);
         }
+        if symbols.len() < 2 {
+            return Err(Error::TooShort);
+        }
+
+        let mut decode: Vec<(char, u8)> = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| (c, i as u8))
+            .collect();
+        decode.sort_unstable();
+        if let Some(pair) = decode.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            return Err(Error::DuplicateCharacter {
+                character: pair[0].0,
+                first: pair[0].1.into(),
+                second: pair[1].1.into(),
+            });
+        }
+
+        let digits = DynamicAlphabet::new_extended(&DIGITS[..symbols.len()])?;
+        Ok(Self {
+            encode: base,
+            decode,
+            digits,
+        })
+    }
+
+    /// The alphabet length.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.encode.as_ref().len()
+    }
+
+    /// Encode the given bytes into a new owned string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let hiragana: Vec<char> = ('ぁ'..='ゖ').collect();
+    /// let hiragana = bsx::CharAlphabet::new(hiragana)?;
+    /// assert_eq!("ぁぁおぽづゅ", hiragana.encode([0x00, 0x00, 0x5e, 0x2b, 0x24]));
+    /// # Ok::<(), bsx::alphabet::Error>(())
+    /// ```
+    pub fn encode(&self, input: impl AsRef<[u8]>) -> String {
+        let symbols = self.encode.as_ref();
+        crate::encode(input)
+            .with_alphabet(&self.digits)
+            .into_vec()
+            .into_iter()
+            .map(|digit| symbols[usize::from(digit)])
+            .collect()
+    }
+
+    /// Decode the given string into a new vector of bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let hiragana: Vec<char> = ('ぁ'..='ゖ').collect();
+    /// let hiragana = bsx::CharAlphabet::new(hiragana)?;
+    /// assert_eq!(vec![0x00, 0x00, 0x5e, 0x2b, 0x24], hiragana.decode("ぁぁおぽづゅ")?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// ### Invalid Character
+    ///
+    /// The index is the byte offset of the character in the input.
+    ///
+    /// ```rust
+    /// let hiragana: Vec<char> = ('ぁ'..='ゖ').collect();
+    /// let hiragana = bsx::CharAlphabet::new(hiragana)?;
+    /// assert_eq!(
+    ///     bsx::decode::Error::InvalidCharacter { character: 'カ', index: 6 },
+    ///     hiragana.decode("ぁぁカ").unwrap_err());
+    /// # Ok::<(), bsx::alphabet::Error>(())
+    /// ```
+    pub fn decode(&self, input: impl AsRef<str>) -> decode::Result<Vec<u8>> {
+        let digits = input
+            .as_ref()
+            .char_indices()
+            .map(|(index, character)| {
+                self.decode
+                    .binary_search_by_key(&character, |&(c, _)| c)
+                    .map(|i| self.decode[i].1)
+                    .map_err(|_| decode::Error::InvalidCharacter { character, index })
+            })
+            .collect::<decode::Result<Vec<u8>>>()?;
+        crate::decode(digits).with_alphabet(&self.digits).into_vec()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: AsRef<[char]>> fmt::Debug for CharAlphabet<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s: String = self.encode.as_ref().iter().collect();
+        f.debug_tuple("CharAlphabet").field(&s).finish()
     }
 }
 
@@ -339,11 +1606,39 @@ impl fmt::Display for Error {
             Error::NonAsciiCharacter { index } => {
                 write!(f, "alphabet contained a non-ascii character at {}", index)
             }
+            Error::TooLong => write!(f, "alphabet contained more than 255 characters"),
+            Error::TooShort => write!(f, "alphabet contained fewer than 2 characters"),
+            Error::InvalidAlias { alias, canonical } => write!(
+                f,
+                "alias `{}` was part of the alphabet or `{}` was not",
+                alias, canonical,
+            ),
+            Error::UnknownName => write!(f, "not a known alphabet or custom(...)"),
         }
     }
 }
 
-impl<A: sealed::Sealed + ?Sized> sealed::Sealed for &A {}
+impl<A: sealed::Sealed + ?Sized> sealed::Sealed for &A {
+    fn encode_into(&self, input: &[u8], output: &mut [MaybeUninit<u8>]) -> encode::Result<usize> {
+        (**self).encode_into(input, output)
+    }
+
+    fn decode_into(&self, input: &[u8], output: &mut [u8]) -> decode::Result<usize> {
+        (**self).decode_into(input, output)
+    }
+
+    fn decode_iter(
+        &self,
+        input: &mut dyn Iterator<Item = (usize, u8)>,
+        output: &mut [u8],
+    ) -> decode::Result<usize> {
+        (**self).decode_iter(input, output)
+    }
+
+    fn bit_packed(&self) -> bool {
+        (**self).bit_packed()
+    }
+}
 
 impl<A: Alphabet + ?Sized> Alphabet for &A {
     fn len(&self) -> usize {
@@ -366,7 +1661,7 @@ const _: () = {
 };
 
 #[test]
-#[should_panic]
+#[should_panic(expected = "alphabet contained a duplicate character")]
 fn test_new_unwrap_does_panic() {
     StaticAlphabet::new_unwrap(b"aa");
 }
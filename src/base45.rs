@@ -0,0 +1,267 @@
+//! Support for the [RFC 9285](https://datatracker.ietf.org/doc/html/rfc9285) base45 encoding, as
+//! used in QR codes such as the EU Digital COVID Certificate.
+//!
+//! Rather than treating the whole input as an integer, the RFC encodes each pair of bytes as three
+//! characters (and a trailing single byte as two characters), least significant digit first.
+//!
+//! ```rust
+//! assert_eq!("BB8", bsx::base45::encode_to_array::<3>(b"AB")?.as_str());
+//! assert_eq!(*b"ietf!", bsx::base45::decode_to_array::<5>("QED8WEX0")?);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    encode::{self as encoding, EncodedArray},
+    Alphabet, StaticAlphabet,
+};
+
+/// The base45 alphabet, this can also be used as a normal [`Alphabet`] to treat the whole input as
+/// an integer.
+pub const ALPHABET: &StaticAlphabet<45> = StaticAlphabet::BASE45;
+
+/// A specialized [`Result`](core::result::Result) type for [`bsx::base45`](module@crate::base45)
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Errors that could occur when decoding a base45 string.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The output buffer was too small to contain the entire input.
+    BufferTooSmall {
+        /// A buffer length that is large enough for the decoded output, the [`decoded_len`] of the input, so may be more
+        /// than the exact decoded length.
+        required: usize,
+    },
+
+    /// The input length left a single character at the end, which can't encode a byte, or did not
+    /// decode to the requested array length.
+    InvalidLength {
+        /// The length of the input.
+        len: usize,
+    },
+
+    /// The input contained a character that was not part of the base45 alphabet.
+    InvalidCharacter {
+        /// The unexpected character.
+        character: char,
+        /// The (byte) index in the input string the character was at.
+        index: usize,
+    },
+
+    /// The input contained a multi-byte (or non-utf8) character.
+    NonAsciiCharacter {
+        /// The (byte) index in the input string the start of the character was at.
+        index: usize,
+    },
+
+    /// A group of characters represented a value too large for the bytes it encodes.
+    Overflow {
+        /// The (byte) index in the input string the group started at.
+        index: usize,
+    },
+}
+
+/// The length of the base45 encoding of `len` bytes.
+///
+/// ```rust
+/// assert_eq!(8, bsx::base45::encoded_len(5));
+/// ```
+pub const fn encoded_len(len: usize) -> usize {
+    len / 2 * 3 + len % 2 * 2
+}
+
+/// An upper bound on the length of the bytes decoded from `len` characters.
+///
+/// ```rust
+/// assert_eq!(5, bsx::base45::decoded_len(8));
+/// ```
+pub const fn decoded_len(len: usize) -> usize {
+    len / 3 * 2 + len % 3 / 2
+}
+
+/// Encode `input` into `output`, returning the length written.
+///
+/// ```rust
+/// let mut output = [0; 8];
+/// assert_eq!(8, bsx::base45::encode_into(b"ietf!", &mut output)?);
+/// assert_eq!(b"QED8WEX0", &output);
+/// # Ok::<(), bsx::encode::Error>(())
+/// ```
+pub fn encode_into(input: &[u8], output: &mut [u8]) -> encoding::Result<usize> {
+    let len = encoded_len(input.len());
+    let output = output
+        .get_mut(..len)
+        .ok_or(encoding::Error::BufferTooSmall { required: len })?;
+    let encode = ALPHABET.encode();
+    for (bytes, chars) in input.chunks(2).zip(output.chunks_mut(3)) {
+        let mut value = bytes
+            .iter()
+            .fold(0usize, |value, &byte| value << 8 | usize::from(byte));
+        for c in chars {
+            *c = encode[value % 45];
+            value /= 45;
+        }
+    }
+    Ok(len)
+}
+
+/// Encode `input` into a new fixed capacity stack allocated string.
+///
+/// ```rust
+/// assert_eq!("%69 VD92EX0", bsx::base45::encode_to_array::<16>(b"Hello!!")?.as_str());
+/// assert_eq!(
+///     bsx::encode::Error::BufferTooSmall { required: 11 },
+///     bsx::base45::encode_to_array::<4>(b"Hello!!").unwrap_err());
+/// # Ok::<(), bsx::encode::Error>(())
+/// ```
+pub fn encode_to_array<const N: usize>(input: &[u8]) -> encoding::Result<EncodedArray<N>> {
+    let mut bytes = [0; N];
+    let len = encode_into(input, &mut bytes)?;
+    EncodedArray::from_utf8(bytes, len)
+}
+
+/// Encode `input` into a new owned string.
+///
+/// ```rust
+/// assert_eq!("%69 VD92EX0", bsx::base45::encode(b"Hello!!"));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub fn encode(input: impl AsRef<[u8]>) -> String {
+    let input = input.as_ref();
+    let mut output = alloc::vec![0; encoded_len(input.len())];
+    encode_into(input, &mut output).unwrap();
+    // The alphabet is pure ASCII so this is always valid
+    String::from_utf8(output).unwrap()
+}
+
+/// Decode `input` into `output`, returning the length written.
+///
+/// ## Errors
+///
+/// ### Invalid Length
+///
+/// ```rust
+/// assert_eq!(
+///     bsx::base45::Error::InvalidLength { len: 4 },
+///     bsx::base45::decode_into("QED8", &mut [0; 4]).unwrap_err());
+/// ```
+///
+/// ### Overflow
+///
+/// ```rust
+/// assert_eq!(
+///     bsx::base45::Error::Overflow { index: 3 },
+///     bsx::base45::decode_into("QEDGGW", &mut [0; 4]).unwrap_err());
+/// ```
+pub fn decode_into(input: impl AsRef<[u8]>, output: &mut [u8]) -> Result<usize> {
+    let input = input.as_ref();
+    if input.len() % 3 == 1 {
+        return Err(Error::InvalidLength { len: input.len() });
+    }
+    let len = decoded_len(input.len());
+    let output = output
+        .get_mut(..len)
+        .ok_or(Error::BufferTooSmall { required: len })?;
+    let decode = ALPHABET.decode();
+    for (i, (chars, bytes)) in input.chunks(3).zip(output.chunks_mut(2)).enumerate() {
+        let mut value = 0;
+        for (j, &c) in chars.iter().enumerate().rev() {
+            let index = i * 3 + j;
+            let digit = decode[c as usize];
+            if digit == 0xFF && c > 127 {
+                return Err(Error::NonAsciiCharacter { index });
+            }
+            if digit == 0xFF {
+                return Err(Error::InvalidCharacter {
+                    character: c as char,
+                    index,
+                });
+            }
+            value = value * 45 + usize::from(digit);
+        }
+        if value >> (8 * bytes.len()) != 0 {
+            return Err(Error::Overflow { index: i * 3 });
+        }
+        for (k, byte) in bytes.iter_mut().rev().enumerate() {
+            *byte = (value >> (8 * k)) as u8;
+        }
+    }
+    Ok(len)
+}
+
+/// Decode `input` into a fixed size array, requiring that the decoded data is exactly `N` bytes.
+///
+/// ```rust
+/// assert_eq!(*b"AB", bsx::base45::decode_to_array::<2>("BB8")?);
+/// assert_eq!(
+///     bsx::base45::Error::InvalidLength { len: 3 },
+///     bsx::base45::decode_to_array::<1>("BB8").unwrap_err());
+/// # Ok::<(), bsx::base45::Error>(())
+/// ```
+pub fn decode_to_array<const N: usize>(input: impl AsRef<[u8]>) -> Result<[u8; N]> {
+    let input = input.as_ref();
+    if decoded_len(input.len()) != N {
+        return Err(Error::InvalidLength { len: input.len() });
+    }
+    let mut output = [0; N];
+    decode_into(input, &mut output)?;
+    Ok(output)
+}
+
+/// Decode `input` into a new vector of bytes.
+///
+/// ```rust
+/// assert_eq!(b"base-45", &*bsx::base45::decode("UJCLQE7W581")?);
+/// # Ok::<(), bsx::base45::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub fn decode(input: impl AsRef<[u8]>) -> Result<Vec<u8>> {
+    let input = input.as_ref();
+    let mut output = alloc::vec![0; decoded_len(input.len())];
+    let len = decode_into(input, &mut output)?;
+    output.truncate(len);
+    Ok(output)
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::BufferTooSmall { required } => write!(
+                f,
+                "buffer provided to decode string into was too small, {} bytes are required",
+                required
+            ),
+            Error::InvalidLength { len } => write!(
+                f,
+                "provided string was {} characters long, which is not a valid base45 length",
+                len
+            ),
+            Error::InvalidCharacter { character, index } => write!(
+                f,
+                "provided string contained invalid character {:?} at byte {}",
+                character, index
+            ),
+            Error::NonAsciiCharacter { index } => write!(
+                f,
+                "provided string contained non-ascii character starting at byte {}",
+                index
+            ),
+            Error::Overflow { index } => write!(
+                f,
+                "provided string contained a group too large for its bytes at byte {}",
+                index
+            ),
+        }
+    }
+}
@@ -0,0 +1,243 @@
+//! Support for the [basE91](http://base91.sourceforge.net/) encoding.
+//!
+//! Rather than treating the whole input as an integer, basE91 takes 13 or 14 bits at a time from
+//! the input and encodes each group as two characters, giving output around 23% larger than the
+//! input compared to 33% for base64.
+//!
+//! ```rust
+//! assert_eq!(">OwJh>}AQ;r@@Y?F", bsx::base91::encode(b"Hello, World!"));
+//! assert_eq!(b"Hello, World!", &*bsx::base91::decode(">OwJh>}AQ;r@@Y?F")?);
+//! # Ok::<(), bsx::base91::Error>(())
+//! ```
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::{encode as encoding, Alphabet, StaticAlphabet};
+
+/// The basE91 alphabet, this can also be used as a normal [`Alphabet`] to treat the whole input as
+/// an integer.
+pub const ALPHABET: &StaticAlphabet<91> = StaticAlphabet::BASE91;
+
+/// A specialized [`Result`](core::result::Result) type for [`bsx::base91`](module@crate::base91)
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Errors that could occur when decoding a basE91 string.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The output buffer was too small to contain the entire input.
+    BufferTooSmall {
+        /// A buffer length that is large enough for the decoded output, the [`decoded_len`] of the input, so may be more
+        /// than the exact decoded length.
+        required: usize,
+    },
+
+    /// The input contained a character that was not part of the basE91 alphabet.
+    InvalidCharacter {
+        /// The unexpected character.
+        character: char,
+        /// The (byte) index in the input string the character was at.
+        index: usize,
+    },
+
+    /// The input contained a multi-byte (or non-utf8) character.
+    NonAsciiCharacter {
+        /// The (byte) index in the input string the start of the character was at.
+        index: usize,
+    },
+}
+
+/// An upper bound on the length of the basE91 encoding of `len` bytes.
+///
+/// ```rust
+/// assert_eq!(16, bsx::base91::encoded_len(13));
+/// ```
+pub const fn encoded_len(len: usize) -> usize {
+    // Split to avoid overflowing on `len * 8` for long inputs
+    len / 13 * 16 + (len % 13 * 8).div_ceil(13) * 2
+}
+
+/// An upper bound on the length of the bytes decoded from `len` characters.
+///
+/// ```rust
+/// assert_eq!(14, bsx::base91::decoded_len(16));
+/// ```
+pub const fn decoded_len(len: usize) -> usize {
+    len / 2 * 7 / 4 + len % 2
+}
+
+/// Encode `input` into `output`, returning the length written.
+///
+/// ```rust
+/// let mut output = [0; 8];
+/// assert_eq!(5, bsx::base91::encode_into(b"test", &mut output)?);
+/// assert_eq!(b"fPNKd", &output[..5]);
+/// # Ok::<(), bsx::encode::Error>(())
+/// ```
+pub fn encode_into(input: &[u8], output: &mut [u8]) -> encoding::Result<usize> {
+    let encode = ALPHABET.encode();
+    let mut len = 0;
+    let mut write = |value: u32| -> encoding::Result<()> {
+        *output.get_mut(len).ok_or(encoding::Error::BufferTooSmall {
+            required: encoded_len(input.len()),
+        })? = encode[value as usize];
+        len += 1;
+        Ok(())
+    };
+
+    let (mut bits, mut count) = (0u32, 0);
+    for &byte in input {
+        bits |= u32::from(byte) << count;
+        count += 8;
+        if count > 13 {
+            // Take 14 bits when the low 13 are small enough that the extra bit still fits in two
+            // characters (88 + 8192 < 91 * 91)
+            let mut value = bits & 0x1FFF;
+            if value > 88 {
+                bits >>= 13;
+                count -= 13;
+            } else {
+                value = bits & 0x3FFF;
+                bits >>= 14;
+                count -= 14;
+            }
+            write(value % 91)?;
+            write(value / 91)?;
+        }
+    }
+
+    if count > 0 {
+        write(bits % 91)?;
+        if count > 7 || bits > 90 {
+            write(bits / 91)?;
+        }
+    }
+
+    Ok(len)
+}
+
+/// Encode `input` into a new owned string.
+///
+/// ```rust
+/// assert_eq!("B\"B\"#", bsx::base91::encode([0xff; 4]));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub fn encode(input: impl AsRef<[u8]>) -> String {
+    let input = input.as_ref();
+    let mut output = alloc::vec![0; encoded_len(input.len())];
+    let len = encode_into(input, &mut output).unwrap();
+    output.truncate(len);
+    // The alphabet is pure ASCII so this is always valid
+    String::from_utf8(output).unwrap()
+}
+
+/// Decode `input` into `output`, returning the length written.
+///
+/// ```rust
+/// let mut output = [0; 4];
+/// assert_eq!(4, bsx::base91::decode_into("fPNKd", &mut output)?);
+/// assert_eq!(b"test", &output);
+/// # Ok::<(), bsx::base91::Error>(())
+/// ```
+///
+/// ## Errors
+///
+/// ### Invalid Character
+///
+/// ```rust
+/// assert_eq!(
+///     bsx::base91::Error::InvalidCharacter { character: '\'', index: 2 },
+///     bsx::base91::decode_into("fP'Kd", &mut [0; 4]).unwrap_err());
+/// ```
+pub fn decode_into(input: impl AsRef<[u8]>, output: &mut [u8]) -> Result<usize> {
+    let input = input.as_ref();
+    let decode = ALPHABET.decode();
+    let mut len = 0;
+    let mut write = |byte: u32| -> Result<()> {
+        *output.get_mut(len).ok_or(Error::BufferTooSmall {
+            required: decoded_len(input.len()),
+        })? = byte as u8;
+        len += 1;
+        Ok(())
+    };
+
+    let (mut bits, mut count) = (0u32, 0);
+    let mut pending = None;
+    for (index, &c) in input.iter().enumerate() {
+        let digit = decode[c as usize];
+        if digit == 0xFF && c > 127 {
+            return Err(Error::NonAsciiCharacter { index });
+        }
+        if digit == 0xFF {
+            return Err(Error::InvalidCharacter {
+                character: c as char,
+                index,
+            });
+        }
+        match pending.take() {
+            None => pending = Some(u32::from(digit)),
+            Some(low) => {
+                let value = low + u32::from(digit) * 91;
+                bits |= value << count;
+                count += if value & 0x1FFF > 88 { 13 } else { 14 };
+                while count > 7 {
+                    write(bits & 0xFF)?;
+                    bits >>= 8;
+                    count -= 8;
+                }
+            }
+        }
+    }
+
+    if let Some(value) = pending {
+        write((bits | value << count) & 0xFF)?;
+    }
+
+    Ok(len)
+}
+
+/// Decode `input` into a new vector of bytes.
+///
+/// ```rust
+/// assert_eq!(vec![0xff; 4], bsx::base91::decode("B\"B\"#")?);
+/// # Ok::<(), bsx::base91::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub fn decode(input: impl AsRef<[u8]>) -> Result<Vec<u8>> {
+    let input = input.as_ref();
+    let mut output = alloc::vec![0; decoded_len(input.len())];
+    let len = decode_into(input, &mut output)?;
+    output.truncate(len);
+    Ok(output)
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::BufferTooSmall { required } => write!(
+                f,
+                "buffer provided to decode string into was too small, {} bytes are required",
+                required
+            ),
+            Error::InvalidCharacter { character, index } => write!(
+                f,
+                "provided string contained invalid character {:?} at byte {}",
+                character, index
+            ),
+            Error::NonAsciiCharacter { index } => write!(
+                f,
+                "provided string contained non-ascii character starting at byte {}",
+                index
+            ),
+        }
+    }
+}
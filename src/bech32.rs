@@ -0,0 +1,371 @@
+//! Support for the [BIP-173](https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki)
+//! bech32 and [BIP-350](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki) bech32m
+//! checksummed strings, as used for Bitcoin segwit and Cosmos addresses.
+//!
+//! A bech32 string is a human-readable part, the separator `1`, then the data as 5-bit values
+//! followed by a 6 character checksum. Unlike the rest of this crate the data bytes are packed
+//! into the 5-bit values from the start rather than being treated as an integer.
+//!
+//! ```rust
+//! use bsx::bech32::Variant;
+//!
+//! let encoded = bsx::bech32::encode("cosmos", [0x01, 0x02, 0x03], Variant::Bech32)?;
+//! assert_eq!("cosmos1qypqxw5atfh", encoded);
+//! assert_eq!(
+//!     ("cosmos".to_owned(), vec![0x01, 0x02, 0x03], Variant::Bech32),
+//!     bsx::bech32::decode(&encoded)?);
+//! # Ok::<(), bsx::bech32::Error>(())
+//! ```
+
+use core::fmt;
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{bits::Regroup, Alphabet, StaticAlphabet};
+
+/// The bech32 character set.
+pub const ALPHABET: &StaticAlphabet<32> = StaticAlphabet::BECH32;
+
+/// The maximum length of a bech32 string.
+pub const MAX_LEN: usize = 90;
+
+/// The number of characters in the checksum.
+const CHECKSUM_LEN: usize = 6;
+
+/// The checksum variants.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Variant {
+    /// The original BIP-173 checksum, used for segwit version 0 addresses.
+    Bech32,
+    /// The BIP-350 checksum, used for segwit version 1 and later addresses.
+    Bech32m,
+}
+
+impl Variant {
+    /// The value the checksum polymod must equal.
+    const fn constant(self) -> u32 {
+        match self {
+            Variant::Bech32 => 1,
+            Variant::Bech32m => 0x2bc8_30a3,
+        }
+    }
+}
+
+/// A specialized [`Result`](core::result::Result) type for [`bsx::bech32`](module@crate::bech32)
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Errors that could occur when encoding or decoding a bech32 string.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The human-readable part was empty, or contained characters outside of ASCII `33` to `126`.
+    InvalidHrp,
+
+    /// The string was longer than [`MAX_LEN`].
+    TooLong {
+        /// The length of the string.
+        len: usize,
+    },
+
+    /// The input did not contain the `1` separator followed by at least the checksum.
+    MissingSeparator,
+
+    /// The input contained both uppercase and lowercase characters.
+    MixedCase,
+
+    /// The input contained a character that was not part of the bech32 character set.
+    InvalidCharacter {
+        /// The unexpected character.
+        character: char,
+        /// The (byte) index in the input string the character was at.
+        index: usize,
+    },
+
+    /// The input contained a multi-byte (or non-utf8) character.
+    NonAsciiCharacter {
+        /// The (byte) index in the input string the start of the character was at.
+        index: usize,
+    },
+
+    /// A data value was not a 5-bit value.
+    InvalidValue {
+        /// The index of the value in the data.
+        index: usize,
+    },
+
+    /// The data values did not pack into whole bytes with zero padding.
+    InvalidPadding,
+
+    /// The checksum did not match either variant.
+    InvalidChecksum,
+}
+
+/// Pack `bytes` into 5-bit values from the start, zero padding the last value.
+///
+/// ```rust
+/// assert_eq!(vec![0x1f, 0x1c], bsx::bech32::to_values([0xff]));
+/// ```
+pub fn to_values(bytes: impl AsRef<[u8]>) -> Vec<u8> {
+    convert_bits(bytes.as_ref(), 8, 5, true).unwrap()
+}
+
+/// Unpack 5-bit values into bytes, the left over bits must be fewer than 5 and all zero.
+///
+/// ```rust
+/// assert_eq!(vec![0xff], bsx::bech32::from_values([0x1f, 0x1c])?);
+/// assert_eq!(
+///     bsx::bech32::Error::InvalidPadding,
+///     bsx::bech32::from_values([0x1f, 0x1d]).unwrap_err());
+/// # Ok::<(), bsx::bech32::Error>(())
+/// ```
+pub fn from_values(values: impl AsRef<[u8]>) -> Result<Vec<u8>> {
+    let values = values.as_ref();
+    if let Some(index) = values.iter().position(|&value| value >= 32) {
+        return Err(Error::InvalidValue { index });
+    }
+    convert_bits(values, 5, 8, false).ok_or(Error::InvalidPadding)
+}
+
+/// Regroup `from` bit values into `to` bit values, returning `None` if not padding and the left
+/// over bits are not valid padding.
+fn convert_bits(input: &[u8], from: u32, to: u32, pad: bool) -> Option<Vec<u8>> {
+    let mut output = Vec::with_capacity((input.len() * from as usize).div_ceil(to as usize));
+    let mut regroup = Regroup::new(from, to);
+    for &value in input {
+        regroup.push(value, |value| output.push(value));
+    }
+    if pad {
+        output.extend(regroup.padded());
+    } else {
+        let (value, bits) = regroup.remainder();
+        if bits >= from || value != 0 {
+            return None;
+        }
+    }
+    Some(output)
+}
+
+/// The BCH checksum polynomial over the 5-bit `values`, continuing from `chk`.
+fn polymod(chk: u32, values: impl IntoIterator<Item = u8>) -> u32 {
+    const GENERATOR: [u32; 5] = [
+        0x3b6a_57b2,
+        0x2650_8e6d,
+        0x1ea1_19fa,
+        0x3d42_33dd,
+        0x2a14_62b3,
+    ];
+    values.into_iter().fold(chk, |chk, value| {
+        let top = chk >> 25;
+        let chk = (chk & 0x1ff_ffff) << 5 ^ u32::from(value);
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| top >> i & 1 == 1)
+            .fold(chk, |chk, (_, generator)| chk ^ generator)
+    })
+}
+
+/// The checksum polymod of the lowercase `hrp`, which is expanded into its high and low bits.
+fn hrp_polymod(hrp: &[u8]) -> u32 {
+    let high = hrp.iter().map(|&c| c >> 5);
+    let low = hrp.iter().map(|&c| c & 0x1f);
+    polymod(1, high.chain([0]).chain(low))
+}
+
+/// Check that `hrp` is a valid human-readable part.
+fn check_hrp(hrp: &[u8]) -> Result<()> {
+    if hrp.is_empty() || hrp.iter().any(|&c| !(33..=126).contains(&c)) {
+        return Err(Error::InvalidHrp);
+    }
+    Ok(())
+}
+
+/// Encode `data` bytes, packed into 5-bit values, with the human-readable part `hrp`.
+///
+/// The output is lowercase, uppercase is equally valid and can be produced with
+/// [`str::to_ascii_uppercase`] for QR codes.
+///
+/// ```rust
+/// use bsx::bech32::Variant;
+///
+/// assert_eq!("a12uel5l", bsx::bech32::encode("A", b"", Variant::Bech32)?);
+/// assert_eq!(
+///     bsx::bech32::Error::InvalidHrp,
+///     bsx::bech32::encode("", b"", Variant::Bech32).unwrap_err());
+/// # Ok::<(), bsx::bech32::Error>(())
+/// ```
+pub fn encode(hrp: &str, data: impl AsRef<[u8]>, variant: Variant) -> Result<String> {
+    encode_values(hrp, to_values(data), variant)
+}
+
+/// Encode 5-bit `values` with the human-readable part `hrp`.
+///
+/// This allows encoding data that is not whole bytes, such as a segwit address which is the
+/// witness version followed by the witness program packed into 5-bit values.
+///
+/// ```rust
+/// use bsx::bech32::Variant;
+///
+/// let program = [
+///     0x75, 0x1e, 0x76, 0xe8, 0x19, 0x91, 0x96, 0xd4, 0x54, 0x94,
+///     0x1c, 0x45, 0xd1, 0xb3, 0xa3, 0x23, 0xf1, 0x43, 0x3b, 0xd6,
+/// ];
+/// let mut values = vec![0];
+/// values.extend(bsx::bech32::to_values(program));
+/// assert_eq!(
+///     "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+///     bsx::bech32::encode_values("bc", &values, Variant::Bech32)?);
+/// # Ok::<(), bsx::bech32::Error>(())
+/// ```
+pub fn encode_values(hrp: &str, values: impl AsRef<[u8]>, variant: Variant) -> Result<String> {
+    let values = values.as_ref();
+    let hrp = hrp.as_bytes();
+    check_hrp(hrp)?;
+    if let Some(index) = values.iter().position(|&value| value >= 32) {
+        return Err(Error::InvalidValue { index });
+    }
+    let len = hrp.len() + 1 + values.len() + CHECKSUM_LEN;
+    if len > MAX_LEN {
+        return Err(Error::TooLong { len });
+    }
+
+    let hrp = hrp.to_ascii_lowercase();
+    // Finishing the polymod with zeros gives the remainder to append
+    let chk = polymod(hrp_polymod(&hrp), values.iter().copied());
+    let chk = polymod(chk, [0; CHECKSUM_LEN]) ^ variant.constant();
+
+    let encode = ALPHABET.encode();
+    let mut output = String::with_capacity(len);
+    output.extend(hrp.iter().map(|&c| c as char));
+    output.push('1');
+    output.extend(
+        values
+            .iter()
+            .map(|&value| encode[usize::from(value)] as char),
+    );
+    output.extend(
+        (0..CHECKSUM_LEN)
+            .rev()
+            .map(|i| encode[(chk >> (5 * i) & 0x1f) as usize] as char),
+    );
+    Ok(output)
+}
+
+/// Decode a bech32 or bech32m string, returning the lowercase human-readable part, the data
+/// unpacked from 5-bit values into bytes, and which checksum variant it used.
+///
+/// ## Errors
+///
+/// ### Invalid Checksum
+///
+/// ```rust
+/// assert_eq!(
+///     bsx::bech32::Error::InvalidChecksum,
+///     bsx::bech32::decode("cosmos1qypqxw5atfm").unwrap_err());
+/// ```
+///
+/// ### Mixed Case
+///
+/// ```rust
+/// assert_eq!(
+///     bsx::bech32::Error::MixedCase,
+///     bsx::bech32::decode("Cosmos1qypqxw5atfh").unwrap_err());
+/// ```
+pub fn decode(input: impl AsRef<[u8]>) -> Result<(String, Vec<u8>, Variant)> {
+    let (hrp, values, variant) = decode_values(input)?;
+    Ok((hrp, from_values(values)?, variant))
+}
+
+/// Decode a bech32 or bech32m string, returning the lowercase human-readable part, the 5-bit
+/// data values, and which checksum variant it used.
+///
+/// ```rust
+/// use bsx::bech32::Variant;
+///
+/// let (hrp, values, variant) =
+///     bsx::bech32::decode_values("BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4")?;
+/// assert_eq!("bc", hrp);
+/// assert_eq!(0, values[0]);
+/// assert_eq!(20, bsx::bech32::from_values(&values[1..])?.len());
+/// assert_eq!(Variant::Bech32, variant);
+/// # Ok::<(), bsx::bech32::Error>(())
+/// ```
+pub fn decode_values(input: impl AsRef<[u8]>) -> Result<(String, Vec<u8>, Variant)> {
+    let input = input.as_ref();
+    if input.len() > MAX_LEN {
+        return Err(Error::TooLong { len: input.len() });
+    }
+    if let Some(index) = input.iter().position(|&c| c > 127) {
+        return Err(Error::NonAsciiCharacter { index });
+    }
+    if input.iter().any(u8::is_ascii_lowercase) && input.iter().any(u8::is_ascii_uppercase) {
+        return Err(Error::MixedCase);
+    }
+    let separator = input
+        .iter()
+        .rposition(|&c| c == b'1')
+        .filter(|&separator| input.len() - separator > CHECKSUM_LEN)
+        .ok_or(Error::MissingSeparator)?;
+
+    let hrp = input[..separator].to_ascii_lowercase();
+    check_hrp(&hrp)?;
+
+    let decode = ALPHABET.decode();
+    let mut values = Vec::with_capacity(input.len() - separator - 1);
+    for (index, &c) in input.iter().enumerate().skip(separator + 1) {
+        let value = decode[c.to_ascii_lowercase() as usize];
+        if value == 0xFF {
+            return Err(Error::InvalidCharacter {
+                character: c as char,
+                index,
+            });
+        }
+        values.push(value);
+    }
+
+    let chk = polymod(hrp_polymod(&hrp), values.iter().copied());
+    let variant = [Variant::Bech32, Variant::Bech32m]
+        .iter()
+        .copied()
+        .find(|variant| chk == variant.constant())
+        .ok_or(Error::InvalidChecksum)?;
+    values.truncate(values.len() - CHECKSUM_LEN);
+
+    // The human-readable part has been checked to be ASCII
+    let hrp = hrp.into_iter().map(char::from).collect();
+    Ok((hrp, values, variant))
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::InvalidHrp => write!(f, "human-readable part was empty or invalid"),
+            Error::TooLong { len } => write!(
+                f,
+                "string was {} characters long, more than the maximum of {}",
+                len, MAX_LEN
+            ),
+            Error::MissingSeparator => write!(f, "provided string was missing the separator"),
+            Error::MixedCase => write!(f, "provided string contained mixed case characters"),
+            Error::InvalidCharacter { character, index } => write!(
+                f,
+                "provided string contained invalid character {:?} at byte {}",
+                character, index
+            ),
+            Error::NonAsciiCharacter { index } => write!(
+                f,
+                "provided string contained non-ascii character starting at byte {}",
+                index
+            ),
+            Error::InvalidValue { index } => {
+                write!(f, "data value at index {} was not a 5-bit value", index)
+            }
+            Error::InvalidPadding => write!(f, "data values had invalid padding"),
+            Error::InvalidChecksum => write!(f, "provided string had an invalid checksum"),
+        }
+    }
+}
@@ -0,0 +1,377 @@
+//! Divide-and-conquer conversions used for large inputs, where the quadratic algorithms in
+//! [`encode`](crate::encode) and [`decode`](crate::decode) become too slow.
+//!
+//! The value is recursively split by precomputed powers of the base, using Karatsuba
+//! multiplication and Newton reciprocal based division, which makes the whole conversion
+//! subquadratic.
+//!
+//! Numbers are represented as little-endian `u32` limbs with no trailing zero limbs.
+
+use alloc::{vec, vec::Vec};
+use core::cmp::Ordering;
+
+use crate::encode::limb_base;
+
+/// Inputs with at least this many bytes are encoded with the divide-and-conquer conversion.
+pub(crate) const ENCODE_THRESHOLD: usize = 2048;
+
+/// Inputs with at least this many characters are decoded with the divide-and-conquer conversion.
+pub(crate) const DECODE_THRESHOLD: usize = 4096;
+
+/// Multiplications where the shorter operand has fewer limbs than this use the schoolbook
+/// algorithm.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Values with at most this many limbs are converted with the quadratic algorithm.
+const BASECASE_LIMBS: usize = 64;
+
+type Limbs = Vec<u32>;
+
+fn trim(mut x: Limbs) -> Limbs {
+    while x.last() == Some(&0) {
+        x.pop();
+    }
+    x
+}
+
+fn trimmed(x: &[u32]) -> &[u32] {
+    &x[..x.iter().rposition(|&limb| limb != 0).map_or(0, |i| i + 1)]
+}
+
+fn from_u64(value: u64) -> Limbs {
+    trim(vec![value as u32, (value >> 32) as u32])
+}
+
+fn cmp(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add(a: &[u32], b: &[u32]) -> Limbs {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut out = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u64;
+    for (i, &limb) in a.iter().enumerate() {
+        carry += u64::from(limb) + u64::from(b.get(i).copied().unwrap_or(0));
+        out.push(carry as u32);
+        carry >>= 32;
+    }
+    out.push(carry as u32);
+    trim(out)
+}
+
+/// `a += b * β^shift`
+fn add_shifted(a: &mut Limbs, b: &[u32], shift: usize) {
+    if b.is_empty() {
+        return;
+    }
+    if a.len() < shift + b.len() {
+        a.resize(shift + b.len(), 0);
+    }
+    let mut carry = 0u64;
+    for (i, limb) in a[shift..].iter_mut().enumerate() {
+        if i >= b.len() && carry == 0 {
+            break;
+        }
+        carry += u64::from(*limb) + u64::from(b.get(i).copied().unwrap_or(0));
+        *limb = carry as u32;
+        carry >>= 32;
+    }
+    if carry > 0 {
+        a.push(carry as u32);
+    }
+}
+
+/// `a -= b`, `a` must not be less than `b`
+fn sub_assign(a: &mut Limbs, b: &[u32]) {
+    let mut borrow = 0i64;
+    for (i, limb) in a.iter_mut().enumerate() {
+        if i >= b.len() && borrow == 0 {
+            break;
+        }
+        let value = i64::from(*limb) - i64::from(b.get(i).copied().unwrap_or(0)) + borrow;
+        *limb = value as u32;
+        borrow = value >> 32;
+    }
+    debug_assert_eq!(borrow, 0);
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+}
+
+fn mul(a: &[u32], b: &[u32]) -> Limbs {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.is_empty() {
+        return Vec::new();
+    }
+
+    if b.len() < KARATSUBA_THRESHOLD {
+        let mut out = vec![0u32; a.len() + b.len()];
+        for (i, &x) in b.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &y) in a.iter().enumerate() {
+                carry += u64::from(x) * u64::from(y) + u64::from(out[i + j]);
+                out[i + j] = carry as u32;
+                carry >>= 32;
+            }
+            out[i + a.len()] = carry as u32;
+        }
+        return trim(out);
+    }
+
+    if b.len() <= a.len() / 2 {
+        // Too unbalanced to split evenly, multiply `b` by each `b` sized piece of `a` instead
+        let mut out = Vec::new();
+        for (i, chunk) in a.chunks(b.len()).enumerate() {
+            add_shifted(&mut out, &mul(trimmed(chunk), b), i * b.len());
+        }
+        return out;
+    }
+
+    let m = a.len() / 2;
+    let (a0, a1) = (trimmed(&a[..m]), &a[m..]);
+    let (b0, b1) = (trimmed(&b[..m]), &b[m..]);
+    let z0 = mul(a0, b0);
+    let z2 = mul(a1, b1);
+    let mut z1 = mul(&add(a0, a1), &add(b0, b1));
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+
+    let mut out = z0;
+    add_shifted(&mut out, &z1, m);
+    add_shifted(&mut out, &z2, 2 * m);
+    out
+}
+
+fn shl_bits(x: &[u32], shift: u32) -> Limbs {
+    if shift == 0 {
+        return x.to_vec();
+    }
+    let mut out = Vec::with_capacity(x.len() + 1);
+    let mut carry = 0;
+    for &limb in x {
+        out.push((limb << shift) | carry);
+        carry = limb >> (32 - shift);
+    }
+    out.push(carry);
+    trim(out)
+}
+
+fn shr_bits(x: &[u32], shift: u32) -> Limbs {
+    if shift == 0 {
+        return x.to_vec();
+    }
+    let mut out = vec![0; x.len()];
+    for (i, &limb) in x.iter().enumerate() {
+        out[i] |= limb >> shift;
+        if i > 0 {
+            out[i - 1] |= limb << (32 - shift);
+        }
+    }
+    trim(out)
+}
+
+/// An approximation of `β^2n / d` where `d` has `n` limbs and the top bit of its most significant
+/// limb set. The result is never greater than the true quotient, and only a few less than it.
+fn reciprocal(d: &[u32]) -> Limbs {
+    let n = d.len();
+    if n == 1 {
+        return from_u64(u64::MAX / u64::from(d[0]));
+    }
+
+    // Taking the reciprocal of the top limbs rounded up gives an initial underestimate with a
+    // little over half the required precision, the extra limbs stop the error of the recursive
+    // reciprocal being squared into the result
+    let h = (n / 2 + 2).min(n - 1);
+    let top = add(&d[n - h..], &[1]);
+    let mut x = vec![0; n - h];
+    if top.len() > h {
+        // The top limbs rounded up to exactly `β^h`, whose reciprocal is `β^h`
+        x.resize(n, 0);
+        x.push(1);
+    } else {
+        x.extend(reciprocal(&top));
+    }
+
+    // A Newton step approaching from below doubles the precision while remaining an
+    // underestimate: x + x * (β^2n - d * x) / β^2n
+    let mut error = vec![0; 2 * n];
+    error.push(1);
+    sub_assign(&mut error, &mul(d, &x));
+    let correction = mul(&x, &error);
+    add(&x, correction.get(2 * n..).unwrap_or(&[]))
+}
+
+/// A power of the base, prepared for repeated division.
+struct Divisor {
+    value: Limbs,
+    shift: u32,
+    normalized: Limbs,
+    reciprocal: Limbs,
+}
+
+impl Divisor {
+    fn new(value: Limbs) -> Self {
+        let shift = value.last().unwrap().leading_zeros();
+        let normalized = shl_bits(&value, shift);
+        let reciprocal = reciprocal(&normalized);
+        Divisor {
+            value,
+            shift,
+            normalized,
+            reciprocal,
+        }
+    }
+
+    /// Divide `x` by this value, `x` must be less than this value squared.
+    fn div_rem(&self, x: &[u32]) -> (Limbs, Limbs) {
+        let n = self.normalized.len();
+        let x = shl_bits(x, self.shift);
+        // Only the top limbs of `x` contribute to the quotient estimate, dropping the rest keeps it
+        // an underestimate and only a few further from the true quotient
+        let mut quotient = mul(x.get(n - 1..).unwrap_or(&[]), &self.reciprocal)
+            .get(n + 1..)
+            .unwrap_or(&[])
+            .to_vec();
+        let mut remainder = x;
+        sub_assign(&mut remainder, &mul(&quotient, &self.normalized));
+        while cmp(&remainder, &self.normalized) != Ordering::Less {
+            sub_assign(&mut remainder, &self.normalized);
+            quotient = add(&quotient, &[1]);
+        }
+        (quotient, shr_bits(&remainder, self.shift))
+    }
+}
+
+fn from_bytes(input: &[u8]) -> Limbs {
+    trim(
+        input
+            .rchunks(4)
+            .map(|chunk| chunk.iter().fold(0, |acc, &b| (acc << 8) | u32::from(b)))
+            .collect(),
+    )
+}
+
+fn to_bytes(x: &[u32]) -> Vec<u8> {
+    let mut out: Vec<u8> = x.iter().rev().flat_map(|limb| limb.to_be_bytes()).collect();
+    let zeros = out.iter().take_while(|&&b| b == 0).count();
+    out.drain(..zeros);
+    out
+}
+
+/// Write the digits of `x` into `output`, most significant first and padded with zeros to fill
+/// it, `x` must fit.
+fn to_digits_basecase(mut x: Limbs, len: u64, output: &mut [u8]) {
+    let (limb_base, limb_digits) = limb_base(len as usize);
+    for group in output.rchunks_mut(limb_digits) {
+        let mut remainder = 0u64;
+        for limb in x.iter_mut().rev() {
+            let value = (remainder << 32) | u64::from(*limb);
+            *limb = (value / limb_base) as u32;
+            remainder = value % limb_base;
+        }
+        x = trim(x);
+        for digit in group.iter_mut().rev() {
+            *digit = (remainder % len) as u8;
+            remainder /= len;
+        }
+    }
+}
+
+/// Write the digits of `x` into `output`, where `output` is exactly twice as long as the number of
+/// digits in the last of `powers`, and `x` is less than that power squared.
+fn to_digits(x: Limbs, powers: &[Divisor], len: u64, output: &mut [u8]) {
+    match powers.split_last() {
+        Some((power, powers)) if x.len() > BASECASE_LIMBS => {
+            let (quotient, remainder) = power.div_rem(&x);
+            let (high, low) = output.split_at_mut(output.len() / 2);
+            to_digits(quotient, powers, len, high);
+            to_digits(remainder, powers, len, low);
+        }
+        _ => to_digits_basecase(x, len, output),
+    }
+}
+
+/// Encode big-endian bytes into the digits of base `len`, most significant first, including a
+/// zero digit for each leading zero byte.
+pub(crate) fn encode(input: &[u8], len: usize) -> Vec<u8> {
+    let zeros = input.iter().take_while(|&&b| b == 0).count();
+    let x = from_bytes(&input[zeros..]);
+
+    // Square the powers until the value is less than the last one squared
+    let (limb_base, limb_digits) = limb_base(len);
+    let mut powers = vec![Divisor::new(from_u64(limb_base))];
+    let mut digits = 2 * limb_digits;
+    while x.len() > 2 * (powers.last().unwrap().value.len() - 1) {
+        let last = &powers.last().unwrap().value;
+        powers.push(Divisor::new(mul(last, last)));
+        digits *= 2;
+    }
+
+    let mut output = vec![0; digits];
+    to_digits(x, &powers, len as u64, &mut output);
+    let leading = output.iter().take_while(|&&d| d == 0).count();
+    let mut digits = vec![0; zeros];
+    digits.extend_from_slice(&output[leading..]);
+    digits
+}
+
+fn from_digits_basecase(digits: &[u8], len: u64) -> Limbs {
+    let (_, limb_digits) = limb_base(len as usize);
+    let mut x = Vec::new();
+    for group in digits.chunks(limb_digits) {
+        let (mut carry, mut multiplier) = (0u64, 1u64);
+        for &digit in group {
+            carry = carry * len + u64::from(digit);
+            multiplier *= len;
+        }
+        for limb in &mut x {
+            carry += u64::from(*limb) * multiplier;
+            *limb = carry as u32;
+            carry >>= 32;
+        }
+        while carry > 0 {
+            x.push(carry as u32);
+            carry >>= 32;
+        }
+    }
+    trim(x)
+}
+
+/// Combine `digits` into a value, where there are at most twice as many as in the last of
+/// `powers`, and each power has twice the digits of the one before it.
+fn from_digits(digits: &[u8], powers: &[Limbs], limb_digits: usize, len: u64) -> Limbs {
+    match powers.split_last() {
+        Some((power, powers)) if digits.len() > BASECASE_LIMBS * limb_digits => {
+            let split = match digits.len().checked_sub(limb_digits << powers.len()) {
+                Some(split) => split,
+                None => return from_digits(digits, powers, limb_digits, len),
+            };
+            let high = from_digits(&digits[..split], powers, limb_digits, len);
+            let low = from_digits(&digits[split..], powers, limb_digits, len);
+            let mut x = mul(&high, power);
+            add_shifted(&mut x, &low, 0);
+            x
+        }
+        _ => from_digits_basecase(digits, len),
+    }
+}
+
+/// Decode digits of base `len`, most significant first, into big-endian bytes including a zero
+/// byte for each leading zero digit.
+pub(crate) fn decode(digits: &[u8], len: usize) -> Vec<u8> {
+    let zeros = digits.iter().take_while(|&&d| d == 0).count();
+
+    let (limb_base, limb_digits) = limb_base(len);
+    let mut powers = vec![from_u64(limb_base)];
+    while digits.len() > limb_digits << powers.len() {
+        let last = powers.last().unwrap();
+        powers.push(mul(last, last));
+    }
+
+    let x = from_digits(digits, &powers, limb_digits, len as u64);
+    let mut output = vec![0; zeros];
+    output.extend(to_bytes(&x));
+    output
+}
@@ -0,0 +1,103 @@
+//! Regrouping of bit packed values, used by the encodings that pack bits from the start of the
+//! input rather than treating it as an integer.
+
+use core::mem::MaybeUninit;
+
+use crate::{decode, encode};
+
+/// Regroups `from` bit values into `to` bit values, most significant bits first.
+pub(crate) struct Regroup {
+    from: u32,
+    to: u32,
+    /// The left over bits that have not made up a full `to` bit value yet.
+    acc: u32,
+    /// How many left over bits there are, always less than `to`.
+    bits: u32,
+}
+
+impl Regroup {
+    pub(crate) const fn new(from: u32, to: u32) -> Self {
+        Regroup {
+            from,
+            to,
+            acc: 0,
+            bits: 0,
+        }
+    }
+
+    /// Add a `from` bit value, calling `f` with each `to` bit value it completes.
+    pub(crate) fn push(&mut self, value: u8, mut f: impl FnMut(u8)) {
+        self.acc = self.acc << self.from | u32::from(value);
+        self.bits += self.from;
+        while self.bits >= self.to {
+            self.bits -= self.to;
+            f((self.acc >> self.bits) as u8 & self.mask());
+        }
+        self.acc &= (1 << self.bits) - 1;
+    }
+
+    /// The left over bits and how many there are.
+    #[cfg_attr(not(feature = "bech32"), allow(dead_code))]
+    pub(crate) fn remainder(&self) -> (u8, u32) {
+        (self.acc as u8, self.bits)
+    }
+
+    /// The left over bits zero padded into a final `to` bit value, if there are any.
+    pub(crate) fn padded(&self) -> Option<u8> {
+        (self.bits > 0).then(|| (self.acc << (self.to - self.bits)) as u8 & self.mask())
+    }
+
+    fn mask(&self) -> u8 {
+        ((1u32 << self.to) - 1) as u8
+    }
+}
+
+/// Encode `input` with the power of two alphabet `encode` without padding, packing the bits from
+/// the start as in RFC 4648.
+pub(crate) fn encode_packed(
+    input: &[u8],
+    output: &mut [MaybeUninit<u8>],
+    encode: &[u8],
+) -> encode::Result<usize> {
+    let len = crate::encoded_len(input.len(), encode.len());
+    let mut output = output
+        .get_mut(..len)
+        .ok_or(encode::Error::BufferTooSmall { required: len })?
+        .iter_mut();
+    let mut write = |value: u8| {
+        output.next().unwrap().write(encode[value as usize]);
+    };
+    let mut regroup = Regroup::new(8, encode.len().trailing_zeros());
+    for &byte in input {
+        regroup.push(byte, &mut write);
+    }
+    if let Some(value) = regroup.padded() {
+        write(value);
+    }
+    Ok(len)
+}
+
+/// Decode the characters of `input`, paired with their index in the input, with the `bits` bit
+/// alphabet `decode` packed as in RFC 4648 without padding, discarding any trailing partial byte.
+pub(crate) fn decode_packed(
+    input: &mut dyn Iterator<Item = (usize, u8)>,
+    output: &mut [u8],
+    decode: &[u8],
+    bits: u32,
+) -> decode::Result<usize> {
+    let mut len = 0;
+    let mut regroup = Regroup::new(bits, 8);
+    for (index, c) in input {
+        let digit = decode::decode_digit(decode, index, c)?;
+        regroup.push(digit, |byte| {
+            if let Some(slot) = output.get_mut(len) {
+                *slot = byte;
+            }
+            len += 1;
+        });
+    }
+    if len > output.len() {
+        return Err(decode::Error::BufferTooSmall { required: len });
+    }
+    Ok(len)
+}
@@ -1,20 +1,39 @@
 //! Functions for decoding arbitrary base encoded strings.
 
-use core::fmt;
+use core::{convert::TryInto, fmt, mem::MaybeUninit};
 
 #[cfg(feature = "alloc")]
-use alloc::{vec, vec::Vec};
+use alloc::{string::String, vec::Vec};
 
-use crate::{alphabet::Unspecified, Alphabet};
+use crate::{
+    alphabet::{Len, Unspecified},
+    encode::{check_value, LeadingZeros, CHECK_SYMBOLS},
+    Alphabet, Scratch, StaticAlphabet,
+};
 
 /// A builder for setting up the alphabet and output of a decode.
 ///
 /// See the documentation for [`bsx::decode`](crate::decode()) for a more
 /// high level view of how to use this.
-#[allow(missing_debug_implementations)]
+#[derive(Clone, Debug)]
 pub struct DecodeBuilder<I: AsRef<[u8]>, A> {
     input: I,
     alpha: A,
+    case_insensitive: bool,
+    /// Bitmask of ASCII characters to skip in the input.
+    ignore: u128,
+    zeros: LeadingZeros,
+    check_symbol: bool,
+    /// Padding character to strip from the end of the input.
+    padding: Option<u8>,
+    bijective: bool,
+    little_endian: bool,
+    /// Skip any characters that are not part of the alphabet.
+    lenient: bool,
+    /// Strip leading and trailing ASCII whitespace from the input.
+    trim: bool,
+    /// Maximum input length in bytes to attempt decoding.
+    max_len: Option<usize>,
 }
 
 /// A specialized [`Result`](core::result::Result) type for [`bsx::decode`](module@crate::decode)
@@ -22,10 +41,16 @@ pub type Result<T> = core::result::Result<T, Error>;
 
 /// Errors that could occur when decoding an arbitrary base encoded string.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[non_exhaustive]
 pub enum Error {
     /// The output buffer was too small to contain the entire input.
-    BufferTooSmall,
+    BufferTooSmall {
+        /// A buffer length that is large enough for the decoded output, this is the
+        /// [`max_decoded_len`](DecodeBuilder::max_decoded_len) so may be more than the exact
+        /// decoded length.
+        required: usize,
+    },
 
     /// The input contained a character that was not part of the current base's alphabet.
     InvalidCharacter {
@@ -42,6 +67,351 @@ pub enum Error {
         /// at.
         index: usize,
     },
+
+    /// The decoded value was too large to fit in the requested integer type.
+    Overflow,
+
+    /// The decoded data was not exactly the length requested by
+    /// [`DecodeBuilder::into_array`].
+    InvalidLength {
+        /// The length that was expected.
+        expected: usize,
+    },
+
+    /// The input's checksum was missing or did not match the decoded data.
+    InvalidChecksum,
+
+    /// The input was longer than the limit set by [`DecodeBuilder::with_max_len`].
+    InputTooLong {
+        /// The maximum input length in bytes.
+        max: usize,
+    },
+
+    /// The padding accepted by [`DecodeBuilder::with_padding`] did not complete the last block
+    /// of a bit-packed alphabet such as [`StaticAlphabet::BASE64`].
+    InvalidPadding,
+}
+
+/// Represents a buffer that can be decoded into. See [`DecodeBuilder::into`] and the provided
+/// implementations for more details.
+pub trait DecodeTarget {
+    /// Decodes into this buffer, provides the maximum length for implementations that wish to
+    /// preallocate space, along with a function that will decode bytes into the buffer and return
+    /// the length written to it.
+    fn decode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+    ) -> Result<usize>;
+}
+
+impl<T: DecodeTarget + ?Sized> DecodeTarget for &mut T {
+    fn decode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+    ) -> Result<usize> {
+        T::decode_with(self, max_len, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+impl DecodeTarget for Vec<u8> {
+    fn decode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+    ) -> Result<usize> {
+        self.resize(max_len, 0);
+        let len = f(&mut *self)?;
+        self.truncate(len);
+        Ok(len)
+    }
+}
+
+/// Decodes after the existing contents of a vector, used by [`DecodeBuilder::onto_vec`].
+#[cfg(feature = "alloc")]
+struct Tail<'a>(&'a mut Vec<u8>);
+
+#[cfg(feature = "alloc")]
+impl DecodeTarget for Tail<'_> {
+    fn decode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+    ) -> Result<usize> {
+        let start = self.0.len();
+        self.0.resize(start + max_len, 0);
+        let result = f(&mut self.0[start..]);
+        self.0.truncate(start + *result.as_ref().unwrap_or(&0));
+        result
+    }
+}
+
+impl DecodeTarget for [u8] {
+    fn decode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+    ) -> Result<usize> {
+        let _ = max_len;
+        f(&mut *self)
+    }
+}
+
+/// Only the prefix of the buffer that could be needed for the decoded output is initialized before
+/// decoding into it.
+impl DecodeTarget for [MaybeUninit<u8>] {
+    fn decode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+    ) -> Result<usize> {
+        let len = max_len.min(self.len());
+        let output = &mut self[..len];
+        output.fill(MaybeUninit::new(0));
+        // SAFETY: the buffer was just initialized
+        f(unsafe { crate::encode::assume_init_mut(output) })
+    }
+}
+
+impl<const N: usize> DecodeTarget for [u8; N] {
+    fn decode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+    ) -> Result<usize> {
+        self[..].decode_with(max_len, f)
+    }
+}
+
+/// Decodes into the array's inline capacity, replacing any existing contents.
+///
+/// ```rust
+/// let mut output = arrayvec::ArrayVec::<u8, 8>::new();
+/// bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output)?;
+/// assert_eq!([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], &output[..]);
+/// # Ok::<(), bsx::decode::Error>(())
+/// ```
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+impl<const CAP: usize> DecodeTarget for arrayvec::ArrayVec<u8, CAP> {
+    fn decode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+    ) -> Result<usize> {
+        self.clear();
+        self.extend(core::iter::repeat(0).take(max_len.min(CAP)));
+        let len = f(&mut *self)?;
+        self.truncate(len);
+        Ok(len)
+    }
+}
+
+/// Decodes into the buffer, replacing any existing contents.
+///
+/// ```rust
+/// let mut output = bytes::BytesMut::new();
+/// bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output)?;
+/// assert_eq!([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], &output[..]);
+/// # Ok::<(), bsx::decode::Error>(())
+/// ```
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+impl DecodeTarget for bytes::BytesMut {
+    fn decode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+    ) -> Result<usize> {
+        self.clear();
+        self.resize(max_len, 0);
+        let len = f(&mut *self)?;
+        self.truncate(len);
+        Ok(len)
+    }
+}
+
+/// Decodes into the vector's inline capacity, replacing any existing contents.
+///
+/// ```rust
+/// let mut output = heapless::Vec::<u8, 8>::new();
+/// bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output)?;
+/// assert_eq!([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], &output[..]);
+/// # Ok::<(), bsx::decode::Error>(())
+/// ```
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+impl<const N: usize> DecodeTarget for heapless::Vec<u8, N> {
+    fn decode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+    ) -> Result<usize> {
+        self.clear();
+        self.extend(core::iter::repeat(0).take(max_len.min(N)));
+        let len = f(&mut *self)?;
+        self.truncate(len);
+        Ok(len)
+    }
+}
+
+/// Byte containers that can be decoded from a string with an alphabet chosen at runtime.
+pub trait FromEncoded: Sized {
+    /// Decode `input` using `alpha`.
+    fn from_encoded(input: &str, alpha: &dyn Alphabet) -> Result<Self>;
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+impl FromEncoded for Vec<u8> {
+    fn from_encoded(input: &str, alpha: &dyn Alphabet) -> Result<Self> {
+        crate::decode(input).with_alphabet(alpha).into_vec()
+    }
+}
+
+/// The decoded data must be exactly `N` bytes long.
+impl<const N: usize> FromEncoded for [u8; N] {
+    fn from_encoded(input: &str, alpha: &dyn Alphabet) -> Result<Self> {
+        crate::decode(input).with_alphabet(alpha).into_array()
+    }
+}
+
+/// Decode input read from a sequence of characters or UTF-16 code units, for inputs that are
+/// not already held in a string.
+///
+/// The input is read in a single pass, each character going straight into the decoder without
+/// being collected first. Every character must be ASCII, so this can't be used with an
+/// [extended alphabet](crate::StaticAlphabet::new_extended) containing non-ASCII bytes. Errors
+/// from decoding this input give the index of the character or code unit, rather than a byte
+/// index.
+///
+/// # Examples
+///
+/// ```rust
+/// use bsx::decode::AsciiInput;
+///
+/// let chars = ['h', 'e', '1', '1', 'o', 'w', 'o', 'r', '1', 'd'];
+/// let mut output = [0; 10];
+/// let len = AsciiInput::from_chars(chars)
+///     .decode_into(bsx::StaticAlphabet::BITCOIN, &mut output)?;
+/// assert_eq!(
+///     [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+///     output[..len]);
+///
+/// assert_eq!(
+///     bsx::decode::Error::NonAsciiCharacter { index: 2 },
+///     AsciiInput::from_chars("he£1o".chars())
+///         .into_vec(bsx::StaticAlphabet::BITCOIN)
+///         .unwrap_err());
+/// # Ok::<(), bsx::decode::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct AsciiInput<I: Iterator> {
+    units: I,
+    /// Convert a unit to its ASCII byte, or `0xFF` if it isn't ASCII.
+    to_ascii: fn(I::Item) -> u8,
+}
+
+impl<I: Iterator<Item = char>> AsciiInput<I> {
+    /// Read the input from a sequence of characters.
+    pub fn from_chars(chars: impl IntoIterator<IntoIter = I>) -> Self {
+        AsciiInput {
+            units: chars.into_iter(),
+            to_ascii: |c| if c.is_ascii() { c as u8 } else { 0xFF },
+        }
+    }
+}
+
+impl<I: Iterator<Item = u16>> AsciiInput<I> {
+    /// Read the input from a sequence of UTF-16 code units, such as a wide string from
+    /// `OsStrExt::encode_wide` on Windows.
+    ///
+    /// Each code unit is looked up in the decoding table as it is read, without converting the
+    /// input to UTF-8. Any non-ASCII unit (including surrogates) gives a
+    /// [`NonAsciiCharacter`](Error::NonAsciiCharacter) error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bsx::decode::AsciiInput;
+    ///
+    /// let wide: Vec<u16> = "he11owor1d".encode_utf16().collect();
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     AsciiInput::from_utf16(wide.iter().copied()).into_vec(bsx::StaticAlphabet::BITCOIN)?);
+    ///
+    /// assert_eq!(
+    ///     bsx::decode::Error::NonAsciiCharacter { index: 1 },
+    ///     AsciiInput::from_utf16([0x0068, 0xD83D, 0xDE00])
+    ///         .into_vec(bsx::StaticAlphabet::BITCOIN)
+    ///         .unwrap_err());
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn from_utf16(units: impl IntoIterator<IntoIter = I>) -> Self {
+        AsciiInput {
+            units: units.into_iter(),
+            to_ascii: |unit| if unit < 0x80 { unit as u8 } else { 0xFF },
+        }
+    }
+}
+
+#[cfg(all(windows, feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(all(windows, feature = "std"))))]
+impl<'a> AsciiInput<std::os::windows::ffi::EncodeWide<'a>> {
+    /// Read the input from the wide characters of an [`OsStr`](std::ffi::OsStr), as given by
+    /// `OsStrExt::encode_wide`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bsx::decode::AsciiInput;
+    /// use std::ffi::OsString;
+    ///
+    /// let arg = OsString::from("he11owor1d");
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     AsciiInput::from_os_str(&arg).into_vec(bsx::StaticAlphabet::BITCOIN)?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn from_os_str(s: &'a std::ffi::OsStr) -> Self {
+        use std::os::windows::ffi::OsStrExt;
+        AsciiInput::from_utf16(s.encode_wide())
+    }
+}
+
+impl<I: Iterator> AsciiInput<I> {
+    /// Decode the input with `alpha` into `output`, returning the length written.
+    ///
+    /// As the input is only read once the output length can't be known up front, an output with
+    /// room for a byte per character will always be large enough.
+    pub fn decode_into(self, alpha: impl Alphabet, output: &mut [u8]) -> Result<usize> {
+        let AsciiInput { units, to_ascii } = self;
+        alpha.decode_iter(&mut units.map(to_ascii).enumerate(), output)
+    }
+
+    /// Decode the input with `alpha` into a new vector of bytes.
+    ///
+    /// The vector is sized from the upper bound of the iterator's
+    /// [`size_hint`](Iterator::size_hint), input without one is collected before decoding.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn into_vec(self, alpha: impl Alphabet) -> Result<Vec<u8>> {
+        let AsciiInput { units, to_ascii } = self;
+        let mut units = units.map(to_ascii).enumerate();
+        let mut output = match units.size_hint().1 {
+            Some(max) => alloc::vec![0; max],
+            None => {
+                let bytes = Scratch::new(units.map(|(_, c)| c).collect::<Vec<u8>>());
+                return crate::decode(&*bytes).with_alphabet(alpha).into_vec();
+            }
+        };
+        let len = alpha.decode_iter(&mut units, &mut output)?;
+        output.truncate(len);
+        Ok(output)
+    }
 }
 
 impl<I: AsRef<[u8]>> DecodeBuilder<I, Unspecified> {
@@ -49,6 +419,16 @@ impl<I: AsRef<[u8]>> DecodeBuilder<I, Unspecified> {
         DecodeBuilder {
             input,
             alpha: Unspecified,
+            case_insensitive: false,
+            ignore: 0,
+            zeros: LeadingZeros::Preserve,
+            check_symbol: false,
+            padding: None,
+            bijective: false,
+            little_endian: false,
+            lenient: false,
+            trim: false,
+            max_len: None,
         }
     }
 }
@@ -70,111 +450,1573 @@ impl<I: AsRef<[u8]>, A> DecodeBuilder<I, A> {
         DecodeBuilder {
             input: self.input,
             alpha,
+            case_insensitive: self.case_insensitive,
+            ignore: self.ignore,
+            zeros: self.zeros,
+            check_symbol: self.check_symbol,
+            padding: self.padding,
+            bijective: self.bijective,
+            little_endian: self.little_endian,
+            lenient: self.lenient,
+            trim: self.trim,
+            max_len: self.max_len,
         }
     }
-}
 
-impl<I: AsRef<[u8]>, A: Alphabet> DecodeBuilder<I, A> {
-    /// Decode into a new vector of bytes.
+    /// Accept letters in either case for this decode, independently of how the alphabet was
+    /// built.
     ///
-    /// See the documentation for [`bsx::decode`](crate::decode()) for an
-    /// explanation of the errors that may occur.
+    /// A letter whose other case is also part of the alphabet keeps its own value, so this only
+    /// affects letters that would otherwise be invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let base32 = bsx::StaticAlphabet::new(b"abcdefghijklmnopqrstuvwxyz234567")?;
+    /// assert_eq!(
+    ///     vec![0x00, 0x02, 0xd5, 0xcd, 0x86],
+    ///     bsx::decode("ABNLTMG").with_alphabet(&base32).case_insensitive().into_vec()?);
+    /// assert_eq!(
+    ///     bsx::decode::Error::InvalidCharacter { character: 'A', index: 0 },
+    ///     bsx::decode("ABNLTMG").with_alphabet(&base32).into_vec().unwrap_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn case_insensitive(self) -> Self {
+        DecodeBuilder {
+            case_insensitive: true,
+            ..self
+        }
+    }
+
+    /// Skip the given characters anywhere in the input, such as separators and line breaks in
+    /// formatted codes, even if they are part of the alphabet.
+    ///
+    /// Indexes in errors still refer to the original input.
+    ///
+    /// # Panics
+    ///
+    /// If any of the characters are not ASCII.
     ///
     /// # Examples
     ///
     /// ```rust
     /// assert_eq!(
     ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
-    ///     bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into_vec()?);
+    ///     bsx::decode("he11-owor\n1d")
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .ignore_chars(" -_\n")
+    ///         .into_vec()?);
+    /// assert_eq!(
+    ///     bsx::decode::Error::InvalidCharacter { character: '0', index: 11 },
+    ///     bsx::decode("he11 owor 10")
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .ignore_chars(" ")
+    ///         .into_vec()
+    ///         .unwrap_err());
     /// # Ok::<(), bsx::decode::Error>(())
     /// ```
+    pub fn ignore_chars(self, chars: &str) -> Self {
+        let ignore = chars.bytes().fold(self.ignore, |ignore, c| {
+            assert!(c < 128, "ignored characters must be ASCII");
+            ignore | 1 << c
+        });
+        DecodeBuilder { ignore, ..self }
+    }
+
+    /// Accept input that has been broken into lines, such as by
+    /// [`EncodeBuilder::with_line_wrap`](crate::encode::EncodeBuilder::with_line_wrap), skipping
+    /// any `\n` or `\r` characters.
     ///
-    #[cfg(feature = "alloc")]
-    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
-    pub fn into_vec(self) -> Result<Vec<u8>> {
-        let mut output = vec![0; self.input.as_ref().len()];
-        self.into(&mut output).map(|len| {
-            output.truncate(len);
-            output
-        })
+    /// This is the same as [`ignore_chars("\r\n")`](Self::ignore_chars).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bsx::decode("he11\r\nowor\r\n1d\r\n")
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .wrapped()
+    ///         .into_vec()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn wrapped(self) -> Self {
+        self.ignore_chars("\r\n")
     }
 
-    /// Decode into the given buffer.
+    /// Strip any leading and trailing ASCII whitespace, such as the trailing newline of input read
+    /// from a file or stdin.
     ///
-    /// Returns the length written into the buffer, the rest of the bytes in
-    /// the buffer will be untouched.
+    /// Indexes in errors still refer to the original input.
     ///
-    /// See the documentation for [`bsx::decode`](crate::decode()) for an
-    /// explanation of the errors that may occur.
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bsx::decode("  he11owor1d\r\n")
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .trim_whitespace()
+    ///         .into_vec()?);
+    /// assert_eq!(
+    ///     bsx::decode::Error::InvalidCharacter { character: ' ', index: 5 },
+    ///     bsx::decode("\the11 owor1d\n")
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .trim_whitespace()
+    ///         .into_vec()
+    ///         .unwrap_err());
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn trim_whitespace(self) -> Self {
+        DecodeBuilder { trim: true, ..self }
+    }
+
+    /// Skip any characters that are not part of the alphabet, rather than failing with an
+    /// [`Error::InvalidCharacter`] or [`Error::NonAsciiCharacter`], for ingesting copy-pasted or
+    /// otherwise mangled input.
+    ///
+    /// A check symbol from [`with_check_symbol`](Self::with_check_symbol) is still required to be
+    /// the last character that is not [ignored](Self::ignore_chars).
     ///
     /// # Examples
     ///
     /// ```rust
-    /// let mut output = [0xFF; 10];
-    /// assert_eq!(8, bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output)?);
     /// assert_eq!(
-    ///     [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58, 0xFF, 0xFF],
-    ///     output);
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bsx::decode("<he11 owor-1d>\n")
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .lenient()
+    ///         .into_vec()?);
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bsx::decode("he11o0wor1d")
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .lenient()
+    ///         .into_vec()?);
     /// # Ok::<(), bsx::decode::Error>(())
     /// ```
-    pub fn into<O: AsMut<[u8]>>(self, mut output: O) -> Result<usize> {
-        decode_into(self.input.as_ref(), output.as_mut(), self.alpha)
+    pub fn lenient(self) -> Self {
+        DecodeBuilder {
+            lenient: true,
+            ..self
+        }
+    }
+
+    /// Treat the input as a zero padded fixed width numeral, as output by
+    /// [`EncodeBuilder::with_min_width`](crate::encode::EncodeBuilder::with_min_width).
+    ///
+    /// All leading zero characters are skipped rather than each decoding to a zero byte, and
+    /// [`into_array`](Self::into_array) left pads the decoded value with zero bytes to fill the
+    /// array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     [0x00, 0x00, 0x07, 0x5b, 0xcd, 0x15],
+    ///     bsx::decode("1111BukQL")
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .padded()
+    ///         .into_array()?);
+    /// assert_eq!(
+    ///     vec![0x07, 0x5b, 0xcd, 0x15],
+    ///     bsx::decode("1111BukQL")
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .padded()
+    ///         .into_vec()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn padded(self) -> Self {
+        self.with_leading_zeros(LeadingZeros::Ignore)
     }
-}
 
-fn decode_into(input: &[u8], output: &mut [u8], alpha: impl Alphabet) -> Result<usize> {
-    let mut index = 0;
-    let (len, decode, encode) = (alpha.len(), alpha.decode(), alpha.encode());
-    let zero = encode[0];
+    /// Choose how leading zero bytes are represented in the input, matching
+    /// [`EncodeBuilder::with_leading_zeros`](crate::encode::EncodeBuilder::with_leading_zeros).
+    ///
+    /// [`LeadingZeros::Ignore`] is the same as [`Self::padded`]. With
+    /// [`LeadingZeros::CountPrefix`] the first character is the number of zero bytes to output
+    /// before the value of the rest of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bsx::encode::LeadingZeros;
+    ///
+    /// let decode = |input, zeros| {
+    ///     bsx::decode(input)
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .with_leading_zeros(zeros)
+    ///         .into_vec()
+    /// };
+    /// assert_eq!(vec![0x00, 0x00, 0x07, 0x5b, 0xcd, 0x15], decode("11BukQL", LeadingZeros::Preserve)?);
+    /// assert_eq!(vec![0x07, 0x5b, 0xcd, 0x15], decode("11BukQL", LeadingZeros::Ignore)?);
+    /// assert_eq!(vec![0x00, 0x00, 0x07, 0x5b, 0xcd, 0x15], decode("3BukQL", LeadingZeros::CountPrefix)?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn with_leading_zeros(self, zeros: LeadingZeros) -> Self {
+        DecodeBuilder { zeros, ..self }
+    }
 
-    for (i, c) in input.iter().enumerate() {
-        if *c > 127 {
-            return Err(Error::NonAsciiCharacter { index: i });
+    /// Accept input with trailing `padding` characters, as output by
+    /// [`EncodeBuilder::with_padding`](crate::encode::EncodeBuilder::with_padding).
+    ///
+    /// The padding is optional. When present with a bit-packed alphabet such as
+    /// [`StaticAlphabet::BASE64`] it must complete the last block of characters, otherwise it is
+    /// not checked to make the input any particular length.
+    ///
+    /// # Panics
+    ///
+    /// If `padding` is not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let decode = |input| {
+    ///     bsx::decode(input)
+    ///         .with_alphabet(bsx::StaticAlphabet::BASE64URL)
+    ///         .with_padding('=')
+    ///         .into_vec()
+    /// };
+    /// assert_eq!(vec![0xff, 0xff], decode("__8=")?);
+    /// assert_eq!(vec![0xff, 0xff], decode("__8")?);
+    /// assert_eq!(bsx::decode::Error::InvalidPadding, decode("__8==").unwrap_err());
+    /// assert_eq!(
+    ///     bsx::decode::Error::InvalidCharacter { character: '=', index: 1 },
+    ///     decode("_=8").unwrap_err());
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn with_padding(self, padding: char) -> Self {
+        assert!(padding.is_ascii(), "padding must be ASCII");
+        DecodeBuilder {
+            padding: Some(padding as u8),
+            ..self
         }
+    }
 
-        let mut val = decode[*c as usize] as usize;
-        if val == 0xFF {
-            return Err(Error::InvalidCharacter {
-                character: *c as char,
-                index: i,
-            });
+    /// Verify and strip Crockford's check symbol from the end of the input, as output by
+    /// [`EncodeBuilder::with_check_symbol`](crate::encode::EncodeBuilder::with_check_symbol).
+    ///
+    /// The check symbol is decoded case-insensitively with the same aliases as
+    /// [`StaticAlphabet::CROCKFORD`], a missing or mismatched check symbol gives an
+    /// [`Error::InvalidChecksum`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let decode = |input| {
+    ///     bsx::decode(input)
+    ///         .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+    ///         .with_check_symbol()
+    ///         .into_u128()
+    /// };
+    /// assert_eq!(123456789, decode("3NQK8NU")?);
+    /// assert_eq!(123456789, decode("3nqk8nu")?);
+    /// assert_eq!(32, decode("10*")?);
+    /// assert_eq!(bsx::decode::Error::InvalidChecksum, decode("3NQK8MU").unwrap_err());
+    /// assert_eq!(bsx::decode::Error::InvalidChecksum, decode("").unwrap_err());
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn with_check_symbol(self) -> Self {
+        DecodeBuilder {
+            check_symbol: true,
+            ..self
         }
+    }
 
-        for byte in &mut output[..index] {
-            val += (*byte as usize) * len;
-            *byte = (val & 0xFF) as u8;
-            val >>= 8;
+    /// Treat the input as an integer numeral, ignoring leading zero characters unless they are
+    /// represented some other way.
+    fn into_integer(self) -> Self {
+        match self.zeros {
+            LeadingZeros::Preserve => self.with_leading_zeros(LeadingZeros::Ignore),
+            _ => self,
         }
+    }
 
-        while val > 0 {
-            let byte = output.get_mut(index).ok_or(Error::BufferTooSmall)?;
-            *byte = (val & 0xFF) as u8;
-            index += 1;
-            val >>= 8
+    /// Decode input with the least significant digit first, as output by
+    /// [`EncodeBuilder::little_endian`](crate::encode::EncodeBuilder::little_endian).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bsx::decode("d1rowo11eh")
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .little_endian()
+    ///         .into_vec()?);
+    /// assert_eq!(
+    ///     vec![0x00, 0x00, 0x01],
+    ///     bsx::decode("211")
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .little_endian()
+    ///         .into_vec()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn little_endian(self) -> Self {
+        DecodeBuilder {
+            little_endian: true,
+            ..self
         }
     }
 
-    for _ in input.iter().take_while(|c| **c == zero) {
-        let byte = output.get_mut(index).ok_or(Error::BufferTooSmall)?;
-        *byte = 0;
-        index += 1;
+    /// Decode bijective numeration, as output by
+    /// [`EncodeBuilder::bijective`](crate::encode::EncodeBuilder::bijective), where the alphabet
+    /// characters represent the digits `1` to `k`.
+    ///
+    /// The output is the minimal big-endian bytes of the value, with the empty string decoding to
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let letters = bsx::StaticAlphabet::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ")?;
+    /// let column = |s: &str| bsx::decode(s).with_alphabet(&letters).bijective().into_u128();
+    /// assert_eq!(0, column("")?);
+    /// assert_eq!(1, column("A")?);
+    /// assert_eq!(27, column("AA")?);
+    /// assert_eq!(703, column("AAA")?);
+    /// assert_eq!(vec![0x02, 0xbe], bsx::decode("ZZ").with_alphabet(&letters).bijective().into_vec()?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn bijective(self) -> Self {
+        DecodeBuilder {
+            bijective: true,
+            ..self
+        }
     }
 
-    output[..index].reverse();
-    Ok(index)
+    /// Reject inputs longer than `max_len` bytes with an [`Error::InputTooLong`] before doing any
+    /// decoding work.
+    ///
+    /// Decoding takes time quadratic in the input length for most alphabets, so this bounds the
+    /// work done for untrusted input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let decode = |input| {
+    ///     bsx::decode(input)
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .with_max_len(10)
+    ///         .into_vec()
+    /// };
+    /// assert_eq!(vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], decode("he11owor1d")?);
+    /// assert_eq!(
+    ///     bsx::decode::Error::InputTooLong { max: 10 },
+    ///     decode("he11owor1d1").unwrap_err());
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn with_max_len(self, max_len: usize) -> Self {
+        DecodeBuilder {
+            max_len: Some(max_len),
+            ..self
+        }
+    }
 }
 
-#[cfg(feature = "std")]
-#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-impl std::error::Error for Error {}
-
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match *self {
-            Error::BufferTooSmall => {
-                write!(f, "buffer provided to decode string into was too small")
-            }
-            Error::InvalidCharacter { character, index } => write!(
+impl<I: AsRef<[u8]>, A: Alphabet> DecodeBuilder<I, A> {
+    /// An upper bound on the length of the output of this decode, taking into account the leading
+    /// zero characters in the input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let builder = bsx::decode("11otoeBRaX").with_alphabet(bsx::StaticAlphabet::BITCOIN);
+    /// assert_eq!(8, builder.max_decoded_len());
+    /// assert_eq!(8, builder.into_vec()?.len());
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn max_decoded_len(&self) -> usize {
+        let (_, input) = self.trim(self.input.as_ref());
+        let input = self.split_check(input).map_or(input, |(input, _)| input);
+        let input = self.trim_padding(input);
+        let (count, offset) = self.count_prefix(input).unwrap_or((0, 0));
+        let input = &input[offset..];
+        let zeros = if self.alpha.bit_packed() {
+            0
+        } else {
+            self.leading_zeros(input)
+        };
+        // Bijective input may gain a digit when converted to positional digits
+        let extra = usize::from(self.bijective);
+        count + zeros + crate::decoded_len(input.len() - zeros + extra, self.alpha.len())
+    }
+
+    /// Decode into a new vector of bytes.
+    ///
+    /// See the documentation for [`bsx::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into_vec()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    ///
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn into_vec(self) -> Result<Vec<u8>> {
+        let mut output = Vec::new();
+        self.into(&mut output)?;
+        Ok(output)
+    }
+
+    /// Decode into a new vector of bytes that is wiped when dropped, for decoding secrets such as
+    /// private keys.
+    ///
+    /// The vector is also wiped if decoding fails part way through.
+    ///
+    /// See the documentation for [`bsx::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let key = bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into_zeroizing_vec()?;
+    /// assert_eq!([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], key[..]);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    #[cfg(all(feature = "alloc", feature = "zeroize"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(all(any(feature = "alloc", feature = "std"), feature = "zeroize")))
+    )]
+    pub fn into_zeroizing_vec(self) -> Result<zeroize::Zeroizing<Vec<u8>>> {
+        let mut output = zeroize::Zeroizing::new(Vec::new());
+        self.into(&mut *output)?;
+        Ok(output)
+    }
+
+    /// Decode onto the end of an existing vector, keeping its current contents.
+    ///
+    /// Returns the length appended to the vector, on error the vector is left unchanged.
+    ///
+    /// See the documentation for [`bsx::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut output = vec![0xFF];
+    /// assert_eq!(8, bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).onto_vec(&mut output)?);
+    /// assert_eq!(vec![0xFF, 0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], output);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn onto_vec(self, output: &mut Vec<u8>) -> Result<usize> {
+        self.into(Tail(output))
+    }
+
+    /// Decode into the given buffer.
+    ///
+    /// Returns the length written into the buffer.
+    ///
+    /// If the buffer is resizeable it will be reallocated to fit the decoded data and truncated to
+    /// size.
+    ///
+    /// If the buffer is not resizeable the rest of the bytes in the buffer will be untouched.
+    ///
+    /// See the documentation for [`bsx::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ## `Vec<u8>`
+    ///
+    /// ```rust
+    /// let mut output = b"goodbye world".to_vec();
+    /// bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output)?;
+    /// assert_eq!(vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], output);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    ///
+    /// ## `&mut [u8]`
+    ///
+    /// ```rust
+    /// let mut output = [0xFF; 10];
+    /// assert_eq!(8, bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output)?);
+    /// assert_eq!(
+    ///     [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58, 0xFF, 0xFF],
+    ///     output);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn into(self, mut output: impl DecodeTarget) -> Result<usize> {
+        if let Some(max) = self.max_len {
+            if self.input.as_ref().len() > max {
+                return Err(Error::InputTooLong { max });
+            }
+        }
+        let max_decoded_len = self.max_decoded_len();
+        let (lead, input) = self.trim(self.input.as_ref());
+        let shift = |err: Error| err.map_index(|index| lead + index);
+        let (input, check) = self.split_check(input).map_err(shift)?;
+        let input = self.check_padding(input)?;
+        let (count, offset) = self.count_prefix(input).map_err(shift)?;
+        let rest = &input[offset..];
+        // Integer input skips all the leading zero characters, which are at the end when little
+        // endian
+        let (start, end) = match (self.zeros, self.little_endian) {
+            (LeadingZeros::Preserve, _) => (0, rest.len()),
+            (_, false) => (self.leading_zeros(rest), rest.len()),
+            (_, true) => (0, rest.len() - self.leading_zeros(rest)),
+        };
+        let (start, end) = (offset + start, offset + end);
+        output
+            .decode_with(max_decoded_len, |output| {
+                output
+                    .get_mut(..count)
+                    .ok_or(Error::BufferTooSmall { required: count })?
+                    .fill(0);
+                let len = self
+                    .decode_into(&input[start..end], &mut output[count..])
+                    .map_err(|err| err.map_index(|index| lead + start + index))?;
+                if check.is_some_and(|check| check != check_value(&output[..count + len])) {
+                    return Err(Error::InvalidChecksum);
+                }
+                Ok(count + len)
+            })
+            .map_err(|err| match err {
+                Error::BufferTooSmall { .. } => Error::BufferTooSmall {
+                    required: max_decoded_len,
+                },
+                other => other,
+            })
+    }
+
+    /// Decode into an [`io::Write`](std::io::Write) sink, such as a file or socket.
+    ///
+    /// Returns the length written into the sink.
+    ///
+    /// The input is decoded into a scratch buffer sized from
+    /// [`max_decoded_len`](Self::max_decoded_len), on the stack for short inputs, then written with
+    /// a single [`write_all`](std::io::Write::write_all). Decoding errors are returned as
+    /// [`InvalidData`](std::io::ErrorKind::InvalidData) errors wrapping the [`Error`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut output = Vec::new();
+    /// bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into_writer(&mut output)?;
+    /// assert_eq!(vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], output);
+    ///
+    /// let err = bsx::decode("he11o0").with_alphabet(bsx::StaticAlphabet::BITCOIN).into_writer(&mut output).unwrap_err();
+    /// assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn into_writer(self, mut output: impl std::io::Write) -> std::io::Result<usize> {
+        let mut stack = Scratch::new([0; 256]);
+        let mut heap = Scratch::new(Vec::new());
+        let max_decoded_len = self.max_decoded_len();
+        let buffer = if max_decoded_len <= stack.len() {
+            &mut stack[..max_decoded_len]
+        } else {
+            heap.resize(max_decoded_len, 0);
+            &mut heap[..]
+        };
+        let len = self
+            .into(&mut *buffer)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        output.write_all(&buffer[..len])?;
+        Ok(len)
+    }
+
+    /// Decode into a possibly uninitialized buffer, returning the initialized prefix containing
+    /// the decoded output.
+    ///
+    /// Only the part of the buffer that could be needed for the decoded output is initialized, so
+    /// large buffers can be reused without being zeroed first.
+    ///
+    /// See the documentation for [`bsx::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    ///
+    /// let mut output = [MaybeUninit::uninit(); 4096];
+    /// let decoded = bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into_uninit(&mut output)?;
+    /// assert_eq!([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], decoded);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn into_uninit(self, output: &mut [MaybeUninit<u8>]) -> Result<&mut [u8]> {
+        let len = self.into(&mut *output)?;
+        // SAFETY: decoding initializes the prefix of the buffer up to the length it returns
+        Ok(unsafe { crate::encode::assume_init_mut(&mut output[..len]) })
+    }
+
+    /// Decode into a fixed size array, requiring that the decoded data is exactly `N` bytes.
+    ///
+    /// See the documentation for [`bsx::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur, in addition an
+    /// [`Error::InvalidLength`] will be returned if the decoded data is a different length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+    ///     bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into_array()?);
+    /// assert_eq!(
+    ///     bsx::decode::Error::InvalidLength { expected: 4 },
+    ///     bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into_array::<4>().unwrap_err());
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn into_array<const N: usize>(self) -> Result<[u8; N]> {
+        let padded = self.zeros == LeadingZeros::Ignore;
+        let mut output = [0; N];
+        let len = self.into(&mut output).map_err(|err| match err {
+            Error::BufferTooSmall { .. } => Error::InvalidLength { expected: N },
+            other => other,
+        })?;
+        if padded && len < N {
+            output.copy_within(..len, N - len);
+            output[..N - len].fill(0);
+        } else if len != N {
+            return Err(Error::InvalidLength { expected: N });
+        }
+        Ok(output)
+    }
+
+    /// Decode into a [`u128`], treating the input as an integer numeral.
+    ///
+    /// Unlike byte decoding, leading zero characters are simply ignored.
+    ///
+    /// See the documentation for [`bsx::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur, in addition an
+    /// [`Error::Overflow`] will be returned if the value does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     123456789,
+    ///     bsx::decode("11BukQL").with_alphabet(bsx::StaticAlphabet::BITCOIN).into_u128()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn into_u128(self) -> Result<u128> {
+        let mut output = Scratch::new([0; 16]);
+        let len = self
+            .into_integer()
+            .into(&mut output[..])
+            .map_err(|err| match err {
+                Error::BufferTooSmall { .. } => Error::Overflow,
+                other => other,
+            })?;
+        Ok(output[..len]
+            .iter()
+            .fold(0, |value, &byte| (value << 8) | u128::from(byte)))
+    }
+
+    /// Decode into a [`u64`], treating the input as an integer numeral.
+    ///
+    /// This is the same as [`Self::into_u128`] but with a smaller range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     u64::MAX,
+    ///     bsx::decode("jpXCZedGfVQ").with_alphabet(bsx::StaticAlphabet::BITCOIN).into_u64()?);
+    /// assert_eq!(
+    ///     bsx::decode::Error::Overflow,
+    ///     bsx::decode("jpXCZedGfVR").with_alphabet(bsx::StaticAlphabet::BITCOIN).into_u64().unwrap_err());
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn into_u64(self) -> Result<u64> {
+        use core::convert::TryFrom;
+        u64::try_from(self.into_u128()?).map_err(|_| Error::Overflow)
+    }
+
+    /// Decode into an arbitrary precision [`BigUint`](num_bigint::BigUint), treating the input
+    /// as an integer numeral.
+    ///
+    /// Unlike byte decoding, leading zero characters are simply ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     num_bigint::BigUint::from(123456789u32),
+    ///     bsx::decode("11BukQL").with_alphabet(bsx::StaticAlphabet::BITCOIN).into_biguint()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    #[cfg(feature = "bigint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bigint")))]
+    pub fn into_biguint(self) -> Result<num_bigint::BigUint> {
+        let mut output = Vec::new();
+        self.into_integer().into(&mut output)?;
+        Ok(num_bigint::BigUint::from_bytes_be(&output))
+    }
+
+    /// Decode `input` into `output` with the alphabet, skipping ignored characters and folding
+    /// case if requested.
+    fn decode_into(&self, input: &[u8], output: &mut [u8]) -> Result<usize> {
+        let kept = input
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|&(_, c)| !self.is_skipped(c));
+        if self.little_endian {
+            self.decode_kept(kept.rev(), output)
+        } else if input.iter().any(|&c| self.is_skipped(c)) {
+            self.decode_kept(kept, output)
+        } else {
+            self.decode_exact(input, output)
+        }
+    }
+
+    /// Decode the characters kept from the input, paired with their index in it, into `output`
+    /// with the alphabet, converting from bijective numeration and folding case if requested.
+    fn decode_kept(
+        &self,
+        mut input: impl DoubleEndedIterator<Item = (usize, u8)>,
+        output: &mut [u8],
+    ) -> Result<usize> {
+        #[cfg(feature = "alloc")]
+        {
+            if self.bijective {
+                let positional =
+                    Scratch::new(self.with_table(|decode| self.positional(input, decode))?);
+                return self.decode_positional(&positional, output);
+            }
+        }
+
+        if self.case_insensitive {
+            let decode = fold_case(self.alpha.decode());
+            decode_iter(input, output, self.alpha.len(), &decode)
+        } else {
+            self.alpha.decode_iter(&mut input, output)
+        }
+    }
+
+    /// Decode `input`, which contains no ignored characters, into `output` with the alphabet,
+    /// converting from bijective numeration if requested.
+    fn decode_exact(&self, input: &[u8], output: &mut [u8]) -> Result<usize> {
+        #[cfg(feature = "alloc")]
+        {
+            if self.bijective {
+                let input = input.iter().copied().enumerate();
+                let positional =
+                    Scratch::new(self.with_table(|decode| self.positional(input, decode))?);
+                return self.decode_positional(&positional, output);
+            }
+        }
+
+        self.decode_positional(input, output)
+    }
+
+    /// Decode positional `input` into `output` with the alphabet, folding case if requested.
+    fn decode_positional(&self, input: &[u8], output: &mut [u8]) -> Result<usize> {
+        if self.case_insensitive {
+            let decode = fold_case(self.alpha.decode());
+            decode_into(input, output, self.alpha.len(), &decode)
+        } else {
+            self.alpha.decode_into(input, output)
+        }
+    }
+
+    /// Convert bijective `input`, paired with the index of each character, into the positional
+    /// characters of the same value, by adding one to each digit.
+    #[cfg(feature = "alloc")]
+    fn positional(
+        &self,
+        input: impl DoubleEndedIterator<Item = (usize, u8)>,
+        decode: &[u8],
+    ) -> Result<Vec<u8>> {
+        let encode = self.alpha.encode();
+        let mut positional = Vec::with_capacity(input.size_hint().1.map_or(0, |len| len + 1));
+        let mut carry = 0;
+        for (i, c) in input.rev() {
+            let digit = usize::from(decode_digit(decode, i, c)?) + 1 + carry;
+            carry = digit / encode.len();
+            positional.push(encode[digit % encode.len()]);
+        }
+        if carry > 0 {
+            positional.push(encode[carry]);
+        }
+        positional.reverse();
+        Ok(positional)
+    }
+
+    /// Call `f` with the decoding table, folded if requested.
+    fn with_table<R>(&self, f: impl FnOnce(&[u8]) -> R) -> R {
+        if self.case_insensitive {
+            f(&fold_case(self.alpha.decode()))
+        } else {
+            f(self.alpha.decode())
+        }
+    }
+
+    /// Split the check symbol off the end of `input` if requested, returning the rest of the input
+    /// and the check value.
+    fn split_check<'a>(&self, input: &'a [u8]) -> Result<(&'a [u8], Option<usize>)> {
+        if !self.check_symbol {
+            return Ok((input, None));
+        }
+        let index = input
+            .iter()
+            .rposition(|&c| !self.is_ignored(c))
+            .ok_or(Error::InvalidChecksum)?;
+        let c = input[index];
+        let symbol = match c.to_ascii_uppercase() {
+            b'O' => b'0',
+            b'I' | b'L' => b'1',
+            other => other,
+        };
+        match CHECK_SYMBOLS.iter().position(|&s| s == symbol) {
+            Some(check) => Ok((&input[..index], Some(check))),
+            None if c > 127 => Err(Error::NonAsciiCharacter { index }),
+            None => Err(Error::InvalidCharacter {
+                character: c as char,
+                index,
+            }),
+        }
+    }
+
+    /// Strip surrounding whitespace from `input` if requested, returning the length stripped from
+    /// the start along with the rest of the input.
+    fn trim<'a>(&self, input: &'a [u8]) -> (usize, &'a [u8]) {
+        if !self.trim {
+            return (0, input);
+        }
+        let start = input
+            .iter()
+            .position(|c| !c.is_ascii_whitespace())
+            .unwrap_or(input.len());
+        let end = input
+            .iter()
+            .rposition(|c| !c.is_ascii_whitespace())
+            .map_or(start, |i| i + 1);
+        (start, &input[start..end])
+    }
+
+    /// Strip any trailing padding characters from `input`, along with any skipped characters
+    /// between them such as the newlines of wrapped output.
+    fn trim_padding<'a>(&self, input: &'a [u8]) -> &'a [u8] {
+        match self.padding {
+            Some(padding) => {
+                let len = input
+                    .iter()
+                    .rposition(|&c| c != padding && !self.is_skipped(c))
+                    .map_or(0, |i| i + 1);
+                &input[..len]
+            }
+            None => input,
+        }
+    }
+
+    /// Strip any trailing padding characters from `input` like [`Self::trim_padding`], checking
+    /// that they complete the last block when the alphabet is bit-packed.
+    ///
+    /// A block is the fewest characters holding a whole number of bytes, such as 4 for base64,
+    /// and the characters before the padding must leave fewer bits over than a full character.
+    fn check_padding<'a>(&self, input: &'a [u8]) -> Result<&'a [u8]> {
+        let trimmed = self.trim_padding(input);
+        let padding = match self.padding {
+            Some(padding) if self.alpha.bit_packed() => padding,
+            _ => return Ok(trimmed),
+        };
+        let padded = input[trimmed.len()..]
+            .iter()
+            .filter(|&&c| c == padding)
+            .count();
+        if padded == 0 {
+            return Ok(trimmed);
+        }
+        let bits = self.alpha.len().trailing_zeros() as usize;
+        let (_, block) = crate::pow2_unit(bits as u32);
+        let last = trimmed.iter().filter(|&&c| !self.is_skipped(c)).count() % block;
+        if last == 0 || padded != block - last || last * bits % 8 >= bits {
+            return Err(Error::InvalidPadding);
+        }
+        Ok(trimmed)
+    }
+
+    /// The number of zero bytes given by the count character of
+    /// [`LeadingZeros::CountPrefix`] input, and the index of the rest of the input after it.
+    fn count_prefix(&self, input: &[u8]) -> Result<(usize, usize)> {
+        if self.zeros != LeadingZeros::CountPrefix {
+            return Ok((0, 0));
+        }
+        match input.iter().position(|&c| !self.is_skipped(c)) {
+            Some(index) => {
+                let count = self.with_table(|decode| decode_digit(decode, index, input[index]))?;
+                Ok((usize::from(count), index + 1))
+            }
+            None => Ok((0, input.len())),
+        }
+    }
+
+    /// Whether `c` is one of the ignored characters.
+    fn is_ignored(&self, c: u8) -> bool {
+        c < 128 && self.ignore & 1 << c != 0
+    }
+
+    /// Whether `c` should be skipped, either as one of the ignored characters or, when lenient, as
+    /// a character not part of the alphabet.
+    fn is_skipped(&self, c: u8) -> bool {
+        let decode = self.alpha.decode();
+        let invalid = || {
+            decode[c as usize] == 0xFF
+                && !(self.case_insensitive
+                    && c.is_ascii_alphabetic()
+                    && decode[(c ^ 0x20) as usize] != 0xFF)
+        };
+        self.is_ignored(c) || (self.lenient && invalid())
+    }
+
+    /// The number of leading zero or ignored characters in `input`, bijective input has no zero
+    /// characters.
+    fn leading_zeros(&self, input: &[u8]) -> usize {
+        self.with_table(|decode| {
+            let is_zero =
+                |&&c: &&u8| (!self.bijective && decode[c as usize] == 0) || self.is_skipped(c);
+            if self.little_endian {
+                input.iter().rev().take_while(is_zero).count()
+            } else {
+                input.iter().take_while(is_zero).count()
+            }
+        })
+    }
+}
+
+/// Extend a decoding table so that letters not part of the alphabet decode the same as their other
+/// case.
+fn fold_case(decode: &[u8]) -> [u8; 256] {
+    let mut folded = [0xFF; 256];
+    folded.copy_from_slice(decode);
+    for c in (b'A'..=b'Z').chain(b'a'..=b'z') {
+        if folded[c as usize] == 0xFF {
+            folded[c as usize] = decode[(c ^ 0x20) as usize];
+        }
+    }
+    folded
+}
+
+/// A reusable decoder for a single alphabet, that keeps its output buffer between calls to
+/// amortize allocation when decoding many values.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut decoder = bsx::decode::Decoder::new(bsx::StaticAlphabet::BITCOIN);
+/// assert_eq!(
+///     [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+///     decoder.decode("he11owor1d")?);
+/// assert_eq!([0x61], decoder.decode("2g")?);
+/// # Ok::<(), bsx::decode::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+#[allow(missing_debug_implementations)]
+pub struct Decoder<A> {
+    alpha: A,
+    output: Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl<A: Alphabet> Decoder<A> {
+    /// Create a decoder for the given alphabet.
+    pub fn new(alpha: A) -> Self {
+        Decoder {
+            alpha,
+            output: Vec::new(),
+        }
+    }
+
+    /// Decode the input, returning a reference to the decoder's internal buffer.
+    ///
+    /// See the documentation for [`bsx::decode`](crate::decode()) for an
+    /// explanation of the errors that may occur.
+    pub fn decode(&mut self, input: impl AsRef<[u8]>) -> Result<&[u8]> {
+        crate::decode(input)
+            .with_alphabet(&self.alpha)
+            .into(&mut self.output)?;
+        Ok(&self.output)
+    }
+}
+
+/// Decode many inputs with the same alphabet, reusing a single scratch buffer so that each output
+/// is allocated exactly once at its final size.
+///
+/// Returns the first error encountered, see the documentation for
+/// [`bsx::decode`](crate::decode()) for an explanation of the errors that may occur.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(
+///     vec![vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], vec![0x61]],
+///     bsx::decode::decode_many(&["he11owor1d", "2g"], bsx::StaticAlphabet::BITCOIN)?);
+/// # Ok::<(), bsx::decode::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub fn decode_many<I: AsRef<[u8]>>(inputs: &[I], alpha: impl Alphabet) -> Result<Vec<Vec<u8>>> {
+    let mut decoder = Decoder::new(alpha);
+    inputs
+        .iter()
+        .map(|input| decoder.decode(input).map(<[u8]>::to_vec))
+        .collect()
+}
+
+/// Decode `input` into little-endian bytes in a `const` context, returning them along with the
+/// decoded length.
+pub(crate) const fn decode_const_le<const N: usize, const LEN: usize>(
+    input: &str,
+    alpha: &StaticAlphabet<LEN>,
+) -> ([u8; N], usize) {
+    let input = input.as_bytes();
+
+    // Mutable references are not available in `const fn`, so this is a simple character at a
+    // time conversion written out in full with `while` loops
+    let mut bytes = [0u8; N];
+    let mut len = 0;
+
+    let mut i = 0;
+    while i < input.len() {
+        let digit = alpha.decode[input[i] as usize];
+        if digit == 0xFF && input[i] > 127 {
+            panic!("input contained a non-ascii character");
+        }
+        if digit == 0xFF {
+            panic!("input contained a character that was not part of the alphabet");
+        }
+        let mut carry = digit as usize;
+        let mut j = 0;
+        while j < len {
+            carry += bytes[j] as usize * LEN;
+            bytes[j] = carry as u8;
+            carry >>= 8;
+            j += 1;
+        }
+        while carry > 0 {
+            if len == N {
+                panic!("decoded output is longer than the array");
+            }
+            bytes[len] = carry as u8;
+            carry >>= 8;
+            len += 1;
+        }
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < input.len() && alpha.decode[input[i] as usize] == 0 {
+        if len == N {
+            panic!("decoded output is longer than the array");
+        }
+        bytes[len] = 0;
+        len += 1;
+        i += 1;
+    }
+
+    (bytes, len)
+}
+
+/// Decode `input` in a `const` context, see [`bsx::decode_const`](crate::decode_const()).
+pub(crate) const fn decode_const<const N: usize, const LEN: usize>(
+    input: &str,
+    alpha: &StaticAlphabet<LEN>,
+) -> [u8; N] {
+    let (bytes, len) = decode_const_le::<N, LEN>(input, alpha);
+    if len != N {
+        panic!("decoded output is shorter than the array");
+    }
+
+    let mut output = [0; N];
+    let mut i = 0;
+    while i < N {
+        output[i] = bytes[N - 1 - i];
+        i += 1;
+    }
+    output
+}
+
+/// Lookup the value of the character `c` at `index` in the input.
+pub(crate) fn decode_digit(decode: &[u8], index: usize, c: u8) -> Result<u8> {
+    let digit = decode.get(c as usize).copied().unwrap_or(0xFF);
+    if digit == 0xFF && c > 127 {
+        return Err(Error::NonAsciiCharacter { index });
+    }
+    if digit == 0xFF {
+        return Err(Error::InvalidCharacter {
+            character: c as char,
+            index,
+        });
+    }
+
+    Ok(digit)
+}
+
+/// Decode an input short enough that it may fit in a `u128` using native arithmetic instead of
+/// limbs, returns `None` if it turns out not to fit.
+fn decode_u128(
+    input: &[u8],
+    output: &mut [u8],
+    decode: &[u8],
+    len: impl Len,
+) -> Result<Option<usize>> {
+    let len = len.get() as u64;
+    let mut value = 0u128;
+    for (i, &c) in input.iter().enumerate() {
+        let digit = decode_digit(decode, i, c)?;
+        value = match value
+            .checked_mul(len.into())
+            .and_then(|value| value.checked_add(digit.into()))
+        {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+    }
+
+    let zeros = input
+        .iter()
+        .take_while(|&&c| decode[c as usize] == 0)
+        .count();
+    let bytes = Scratch::new(value.to_be_bytes());
+    let bytes = &bytes[(value.leading_zeros() / 8) as usize..];
+    let output = output
+        .get_mut(..zeros + bytes.len())
+        .ok_or(Error::BufferTooSmall {
+            required: zeros + bytes.len(),
+        })?;
+    let (leading, rest) = output.split_at_mut(zeros);
+    leading.fill(0);
+    rest.copy_from_slice(bytes);
+    Ok(Some(zeros + bytes.len()))
+}
+
+/// Decode `input` using the 256 entry decoding table `decode`, `length` is the alphabet length
+/// which may be a compile time constant.
+pub(crate) fn decode_into<L: Len>(
+    input: &[u8],
+    output: &mut [u8],
+    length: L,
+    decode: &[u8],
+) -> Result<usize> {
+    let len = length.get() as u64;
+
+    #[cfg(feature = "rayon")]
+    {
+        if len > 1 && len.is_power_of_two() && input.len() >= crate::parallel::THRESHOLD {
+            return crate::parallel::decode(input, output, decode, len as usize);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    {
+        if len > 1 && input.len() >= crate::bignum::DECODE_THRESHOLD {
+            let digits = input
+                .iter()
+                .enumerate()
+                .map(|(i, &c)| decode_digit(decode, i, c));
+            return decode_bignum(digits, output, len);
+        }
+    }
+
+    if len > 1 && input.len() * (63 - len.leading_zeros()) as usize <= 128 {
+        if let Some(written) = decode_u128(input, output, decode, length)? {
+            return Ok(written);
+        }
+    }
+
+    let (_, group_len) = length.limb_base();
+    let mut value = Limbs::default();
+
+    // Characters are translated a block at a time so that the lookups can be vectorized
+    let block_len = 64 / group_len * group_len;
+    let mut digits = Scratch::new([0; 64]);
+
+    for (block_index, block) in input.chunks(block_len).enumerate() {
+        let digits = &mut digits[..block.len()];
+        if !crate::simd::decode(block, digits, &decode[..128]) {
+            // Redo the lookups one at a time, either finding which character was invalid or
+            // handling non-ASCII characters of an extended alphabet
+            let start = block_index * block_len;
+            for (i, (&c, digit)) in block.iter().zip(&mut *digits).enumerate() {
+                *digit = decode_digit(decode, start + i, c)?;
+            }
+        }
+        value.push(digits, output, len, group_len)?;
+    }
+
+    let zeros = input
+        .iter()
+        .take_while(|&&c| decode[c as usize] == 0)
+        .count();
+    value.finish(zeros, output)
+}
+
+/// Decode the characters of `input` paired with their index in the original input, for input
+/// that has characters skipped or converted as it is read so there is no slice to decode.
+///
+/// The block buffer is filled one character at a time, keeping the indexes alongside for
+/// reporting errors.
+pub(crate) fn decode_iter<L: Len>(
+    mut input: impl Iterator<Item = (usize, u8)>,
+    output: &mut [u8],
+    length: L,
+    decode: &[u8],
+) -> Result<usize> {
+    let len = length.get() as u64;
+
+    #[cfg(feature = "alloc")]
+    {
+        if len > 1
+            && input
+                .size_hint()
+                .1
+                .is_some_and(|n| n >= crate::bignum::DECODE_THRESHOLD)
+        {
+            let digits = input.map(|(i, c)| decode_digit(decode, i, c));
+            return decode_bignum(digits, output, len);
+        }
+    }
+
+    let (_, group_len) = length.limb_base();
+    let mut value = Limbs::default();
+
+    let block_len = 64 / group_len * group_len;
+    let mut block = Scratch::new([0; 64]);
+    let mut indexes = [0; 64];
+    let mut digits = Scratch::new([0; 64]);
+
+    // The input can only be read once, so leading zero characters are counted along the way
+    let mut zeros = 0;
+    let mut leading = true;
+
+    loop {
+        let mut filled = 0;
+        for (index, c) in input.by_ref().take(block_len) {
+            block[filled] = c;
+            indexes[filled] = index;
+            filled += 1;
+        }
+
+        let digits = &mut digits[..filled];
+        if !crate::simd::decode(&block[..filled], digits, &decode[..128]) {
+            for ((&c, &index), digit) in block.iter().zip(&indexes).zip(&mut *digits) {
+                *digit = decode_digit(decode, index, c)?;
+            }
+        }
+        if leading {
+            let count = digits.iter().take_while(|&&digit| digit == 0).count();
+            zeros += count;
+            leading = count == filled;
+        }
+        value.push(digits, output, len, group_len)?;
+
+        if filled < block_len {
+            break;
+        }
+    }
+
+    value.finish(zeros, output)
+}
+
+/// Decode a long input with the subquadratic bignum conversion rather than limbs.
+#[cfg(feature = "alloc")]
+fn decode_bignum(
+    digits: impl Iterator<Item = Result<u8>>,
+    output: &mut [u8],
+    len: u64,
+) -> Result<usize> {
+    let digits = Scratch::new(digits.collect::<Result<Vec<u8>>>()?);
+    let bytes = Scratch::new(crate::bignum::decode(&digits, len as usize));
+    output
+        .get_mut(..bytes.len())
+        .ok_or(Error::BufferTooSmall {
+            required: bytes.len(),
+        })?
+        .copy_from_slice(&bytes);
+    Ok(bytes.len())
+}
+
+/// A value being decoded, accumulated as little-endian `u32` limbs stored at the start of the
+/// output. The most significant limb is kept separate in `top` so that the stored limbs never
+/// need more space than the final bytes will.
+#[derive(Default)]
+struct Limbs {
+    limbs: usize,
+    top: u64,
+}
+
+impl Limbs {
+    /// Add `digits` to the value.
+    ///
+    /// Digits are folded into a single multiplier of `len^group_len` so each pass over the limbs
+    /// accounts for as many digits as will fit in a `u32`.
+    fn push(&mut self, digits: &[u8], output: &mut [u8], len: u64, group_len: usize) -> Result<()> {
+        for group in digits.chunks(group_len) {
+            let (mut val, multiplier) = group
+                .iter()
+                .fold((0u64, 1u64), |(val, multiplier), &digit| {
+                    (val * len + u64::from(digit), multiplier * len)
+                });
+
+            for chunk in output[..self.limbs * 4].chunks_exact_mut(4) {
+                val += u64::from(u32::from_le_bytes(chunk.try_into().unwrap())) * multiplier;
+                chunk.copy_from_slice(&(val as u32).to_le_bytes());
+                val >>= 32;
+            }
+
+            self.top = self.top * multiplier + val;
+            if self.top > u64::from(u32::MAX) {
+                let index = self.limbs * 4;
+                output
+                    .get_mut(index..index + 4)
+                    .ok_or(Error::BufferTooSmall {
+                        required: index + 4,
+                    })?
+                    .copy_from_slice(&(self.top as u32).to_le_bytes());
+                self.limbs += 1;
+                self.top >>= 32;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write out the rest of the value followed by a zero byte for each of the `zeros` leading
+    /// zero characters, then reverse the output into big-endian order.
+    fn finish(mut self, zeros: usize, output: &mut [u8]) -> Result<usize> {
+        let mut index = self.limbs * 4;
+        while self.top > 0 {
+            let byte = output.get_mut(index).ok_or(Error::BufferTooSmall {
+                required: index + 1,
+            })?;
+            *byte = self.top as u8;
+            index += 1;
+            self.top >>= 8;
+        }
+
+        let end = index + zeros;
+        output
+            .get_mut(index..end)
+            .ok_or(Error::BufferTooSmall { required: end })?
+            .fill(0);
+
+        output[..end].reverse();
+        Ok(end)
+    }
+}
+
+impl Error {
+    /// Adjust the input index of the error, if it has one.
+    fn map_index(self, f: impl FnOnce(usize) -> usize) -> Self {
+        match self {
+            Error::InvalidCharacter { character, index } => Error::InvalidCharacter {
+                character,
+                index: f(index),
+            },
+            Error::NonAsciiCharacter { index } => Error::NonAsciiCharacter { index: f(index) },
+            other => other,
+        }
+    }
+
+    /// The input index of the error, if it has one.
+    fn index(&self) -> Option<usize> {
+        match *self {
+            Error::InvalidCharacter { index, .. } | Error::NonAsciiCharacter { index } => {
+                Some(index)
+            }
+            _ => None,
+        }
+    }
+
+    /// The line and column in `input` of the character this error refers to, for diagnostics on
+    /// wrapped or multi-line input.
+    ///
+    /// `input` should be the same input that was being decoded. Lines are split on `\n`, and
+    /// columns count characters rather than bytes, both starting from 1.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bsx::decode::Position;
+    ///
+    /// let input = "he11owor1d\nhe11owor1d\nhe11o0wor1d\n";
+    /// let err = bsx::decode(input)
+    ///     .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///     .wrapped()
+    ///     .into_vec()
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     bsx::decode::Error::InvalidCharacter { character: '0', index: 27 },
+    ///     err);
+    /// assert_eq!(Some(Position { line: 3, column: 6 }), err.position(input));
+    /// ```
+    pub fn position(&self, input: impl AsRef<[u8]>) -> Option<Position> {
+        let before = input.as_ref().get(..self.index()?)?;
+        let line_start = before
+            .iter()
+            .rposition(|&c| c == b'\n')
+            .map_or(0, |i| i + 1);
+        Some(Position {
+            line: before.iter().filter(|&&c| c == b'\n').count() + 1,
+            column: before[line_start..]
+                .iter()
+                .filter(|&&c| c & 0xC0 != 0x80)
+                .count()
+                + 1,
+        })
+    }
+
+    /// Capture a short snippet of `input` around the character this error refers to, so that
+    /// messages shown to end users can pinpoint the problem in long inputs.
+    ///
+    /// `input` should be the same input that was being decoded. Errors that don't refer to a
+    /// character have no snippet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = "he11owor1dhe11owor1dhe11owor10dhe11owor1dhe11owor1d";
+    /// let err = bsx::decode(input)
+    ///     .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///     .into_vec()
+    ///     .unwrap_err()
+    ///     .with_context(input);
+    /// assert_eq!(Some("dhe11owor10dhe11owor1"), err.snippet());
+    /// assert_eq!(
+    ///     r#"provided string contained invalid character '0' at byte 29 (line 1, column 30), near "...dhe11owor10dhe11owor1...""#,
+    ///     err.to_string());
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn with_context(self, input: impl AsRef<[u8]>) -> ContextError {
+        let input = input.as_ref();
+        let window = self
+            .index()
+            .filter(|&index| index < input.len())
+            .map(|index| {
+                // Widen the window to whole characters, so the snippet doesn't start or end with a
+                // partial one
+                let is_boundary = |i: usize| input[i] & 0xC0 != 0x80;
+                let mut start = index.saturating_sub(CONTEXT_LEN);
+                while start > 0 && !is_boundary(start) {
+                    start -= 1;
+                }
+                let mut end = (index + 1 + CONTEXT_LEN).min(input.len());
+                while end < input.len() && !is_boundary(end) {
+                    end += 1;
+                }
+                (start, end)
+            });
+        ContextError {
+            error: self,
+            position: self.position(input),
+            snippet: window
+                .map(|(start, end)| String::from_utf8_lossy(&input[start..end]).into_owned()),
+            truncated: window.map_or((false, false), |(start, end)| {
+                (start > 0, end < input.len())
+            }),
+        }
+    }
+}
+
+/// A line and column in the input, returned by [`Error::position`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Position {
+    /// The line number, starting from 1.
+    pub line: usize,
+    /// The column number in characters, starting from 1.
+    pub column: usize,
+}
+
+/// The number of bytes of input either side of the invalid character kept by
+/// [`Error::with_context`].
+#[cfg(feature = "alloc")]
+const CONTEXT_LEN: usize = 10;
+
+/// An [`Error`] along with the position and a snippet of the input around the character it refers
+/// to, created by [`Error::with_context`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContextError {
+    error: Error,
+    position: Option<Position>,
+    snippet: Option<String>,
+    /// Whether the input continues before and after the snippet.
+    truncated: (bool, bool),
+}
+
+#[cfg(feature = "alloc")]
+impl ContextError {
+    /// The underlying error.
+    pub fn error(&self) -> Error {
+        self.error
+    }
+
+    /// The line and column of the character the error refers to.
+    pub fn position(&self) -> Option<Position> {
+        self.position
+    }
+
+    /// The part of the input surrounding the character the error refers to, with any invalid
+    /// UTF-8 replaced.
+    pub fn snippet(&self) -> Option<&str> {
+        self.snippet.as_deref()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<ContextError> for Error {
+    fn from(err: ContextError) -> Self {
+        err.error
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ContextError {}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        if let Some(Position { line, column }) = self.position {
+            write!(f, " (line {}, column {})", line, column)?;
+        }
+        if let Some(snippet) = &self.snippet {
+            let (before, after) = self.truncated;
+            write!(
+                f,
+                ", near \"{}{}{}\"",
+                if before { "..." } else { "" },
+                snippet.escape_debug(),
+                if after { "..." } else { "" }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::BufferTooSmall { required } => write!(
+                f,
+                "buffer provided to decode string into was too small, {} bytes are required",
+                required
+            ),
+            Error::InvalidCharacter { character, index } => write!(
                 f,
                 "provided string contained invalid character {:?} at byte {}",
                 character, index
@@ -184,6 +2026,26 @@ impl fmt::Display for Error {
                 "provided string contained non-ascii character starting at byte {}",
                 index
             ),
+            Error::Overflow => write!(f, "decoded value was too large for the integer type"),
+            Error::InvalidLength { expected } => write!(
+                f,
+                "provided string did not decode to exactly {} bytes",
+                expected
+            ),
+            Error::InvalidChecksum => write!(f, "provided string had an invalid checksum"),
+            Error::InputTooLong { max } => {
+                write!(
+                    f,
+                    "provided string was longer than the limit of {} bytes",
+                    max
+                )
+            }
+            Error::InvalidPadding => {
+                write!(
+                    f,
+                    "provided string had padding that did not complete a block"
+                )
+            }
         }
     }
 }
@@ -0,0 +1,173 @@
+//! Functions for finding which alphabets an encoded string could be in.
+
+use core::fmt;
+
+use crate::{alphabet, Alphabet};
+
+/// A builder for setting up the alphabets to check an input against.
+///
+/// See the documentation for [`bsx::detect`](crate::detect()) for a more
+/// high level view of how to use this.
+#[derive(Clone)]
+pub struct DetectBuilder<'s, 'a, I: AsRef<[u8]>> {
+    input: I,
+    builtins: bool,
+    alphabets: &'s [(&'a str, &'a dyn Alphabet)],
+}
+
+/// An alphabet that every character of the input is part of.
+#[derive(Copy, Clone)]
+pub struct Candidate<'a> {
+    /// The name of the alphabet, as used by [`alphabet::by_name`] for the built-in alphabets.
+    pub name: &'a str,
+    /// The alphabet itself, for decoding the input.
+    pub alphabet: &'a dyn Alphabet,
+}
+
+impl<I: AsRef<[u8]>> DetectBuilder<'static, 'static, I> {
+    pub(crate) fn new(input: I) -> Self {
+        DetectBuilder {
+            input,
+            builtins: true,
+            alphabets: &[],
+        }
+    }
+}
+
+impl<'s, 'a, I: AsRef<[u8]>> DetectBuilder<'s, 'a, I> {
+    /// Also check the input against the given named alphabets, after the built-in alphabets.
+    ///
+    /// This replaces any alphabets given to an earlier call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let digits = bsx::StaticAlphabet::new(b"0123456789")?;
+    /// let names: Vec<_> = bsx::detect("1234")
+    ///     .with_alphabets(&[("decimal", &digits)])
+    ///     .candidates()
+    ///     .map(|candidate| candidate.name)
+    ///     .collect();
+    /// assert_eq!(names.last(), Some(&"decimal"));
+    /// # Ok::<(), bsx::alphabet::Error>(())
+    /// ```
+    pub fn with_alphabets<'t, 'b>(
+        self,
+        alphabets: &'t [(&'b str, &'b dyn Alphabet)],
+    ) -> DetectBuilder<'t, 'b, I> {
+        DetectBuilder {
+            input: self.input,
+            builtins: self.builtins,
+            alphabets,
+        }
+    }
+
+    /// Don't check the input against the built-in alphabets, only those given to
+    /// [`with_alphabets`](Self::with_alphabets).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let digits = bsx::StaticAlphabet::new(b"0123456789")?;
+    /// let octal = bsx::StaticAlphabet::new(b"01234567")?;
+    /// let names: Vec<_> = bsx::detect("1289")
+    ///     .with_alphabets(&[("decimal", &digits), ("octal", &octal)])
+    ///     .without_builtins()
+    ///     .candidates()
+    ///     .map(|candidate| candidate.name)
+    ///     .collect();
+    /// assert_eq!(vec!["decimal"], names);
+    /// # Ok::<(), bsx::alphabet::Error>(())
+    /// ```
+    pub fn without_builtins(self) -> Self {
+        DetectBuilder {
+            builtins: false,
+            ..self
+        }
+    }
+
+    /// The alphabets that every character of the input is part of, the built-in alphabets in the
+    /// order of [`alphabet::by_name`] followed by any given to
+    /// [`with_alphabets`](Self::with_alphabets).
+    ///
+    /// Built-in alphabets with the same characters as an earlier one, such as `"monero"` and
+    /// `"bitcoin"`, are only reported once under the first name.
+    ///
+    /// Only the characters are checked, an input may still fail to decode with a candidate
+    /// alphabet if it has a checksum or length requirements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let candidates = |input| {
+    ///     bsx::detect(input)
+    ///         .candidates()
+    ///         .map(|candidate| candidate.name)
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(
+    ///     vec!["bitcoin", "ripple", "flickr", "crockford", "zbase32", "base36", "base62", "base64", "base64url", "z85", "base91"],
+    ///     candidates("he11owor1d"));
+    /// assert_eq!(
+    ///     vec!["crockford", "base16", "base16upper", "base36", "base62", "base64", "base64url", "ascii85", "z85", "base91"],
+    ///     candidates("deadbeef01"));
+    /// assert!(candidates("hello world").is_empty());
+    /// ```
+    pub fn candidates(&self) -> impl Iterator<Item = Candidate<'a>> + '_ {
+        let builtins = alphabet::NAMED
+            .iter()
+            .enumerate()
+            .filter(move |_| self.builtins)
+            // Skip aliases of an earlier name
+            .filter(|&(i, &(_, alpha))| {
+                !alphabet::NAMED[..i]
+                    .iter()
+                    .any(|&(_, other)| other.encode() == alpha.encode())
+            })
+            .map(|(_, &(name, alphabet))| Candidate { name, alphabet });
+        let custom = self
+            .alphabets
+            .iter()
+            .map(|&(name, alphabet)| Candidate { name, alphabet });
+        builtins.chain(custom).filter(move |candidate| {
+            let decode = candidate.alphabet.decode();
+            self.input
+                .as_ref()
+                .iter()
+                .all(|&c| decode[c as usize] != 0xFF)
+        })
+    }
+}
+
+impl fmt::Debug for Candidate<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Candidate")
+            .field("name", &self.name)
+            .field("len", &self.alphabet.len())
+            .finish()
+    }
+}
+
+impl<I: AsRef<[u8]> + fmt::Debug> fmt::Debug for DetectBuilder<'_, '_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Alphabets<'s, 'a>(&'s [(&'a str, &'a dyn Alphabet)]);
+
+        impl fmt::Debug for Alphabets<'_, '_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list()
+                    .entries(
+                        self.0
+                            .iter()
+                            .map(|&(name, alphabet)| Candidate { name, alphabet }),
+                    )
+                    .finish()
+            }
+        }
+
+        f.debug_struct("DetectBuilder")
+            .field("input", &self.input)
+            .field("builtins", &self.builtins)
+            .field("alphabets", &Alphabets(self.alphabets))
+            .finish()
+    }
+}
@@ -1,17 +1,329 @@
 //! Functions for encoding arbitrary bases into strings.
 
-use core::fmt;
+use core::{convert::TryInto, fmt, mem::MaybeUninit};
 
 #[cfg(feature = "alloc")]
 use alloc::{string::String, vec::Vec};
 
-use crate::{alphabet::Unspecified, Alphabet};
+use crate::{
+    alphabet::{Len, Unspecified},
+    multibase, Alphabet, StaticAlphabet,
+};
 
 /// A builder for setting up the alphabet and output of an encode.
-#[allow(missing_debug_implementations)]
+#[derive(Clone, Debug)]
 pub struct EncodeBuilder<I: AsRef<[u8]>, A> {
     input: I,
     alpha: A,
+    prefix: Option<u8>,
+    format: Format,
+}
+
+/// How leading zero bytes of the input are represented, see [`EncodeBuilder::with_leading_zeros`]
+/// and [`DecodeBuilder::with_leading_zeros`](crate::decode::DecodeBuilder::with_leading_zeros).
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum LeadingZeros {
+    /// Each leading zero byte is encoded as a leading zero character, as in Base58Check.
+    #[default]
+    Preserve,
+
+    /// The input is treated purely as an integer, leading zero bytes are dropped and a zero value
+    /// is encoded as a single zero character.
+    Ignore,
+
+    /// The number of leading zero bytes is encoded as a single character, the digit of that
+    /// value, followed by the rest of the input treated as an integer.
+    CountPrefix,
+}
+
+/// The symbols for Crockford's check values `0` to `36`.
+pub(crate) const CHECK_SYMBOLS: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
+/// The value of `input` as a big-endian integer modulo 37, for Crockford's check symbol.
+pub(crate) fn check_value(input: &[u8]) -> usize {
+    input
+        .iter()
+        .fold(0, |value, &byte| (value * 256 + usize::from(byte)) % 37)
+}
+
+/// Post-processing applied to the encoded characters.
+#[derive(Copy, Clone, Debug, Default)]
+struct Format {
+    /// How leading zero bytes are represented.
+    zeros: LeadingZeros,
+    /// Whether to append Crockford's check symbol.
+    check_symbol: bool,
+    /// Whether to use bijective numeration, with no zero digit.
+    bijective: bool,
+    /// Whether to output the least significant digit first.
+    little_endian: bool,
+    /// The minimum number of characters, padded with the zero character.
+    min_width: usize,
+    /// A separator character to insert, and how many characters to insert it between.
+    separator: Option<(u8, usize)>,
+    /// How many columns to wrap lines at, including any prefix.
+    wrap: Option<usize>,
+    /// Whether to force letters to uppercase (`true`) or lowercase (`false`).
+    uppercase: Option<bool>,
+    /// A padding character to append, and the multiple of characters to pad to.
+    padding: Option<(u8, usize)>,
+}
+
+impl Format {
+    /// An upper bound on the formatted length of `len` encoded characters.
+    fn max_len(&self, len: usize) -> Option<usize> {
+        let len = len.max(self.min_width);
+        let len = match self.separator {
+            Some((_, every)) => len.checked_add(len.saturating_sub(1) / every)?,
+            None => len,
+        };
+        match self.padding {
+            Some((_, multiple)) => len.div_ceil(multiple).checked_mul(multiple),
+            None => Some(len),
+        }
+    }
+
+    /// Format the `len` encoded characters at the start of `output` in place, returning the new
+    /// length.
+    ///
+    /// # Safety
+    ///
+    /// The first `len` bytes of `output` must be initialized.
+    unsafe fn apply(
+        &self,
+        output: &mut [MaybeUninit<u8>],
+        len: usize,
+        alpha: &impl Alphabet,
+    ) -> Result<usize> {
+        let mut len = len;
+        if self.bijective {
+            len = bijective(output, len, alpha);
+        }
+        if self.little_endian {
+            // SAFETY: the caller guarantees the first `len` bytes are initialized
+            assume_init_mut(&mut output[..len]).reverse();
+        }
+        if len < self.min_width {
+            let zero = alpha.encode()[0];
+            len = pad(output, len, zero, self.min_width, self.little_endian)?;
+        }
+        if let Some(uppercase) = self.uppercase {
+            let decode = alpha.decode();
+            for c in &mut output[..len] {
+                // SAFETY: the caller guarantees the first `len` bytes are initialized
+                let value = c.assume_init();
+                let other = if uppercase {
+                    value.to_ascii_uppercase()
+                } else {
+                    value.to_ascii_lowercase()
+                };
+                // Only change the case if it doesn't turn into a different character of the
+                // alphabet
+                let other_value = decode[other as usize];
+                if other_value == 0xFF || other_value == decode[value as usize] {
+                    c.write(other);
+                }
+            }
+        }
+        if let Some((separator, every)) = self.separator {
+            len = insert_separators(output, len, separator, every)?;
+        }
+        if let Some((padding, multiple)) = self.padding {
+            let padded = len.div_ceil(multiple) * multiple;
+            for c in output
+                .get_mut(len..padded)
+                .ok_or(Error::BufferTooSmall { required: padded })?
+            {
+                c.write(padding);
+            }
+            len = padded;
+        }
+        Ok(len)
+    }
+}
+
+/// Convert the `len` positional encoded characters at the start of `output` into bijective
+/// numeration in place, returning the new length.
+///
+/// A value `n` with positional digits `P` of length `p` has bijective digits `B` of length `L`
+/// where `B + 1` (each digit incremented) equals `P`, so `B` is `P` minus the all ones number of
+/// length `L`, with `L` either `p` or `p - 1` depending on whether `P` is at least all ones.
+///
+/// # Safety
+///
+/// The first `len` bytes of `output` must be initialized.
+unsafe fn bijective(output: &mut [MaybeUninit<u8>], len: usize, alpha: &impl Alphabet) -> usize {
+    let (encode, decode) = (alpha.encode(), alpha.decode());
+    let k = encode.len() as u16;
+    // SAFETY: the caller guarantees the first `len` bytes are initialized
+    let digits = assume_init_mut(&mut output[..len]);
+    for digit in &mut *digits {
+        *digit = decode[*digit as usize];
+    }
+
+    let at_least_ones = match digits.iter().find(|&&digit| digit != 1) {
+        Some(&digit) => digit > 1,
+        None => true,
+    };
+    let ones = if at_least_ones {
+        len
+    } else {
+        len.saturating_sub(1)
+    };
+
+    let mut borrow = 0;
+    for (i, digit) in digits.iter_mut().rev().enumerate() {
+        let subtrahend = u16::from(i < ones) + borrow;
+        borrow = u16::from(u16::from(*digit) < subtrahend);
+        *digit = (u16::from(*digit) + borrow * k - subtrahend) as u8;
+    }
+
+    // When subtracting the shorter all ones number the top digit is now zero
+    let start = len - ones;
+    for digit in &mut *digits {
+        *digit = encode[*digit as usize];
+    }
+    digits.copy_within(start.., 0);
+    ones
+}
+
+/// Pad the first `len` bytes of `output` with `zero` up to `width`, on the left or on the right if
+/// `little_endian`, returning the new length.
+fn pad(
+    output: &mut [MaybeUninit<u8>],
+    len: usize,
+    zero: u8,
+    width: usize,
+    little_endian: bool,
+) -> Result<usize> {
+    let output = output
+        .get_mut(..width)
+        .ok_or(Error::BufferTooSmall { required: width })?;
+    let padding = if little_endian {
+        &mut output[len..]
+    } else {
+        output.copy_within(..len, width - len);
+        &mut output[..width - len]
+    };
+    for digit in padding {
+        digit.write(zero);
+    }
+    Ok(width)
+}
+
+/// Insert `separator` between every group of `every` characters of the first `len` bytes of
+/// `output`, returning the new length.
+///
+/// # Safety
+///
+/// The first `len` bytes of `output` must be initialized.
+unsafe fn insert_separators(
+    output: &mut [MaybeUninit<u8>],
+    len: usize,
+    separator: u8,
+    every: usize,
+) -> Result<usize> {
+    let new_len = len + len.saturating_sub(1) / every;
+    if new_len > output.len() {
+        return Err(Error::BufferTooSmall { required: new_len });
+    }
+
+    // Work backwards so that each character is moved before its destination is overwritten
+    for i in (0..len).rev() {
+        let dest = i + i / every;
+        // SAFETY: the caller guarantees the first `len` bytes are initialized, and `dest >= i` so
+        // this byte has not been overwritten yet
+        let c = output[i].assume_init();
+        output[dest].write(c);
+        if i % every == 0 && i > 0 {
+            output[dest - 1].write(separator);
+        }
+    }
+
+    Ok(new_len)
+}
+
+/// The big-endian bytes of an integer, with leading zero bytes removed, used as the input when
+/// encoding via [`bsx::encode_int`](crate::encode_int()).
+#[derive(Copy, Clone, Debug)]
+pub struct IntInput {
+    bytes: [u8; 16],
+    start: usize,
+}
+
+impl IntInput {
+    pub(crate) fn new(value: u128) -> Self {
+        let bytes = value.to_be_bytes();
+        // A zero value keeps a single zero byte so that it encodes as a single zero character
+        let start = ((value.leading_zeros() / 8) as usize).min(bytes.len() - 1);
+        IntInput { bytes, start }
+    }
+}
+
+impl AsRef<[u8]> for IntInput {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[self.start..]
+    }
+}
+
+/// A fixed capacity stack allocated encoded string, returned from
+/// [`EncodeBuilder::into_array`].
+///
+/// This dereferences to the encoded [`str`].
+#[derive(Copy, Clone)]
+pub struct EncodedArray<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> EncodedArray<N> {
+    /// Wrap the first `len` encoded bytes, checking that they are valid UTF-8.
+    pub(crate) fn from_utf8(bytes: [u8; N], len: usize) -> Result<Self> {
+        core::str::from_utf8(&bytes[..len]).map_err(|_| Error::InvalidUtf8)?;
+        Ok(EncodedArray { bytes, len })
+    }
+
+    /// The encoded string.
+    pub const fn as_str(&self) -> &str {
+        // The bytes are checked to be valid UTF-8 when the array is created
+        match core::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl<const N: usize> core::ops::Deref for EncodedArray<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for EncodedArray<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for EncodedArray<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl<const N: usize> fmt::Debug for EncodedArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("EncodedArray").field(&self.as_str()).finish()
+    }
+}
+
+impl<const N: usize> fmt::Display for EncodedArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// A specialized [`Result`](core::result::Result) type for [`bsx::encode`](module@crate::encode)
@@ -19,10 +331,30 @@ pub type Result<T> = core::result::Result<T, Error>;
 
 /// Errors that could occur when encoding to a string.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[non_exhaustive]
 pub enum Error {
     /// The output buffer was too small to contain the entire input.
-    BufferTooSmall,
+    BufferTooSmall {
+        /// A buffer length that is large enough for the encoded output, such as the
+        /// [`max_encoded_len`](EncodeBuilder::max_encoded_len), so may be more than the exact
+        /// encoded length.
+        required: usize,
+    },
+
+    /// The output was a string, but the encoded bytes were not valid UTF-8, only possible with an
+    /// extended alphabet containing non-ASCII bytes.
+    InvalidUtf8,
+
+    /// The input had more leading zero bytes than [`LeadingZeros::CountPrefix`] can represent
+    /// with a single character of the alphabet.
+    TooManyLeadingZeros,
+
+    /// The [`fmt::Write`] sink passed to [`EncodeBuilder::into_fmt`] returned an error.
+    Fmt,
+
+    /// The length of the encoded output would not fit in a `usize`.
+    Overflow,
 }
 
 /// Represents a buffer that can be encoded into. See [`EncodeBuilder::into`] and the provided
@@ -31,18 +363,26 @@ pub trait EncodeTarget {
     /// Encodes into this buffer, provides the maximum length for implementations that wish to
     /// preallocate space, along with a function that will encode ASCII bytes into the buffer and
     /// return the length written to it.
-    fn encode_with(
+    ///
+    /// The buffer passed to `f` may be uninitialized, so growable implementations can avoid
+    /// zero-filling space that is immediately overwritten.
+    ///
+    /// # Safety
+    ///
+    /// `f` must only write initialized bytes into the buffer, and must have initialized at least
+    /// the prefix of the buffer up to the length it returns.
+    unsafe fn encode_with(
         &mut self,
         max_len: usize,
-        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
     ) -> Result<usize>;
 }
 
 impl<T: EncodeTarget + ?Sized> EncodeTarget for &mut T {
-    fn encode_with(
+    unsafe fn encode_with(
         &mut self,
         max_len: usize,
-        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
     ) -> Result<usize> {
         T::encode_with(self, max_len, f)
     }
@@ -51,14 +391,16 @@ impl<T: EncodeTarget + ?Sized> EncodeTarget for &mut T {
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
 impl EncodeTarget for Vec<u8> {
-    fn encode_with(
+    unsafe fn encode_with(
         &mut self,
         max_len: usize,
-        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
     ) -> Result<usize> {
-        self.resize(max_len, 0);
-        let len = f(&mut *self)?;
-        self.truncate(len);
+        self.clear();
+        self.reserve(max_len);
+        let len = f(&mut self.spare_capacity_mut()[..max_len])?;
+        // SAFETY: the caller guarantees `f` has initialized the first `len` bytes
+        self.set_len(len);
         Ok(len)
     }
 }
@@ -66,34 +408,66 @@ impl EncodeTarget for Vec<u8> {
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
 impl EncodeTarget for String {
-    fn encode_with(
+    unsafe fn encode_with(
         &mut self,
         max_len: usize,
-        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
     ) -> Result<usize> {
-        let mut output = core::mem::replace(self, String::new()).into_bytes();
+        let mut output = core::mem::take(self).into_bytes();
         let len = output.encode_with(max_len, f)?;
-        *self = String::from_utf8(output).unwrap();
+        *self = String::from_utf8(output).map_err(|_| Error::InvalidUtf8)?;
+        Ok(len)
+    }
+}
+
+/// Encodes after the existing contents of a vector, used by [`EncodeBuilder::onto_vec`].
+#[cfg(feature = "alloc")]
+struct Tail<'a>(&'a mut Vec<u8>);
+
+#[cfg(feature = "alloc")]
+impl EncodeTarget for Tail<'_> {
+    unsafe fn encode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
+    ) -> Result<usize> {
+        let start = self.0.len();
+        self.0.reserve(max_len);
+        let len = f(&mut self.0.spare_capacity_mut()[..max_len])?;
+        // SAFETY: the caller guarantees `f` has initialized the first `len` bytes after `start`
+        self.0.set_len(start + len);
         Ok(len)
     }
 }
 
 impl EncodeTarget for [u8] {
-    fn encode_with(
+    unsafe fn encode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
+    ) -> Result<usize> {
+        let _ = max_len;
+        // SAFETY: the caller guarantees `f` only writes initialized bytes
+        f(as_uninit_mut(self))
+    }
+}
+
+impl EncodeTarget for [MaybeUninit<u8>] {
+    unsafe fn encode_with(
         &mut self,
         max_len: usize,
-        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
     ) -> Result<usize> {
         let _ = max_len;
-        f(&mut *self)
+        f(self)
     }
 }
 
 impl EncodeTarget for str {
-    fn encode_with(
+    unsafe fn encode_with(
         &mut self,
         max_len: usize,
-        f: impl for<'a> FnOnce(&'a mut [u8]) -> Result<usize>,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
     ) -> Result<usize> {
         struct Guard<'a>(&'a mut [u8]);
 
@@ -124,16 +498,211 @@ impl EncodeTarget for str {
 
         let _ = max_len;
 
-        let guard = Guard(unsafe { self.as_bytes_mut() });
-        f(&mut *guard.0)
+        let guard = Guard(self.as_bytes_mut());
+        // SAFETY: the caller guarantees `f` only writes initialized bytes
+        let len = f(as_uninit_mut(guard.0))?;
+        core::str::from_utf8(&guard.0[..len]).map_err(|_| Error::InvalidUtf8)?;
+        Ok(len)
     }
 }
 
+/// Encodes into the array's inline capacity, replacing any existing contents.
+///
+/// ```rust
+/// let mut output = arrayvec::ArrayVec::<u8, 10>::new();
+/// bsx::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]).with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output)?;
+/// assert_eq!(b"he11owor1d", &output[..]);
+///
+/// let mut output = arrayvec::ArrayVec::<u8, 4>::new();
+/// assert_eq!(
+///     bsx::encode::Error::BufferTooSmall { required: 11 },
+///     bsx::encode([0xFF; 8]).with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output).unwrap_err());
+/// # Ok::<(), bsx::encode::Error>(())
+/// ```
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+impl<const CAP: usize> EncodeTarget for arrayvec::ArrayVec<u8, CAP> {
+    unsafe fn encode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
+    ) -> Result<usize> {
+        let _ = max_len;
+        self.clear();
+        let output = core::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), CAP);
+        let len = f(output)?;
+        // SAFETY: the caller guarantees `f` has initialized the first `len` bytes
+        self.set_len(len);
+        Ok(len)
+    }
+}
+
+/// Encodes into the string's inline capacity, replacing any existing contents.
+///
+/// ```rust
+/// let mut output = arrayvec::ArrayString::<10>::new();
+/// bsx::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]).with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output)?;
+/// assert_eq!("he11owor1d", output.as_str());
+/// # Ok::<(), bsx::encode::Error>(())
+/// ```
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrayvec")))]
+impl<const CAP: usize> EncodeTarget for arrayvec::ArrayString<CAP> {
+    unsafe fn encode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
+    ) -> Result<usize> {
+        let _ = max_len;
+        self.clear();
+        let output = core::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), CAP);
+        let len = f(output)?;
+        // SAFETY: the caller guarantees `f` has initialized the first `len` bytes
+        let bytes = core::slice::from_raw_parts(self.as_ptr(), len);
+        core::str::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)?;
+        // SAFETY: the first `len` bytes are initialized and were just checked to be valid UTF-8
+        self.set_len(len);
+        Ok(len)
+    }
+}
+
+/// Encodes into the vector's inline capacity, replacing any existing contents.
+///
+/// ```rust
+/// let mut output = heapless::Vec::<u8, 10>::new();
+/// bsx::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]).with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output)?;
+/// assert_eq!(b"he11owor1d", &output[..]);
+///
+/// let mut output = heapless::Vec::<u8, 4>::new();
+/// assert_eq!(
+///     bsx::encode::Error::BufferTooSmall { required: 11 },
+///     bsx::encode([0xFF; 8]).with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output).unwrap_err());
+/// # Ok::<(), bsx::encode::Error>(())
+/// ```
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+impl<const N: usize> EncodeTarget for heapless::Vec<u8, N> {
+    unsafe fn encode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
+    ) -> Result<usize> {
+        let _ = max_len;
+        self.clear();
+        let output = core::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), N);
+        let len = f(output)?;
+        // SAFETY: the caller guarantees `f` has initialized the first `len` bytes
+        self.set_len(len);
+        Ok(len)
+    }
+}
+
+/// Encodes into the string's inline capacity, replacing any existing contents.
+///
+/// ```rust
+/// let mut output = heapless::String::<10>::new();
+/// bsx::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]).with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output)?;
+/// assert_eq!("he11owor1d", output.as_str());
+/// # Ok::<(), bsx::encode::Error>(())
+/// ```
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+impl<const N: usize> EncodeTarget for heapless::String<N> {
+    unsafe fn encode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
+    ) -> Result<usize> {
+        // SAFETY: the contents are checked to be valid UTF-8 (or cleared) before returning
+        let output = self.as_mut_vec();
+        let len = output.encode_with(max_len, f)?;
+        if core::str::from_utf8(output).is_err() {
+            output.clear();
+            return Err(Error::InvalidUtf8);
+        }
+        Ok(len)
+    }
+}
+
+/// Encodes into the buffer, replacing any existing contents.
+///
+/// ```rust
+/// let mut output = bytes::BytesMut::new();
+/// bsx::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]).with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output)?;
+/// assert_eq!(b"he11owor1d", &output[..]);
+/// # Ok::<(), bsx::encode::Error>(())
+/// ```
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+impl EncodeTarget for bytes::BytesMut {
+    unsafe fn encode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
+    ) -> Result<usize> {
+        self.clear();
+        self.reserve(max_len);
+        let output = core::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), max_len);
+        let len = f(output)?;
+        // SAFETY: the caller guarantees `f` has initialized the first `len` bytes
+        self.set_len(len);
+        Ok(len)
+    }
+}
+
+/// Encodes into the vector, replacing any existing contents. Outputs that fit in the inline
+/// capacity stay on the stack, longer ones spill to the heap.
+///
+/// ```rust
+/// let mut output = smallvec::SmallVec::<[u8; 16]>::new();
+/// bsx::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]).with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output)?;
+/// assert_eq!(b"he11owor1d", &output[..]);
+/// assert!(!output.spilled());
+/// # Ok::<(), bsx::encode::Error>(())
+/// ```
+#[cfg(feature = "smallvec")]
+#[cfg_attr(docsrs, doc(cfg(feature = "smallvec")))]
+impl<A: smallvec::Array<Item = u8>> EncodeTarget for smallvec::SmallVec<A> {
+    unsafe fn encode_with(
+        &mut self,
+        max_len: usize,
+        f: impl for<'a> FnOnce(&'a mut [MaybeUninit<u8>]) -> Result<usize>,
+    ) -> Result<usize> {
+        self.clear();
+        self.reserve(max_len);
+        let output = core::slice::from_raw_parts_mut(self.as_mut_ptr().cast(), max_len);
+        let len = f(output)?;
+        // SAFETY: the caller guarantees `f` has initialized the first `len` bytes
+        self.set_len(len);
+        Ok(len)
+    }
+}
+
+/// View an initialized buffer as a possibly uninitialized buffer.
+///
+/// # Safety
+///
+/// The caller must not write uninitialized bytes into the returned buffer.
+unsafe fn as_uninit_mut(slice: &mut [u8]) -> &mut [MaybeUninit<u8>] {
+    core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len())
+}
+
+/// View a possibly uninitialized buffer as an initialized buffer.
+///
+/// # Safety
+///
+/// The caller must ensure every byte in the buffer has been initialized.
+pub(crate) unsafe fn assume_init_mut(slice: &mut [MaybeUninit<u8>]) -> &mut [u8] {
+    core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len())
+}
+
 impl<I: AsRef<[u8]>> EncodeBuilder<I, Unspecified> {
     pub(crate) fn new(input: I) -> Self {
         EncodeBuilder {
             input,
             alpha: Unspecified,
+            prefix: None,
+            format: Format::default(),
         }
     }
 }
@@ -155,13 +724,384 @@ impl<I: AsRef<[u8]>, A> EncodeBuilder<I, A> {
         EncodeBuilder {
             input: self.input,
             alpha,
+            // A multibase prefix only describes the alphabet it came with
+            prefix: None,
+            format: self.format,
+        }
+    }
+
+    /// Encode using the given multibase encoding, and prefix the output with its identifying
+    /// character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// assert_eq!(
+    ///     "zhe11owor1d",
+    ///     bsx::encode(input)
+    ///         .with_multibase_prefix(bsx::multibase::Base::Base58Btc)
+    ///         .into_string());
+    /// ```
+    pub fn with_multibase_prefix(self, base: multibase::Base) -> EncodeBuilder<I, multibase::Base> {
+        EncodeBuilder {
+            input: self.input,
+            alpha: base,
+            prefix: Some(base.code() as u8),
+            format: self.format,
         }
     }
+
+    /// Choose how leading zero bytes of the input are represented in the output, by default each
+    /// one becomes a leading zero character as in Base58Check.
+    ///
+    /// With [`LeadingZeros::CountPrefix`] the count character comes directly after any prefix and
+    /// is not affected by the other formatting options. Use
+    /// [`DecodeBuilder::with_leading_zeros`](crate::decode::DecodeBuilder::with_leading_zeros)
+    /// with the same option to decode it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bsx::encode::LeadingZeros;
+    ///
+    /// let input = [0x00, 0x00, 0x07, 0x5b, 0xcd, 0x15];
+    /// let encode = |zeros| {
+    ///     bsx::encode(input)
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .with_leading_zeros(zeros)
+    ///         .into_string()
+    /// };
+    /// assert_eq!("11BukQL", encode(LeadingZeros::Preserve));
+    /// assert_eq!("BukQL", encode(LeadingZeros::Ignore));
+    /// assert_eq!("3BukQL", encode(LeadingZeros::CountPrefix));
+    /// ```
+    pub fn with_leading_zeros(mut self, zeros: LeadingZeros) -> Self {
+        self.format.zeros = zeros;
+        self
+    }
+
+    /// Append Crockford's check symbol, the value of the input modulo 37, which catches single
+    /// character errors and adjacent transpositions in human-entered strings.
+    ///
+    /// The symbol is one of the [Crockford](crate::StaticAlphabet::CROCKFORD) digits for values
+    /// below 32, or one of `*~$=U`, and comes after any separators. Use
+    /// [`DecodeBuilder::with_check_symbol`](crate::decode::DecodeBuilder::with_check_symbol) to
+    /// verify it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     "3NQK8NU",
+    ///     bsx::encode_int(123456789u32)
+    ///         .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+    ///         .with_check_symbol()
+    ///         .into_string());
+    /// assert_eq!(
+    ///     "10*",
+    ///     bsx::encode_int(32u32)
+    ///         .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+    ///         .with_check_symbol()
+    ///         .into_string());
+    /// ```
+    pub fn with_check_symbol(mut self) -> Self {
+        self.format.check_symbol = true;
+        self
+    }
+
+    /// Output the least significant digit first, as used by some protocols.
+    ///
+    /// Leading zero bytes of the input become trailing zero characters, and any
+    /// [minimum width](Self::with_min_width) padding is added on the right. Use
+    /// [`DecodeBuilder::little_endian`](crate::decode::DecodeBuilder::little_endian) to decode it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// assert_eq!(
+    ///     "d1rowo11eh",
+    ///     bsx::encode(input)
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .little_endian()
+    ///         .into_string());
+    /// assert_eq!(
+    ///     "211",
+    ///     bsx::encode([0x00, 0x00, 0x01])
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .little_endian()
+    ///         .into_string());
+    /// ```
+    pub fn little_endian(mut self) -> Self {
+        self.format.little_endian = true;
+        self
+    }
+
+    /// Encode using bijective numeration, where the alphabet characters represent the digits `1`
+    /// to `k` rather than `0` to `k - 1`, so there is no zero digit and every string is the
+    /// encoding of a unique value, e.g. `a`, `b`, ..., `z`, `aa`, `ab`, ... like spreadsheet
+    /// columns.
+    ///
+    /// The input is treated purely as an integer, leading zero bytes do not affect the output and
+    /// zero encodes as the empty string. Use
+    /// [`DecodeBuilder::bijective`](crate::decode::DecodeBuilder::bijective) to decode it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let letters = bsx::StaticAlphabet::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ")?;
+    /// let column = |n: u32| bsx::encode_int(n).with_alphabet(&letters).bijective().into_string();
+    /// assert_eq!("", column(0));
+    /// assert_eq!("A", column(1));
+    /// assert_eq!("Z", column(26));
+    /// assert_eq!("AA", column(27));
+    /// assert_eq!("ZZ", column(702));
+    /// assert_eq!("AAA", column(703));
+    /// # Ok::<(), bsx::alphabet::Error>(())
+    /// ```
+    pub fn bijective(mut self) -> Self {
+        self.format.bijective = true;
+        self
+    }
+
+    /// Left pad the output with the zero character of the alphabet up to `width` encoded
+    /// characters, for fixed width output such as sortable identifiers.
+    ///
+    /// The width does not include any prefix or separators. The padding changes the number of
+    /// leading zero bytes the output decodes to, use
+    /// [`DecodeBuilder::padded`](crate::decode::DecodeBuilder::padded) to decode it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let encoded = bsx::encode_int(123456789u32)
+    ///     .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///     .with_min_width(8)
+    ///     .into_string();
+    /// assert_eq!("111BukQL", encoded);
+    ///
+    /// let input = [0x00, 0x07, 0x5b, 0xcd, 0x15];
+    /// let encoded = bsx::encode(input)
+    ///     .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///     .with_min_width(8)
+    ///     .into_string();
+    /// assert_eq!("111BukQL", encoded);
+    /// assert_eq!(
+    ///     input,
+    ///     bsx::decode(encoded)
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .padded()
+    ///         .into_array()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn with_min_width(mut self, width: usize) -> Self {
+        self.format.min_width = width;
+        self
+    }
+
+    /// Break the output into lines of `columns` characters, including any prefix and separators,
+    /// by inserting `\n` characters, like `base64 --wrap`.
+    ///
+    /// The last line is not terminated, [`DecodeBuilder::wrapped`](crate::decode::DecodeBuilder::wrapped)
+    /// can be used to decode the wrapped output.
+    ///
+    /// # Panics
+    ///
+    /// If `columns` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let encoded = bsx::encode(input)
+    ///     .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///     .with_line_wrap(4)
+    ///     .into_string();
+    /// assert_eq!("he11\nowor\n1d", encoded);
+    /// assert_eq!(
+    ///     input,
+    ///     bsx::decode(encoded)
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .wrapped()
+    ///         .into_array()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn with_line_wrap(mut self, columns: usize) -> Self {
+        assert!(columns > 0, "lines must be wrapped at one or more columns");
+        self.format.wrap = Some(columns);
+        self
+    }
+
+    /// Output letters in uppercase, for alphabets that decode letters in either case such as from
+    /// [`StaticAlphabet::new_case_insensitive`].
+    ///
+    /// Letters whose uppercase is a different character of the alphabet are left alone, so the
+    /// output can always be decoded with the alphabet, using
+    /// [`DecodeBuilder::case_insensitive`](crate::decode::DecodeBuilder::case_insensitive) if the
+    /// alphabet itself is not case-insensitive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let base36 = bsx::DynamicAlphabet::new_case_insensitive("0123456789abcdefghijklmnopqrstuvwxyz")?;
+    /// let encoded = bsx::encode([0x01, 0xbe, 0x15, 0xdc])
+    ///     .with_alphabet(&base36)
+    ///     .uppercase()
+    ///     .into_string();
+    /// assert_eq!("HELLO", encoded);
+    /// assert_eq!(
+    ///     vec![0x01, 0xbe, 0x15, 0xdc],
+    ///     bsx::decode(encoded).with_alphabet(&base36).into_vec()?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn uppercase(mut self) -> Self {
+        self.format.uppercase = Some(true);
+        self
+    }
+
+    /// Output letters in lowercase, for alphabets that decode letters in either case such as from
+    /// [`StaticAlphabet::new_case_insensitive`].
+    ///
+    /// See [`Self::uppercase`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let base32 = bsx::StaticAlphabet::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567")?;
+    /// let encoded = bsx::encode([0x00, 0x02, 0xd5, 0xcd, 0x86])
+    ///     .with_alphabet(&base32)
+    ///     .lowercase()
+    ///     .into_string();
+    /// assert_eq!("abnltmg", encoded);
+    /// assert_eq!(
+    ///     vec![0x00, 0x02, 0xd5, 0xcd, 0x86],
+    ///     bsx::decode(encoded).with_alphabet(&base32).case_insensitive().into_vec()?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn lowercase(mut self) -> Self {
+        self.format.uppercase = Some(false);
+        self
+    }
+
+    /// Insert `separator` between every group of `every` encoded characters, such as to format a
+    /// code as `ABCD-EFGH-IJKL`.
+    ///
+    /// The groups are counted from the start of the encoded characters, not including any prefix,
+    /// and [`DecodeBuilder::ignore_chars`](crate::decode::DecodeBuilder::ignore_chars) can be used
+    /// to decode the grouped output.
+    ///
+    /// # Panics
+    ///
+    /// If `separator` is not ASCII, or `every` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let encoded = bsx::encode(input)
+    ///     .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///     .with_separator('-', 4)
+    ///     .into_string();
+    /// assert_eq!("he11-owor-1d", encoded);
+    /// assert_eq!(
+    ///     input,
+    ///     bsx::decode(encoded)
+    ///         .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .ignore_chars("-")
+    ///         .into_array()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn with_separator(mut self, separator: char, every: usize) -> Self {
+        assert!(separator.is_ascii(), "separator must be ASCII");
+        assert!(
+            every > 0,
+            "separator must be inserted every one or more characters"
+        );
+        self.format.separator = Some((separator as u8, every));
+        self
+    }
+
+    /// Append `padding` characters until the number of encoded characters is a multiple of
+    /// `multiple`, for protocols that expect padded output such as base64 with `=`.
+    ///
+    /// The padding comes after any separators and does not include any prefix, use
+    /// [`DecodeBuilder::with_padding`](crate::decode::DecodeBuilder::with_padding) to decode it.
+    ///
+    /// # Panics
+    ///
+    /// If `padding` is not ASCII, or `multiple` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let encoded = bsx::encode(b"hello")
+    ///     .with_alphabet(bsx::StaticAlphabet::BASE64)
+    ///     .with_padding('=', 4)
+    ///     .into_string();
+    /// assert_eq!("aGVsbG8=", encoded);
+    /// assert_eq!(
+    ///     b"hello",
+    ///     &bsx::decode(encoded)
+    ///         .with_alphabet(bsx::StaticAlphabet::BASE64)
+    ///         .with_padding('=')
+    ///         .into_array()?);
+    /// # Ok::<(), bsx::decode::Error>(())
+    /// ```
+    pub fn with_padding(mut self, padding: char, multiple: usize) -> Self {
+        assert!(padding.is_ascii(), "padding must be ASCII");
+        assert!(multiple > 0, "padding must be to a multiple of one or more");
+        self.format.padding = Some((padding as u8, multiple));
+        self
+    }
 }
 
 impl<I: AsRef<[u8]>, A: Alphabet> EncodeBuilder<I, A> {
+    /// An upper bound on the length of the output of this encode, taking into account the leading
+    /// zeros in the input.
+    ///
+    /// Saturates at `usize::MAX` if the output would be too long to represent, encoding will then
+    /// fail with [`Error::Overflow`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x00, 0x00, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let builder = bsx::encode(input).with_alphabet(bsx::StaticAlphabet::BITCOIN);
+    /// assert_eq!(11, builder.max_encoded_len());
+    /// assert_eq!("11otoeBRaX", builder.into_string());
+    /// ```
+    pub fn max_encoded_len(&self) -> usize {
+        self.checked_max_encoded_len().unwrap_or(usize::MAX)
+    }
+
+    fn checked_max_encoded_len(&self) -> Option<usize> {
+        let input = self.input.as_ref();
+        let zeros = if self.alpha.bit_packed() {
+            0
+        } else {
+            input.iter().take_while(|&&v| v == 0).count()
+        };
+        let prefix = if self.prefix.is_some() { 1 } else { 0 };
+        let count = usize::from(self.format.zeros == LeadingZeros::CountPrefix);
+        let check = usize::from(self.format.check_symbol);
+        let digits =
+            zeros.checked_add(crate::encoded_len(input.len() - zeros, self.alpha.len()))?;
+        let len = (prefix + count + check).checked_add(self.format.max_len(digits)?)?;
+        match self.format.wrap {
+            Some(columns) => len.checked_add(len.saturating_sub(1) / columns),
+            None => Some(len),
+        }
+    }
+
     /// Encode into a new owned string.
     ///
+    /// # Panics
+    ///
+    /// If the encoded output is not valid UTF-8, which is only possible with an
+    /// [extended alphabet](crate::StaticAlphabet::new_extended), the input has too many
+    /// leading zero bytes for [`LeadingZeros::CountPrefix`], or the output length overflows.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -178,6 +1118,11 @@ impl<I: AsRef<[u8]>, A: Alphabet> EncodeBuilder<I, A> {
 
     /// Encode into a new owned vector.
     ///
+    /// # Panics
+    ///
+    /// If the input has too many leading zero bytes for [`LeadingZeros::CountPrefix`], or the
+    /// output length overflows.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -192,6 +1137,164 @@ impl<I: AsRef<[u8]>, A: Alphabet> EncodeBuilder<I, A> {
         output
     }
 
+    /// Encode onto the end of an existing string, keeping its current contents.
+    ///
+    /// Returns the length appended to the string, on error the string is left unchanged.
+    ///
+    /// See the documentation for [`bsx::encode`](crate::encode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let mut output = "key_".to_owned();
+    /// assert_eq!(10, bsx::encode(input).with_alphabet(bsx::StaticAlphabet::BITCOIN).onto_string(&mut output)?);
+    /// assert_eq!("key_he11owor1d", output);
+    /// # Ok::<(), bsx::encode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn onto_string(self, output: &mut String) -> Result<usize> {
+        let mut bytes = core::mem::take(output).into_bytes();
+        let start = bytes.len();
+        let result = self.onto_vec(&mut bytes).and_then(|len| {
+            core::str::from_utf8(&bytes[start..]).map_err(|_| Error::InvalidUtf8)?;
+            Ok(len)
+        });
+        if result.is_err() {
+            bytes.truncate(start);
+        }
+        // SAFETY: the original contents were a valid string, and the appended bytes (if any) were
+        // checked to be valid UTF-8 starting from a character boundary
+        *output = unsafe { String::from_utf8_unchecked(bytes) };
+        result
+    }
+
+    /// Encode onto the end of an existing vector, keeping its current contents.
+    ///
+    /// Returns the length appended to the vector, on error the vector is left unchanged.
+    ///
+    /// See the documentation for [`bsx::encode`](crate::encode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let mut output = b"key_".to_vec();
+    /// assert_eq!(10, bsx::encode(input).with_alphabet(bsx::StaticAlphabet::BITCOIN).onto_vec(&mut output)?);
+    /// assert_eq!(b"key_he11owor1d", &*output);
+    /// # Ok::<(), bsx::encode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn onto_vec(self, output: &mut Vec<u8>) -> Result<usize> {
+        self.encode_to(Tail(output))
+    }
+
+    /// Encode into a new fixed capacity stack allocated string.
+    ///
+    /// See the documentation for [`bsx::encode`](crate::encode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let output = bsx::encode(input).with_alphabet(bsx::StaticAlphabet::BITCOIN).into_array::<16>()?;
+    /// assert_eq!("he11owor1d", &*output);
+    /// assert_eq!(
+    ///     bsx::encode::Error::BufferTooSmall { required: 11 },
+    ///     bsx::encode(input).with_alphabet(bsx::StaticAlphabet::BITCOIN).into_array::<8>().unwrap_err());
+    /// # Ok::<(), bsx::encode::Error>(())
+    /// ```
+    pub fn into_array<const N: usize>(self) -> Result<EncodedArray<N>> {
+        let mut bytes = [0; N];
+        let len = self.into(&mut bytes[..])?;
+        EncodedArray::from_utf8(bytes, len)
+    }
+
+    /// Encode into a [`fmt::Write`] sink, such as a [`fmt::Formatter`] or a `String`.
+    ///
+    /// Returns the length written into the sink.
+    ///
+    /// The output is encoded into a stack buffer before being written. Without the `alloc`
+    /// feature outputs longer than this buffer (256 characters) give a
+    /// [`BufferTooSmall`](Error::BufferTooSmall) error, with it they are encoded into a temporary
+    /// heap allocation instead.
+    ///
+    /// See the documentation for [`bsx::encode`](crate::encode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::fmt::Write;
+    ///
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let mut output = String::from("key: ");
+    /// bsx::encode(input).with_alphabet(bsx::StaticAlphabet::BITCOIN).into_fmt(&mut output)?;
+    /// write!(output, "!").unwrap();
+    /// assert_eq!("key: he11owor1d!", output);
+    /// # Ok::<(), bsx::encode::Error>(())
+    /// ```
+    pub fn into_fmt(self, output: &mut impl fmt::Write) -> Result<usize> {
+        self.encode_buffered(|encoded| {
+            let encoded = core::str::from_utf8(encoded).map_err(|_| Error::InvalidUtf8)?;
+            output.write_str(encoded).map_err(|_| Error::Fmt)?;
+            Ok(encoded.len())
+        })?
+    }
+
+    /// Encode into an [`io::Write`](std::io::Write) sink, such as a file or socket.
+    ///
+    /// Returns the length written into the sink.
+    ///
+    /// Short outputs are encoded into a stack buffer and longer ones into a temporary heap
+    /// allocation before being written with a single [`write_all`](std::io::Write::write_all).
+    /// Encoding errors are returned as [`InvalidInput`](std::io::ErrorKind::InvalidInput) errors
+    /// wrapping the [`Error`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let mut output = b"key: ".to_vec();
+    /// bsx::encode(input).with_alphabet(bsx::StaticAlphabet::BITCOIN).into_writer(&mut output)?;
+    /// assert_eq!(b"key: he11owor1d", &*output);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn into_writer(self, mut output: impl std::io::Write) -> std::io::Result<usize> {
+        self.encode_buffered(|encoded| output.write_all(encoded).map(|()| encoded.len()))
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?
+    }
+
+    /// Encode into a possibly uninitialized buffer, returning the initialized prefix containing
+    /// the encoded output.
+    ///
+    /// See the documentation for [`bsx::encode`](crate::encode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    ///
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let mut output = [MaybeUninit::uninit(); 16];
+    /// let encoded = bsx::encode(input).with_alphabet(bsx::StaticAlphabet::BITCOIN).into_uninit(&mut output)?;
+    /// assert_eq!(b"he11owor1d", encoded);
+    /// # Ok::<(), bsx::encode::Error>(())
+    /// ```
+    pub fn into_uninit(self, output: &mut [MaybeUninit<u8>]) -> Result<&mut [u8]> {
+        let len = self.into(&mut *output)?;
+        // SAFETY: encoding initializes the prefix of the buffer up to the length it returns
+        Ok(unsafe { assume_init_mut(&mut output[..len]) })
+    }
+
     /// Encode into the given buffer.
     ///
     /// Returns the length written into the buffer.
@@ -257,56 +1360,453 @@ impl<I: AsRef<[u8]>, A: Alphabet> EncodeBuilder<I, A> {
     /// assert_eq!("he11owor1d\0ld", output);
     /// # Ok::<(), bsx::encode::Error>(())
     /// ```
-    pub fn into(self, mut output: impl EncodeTarget) -> Result<usize> {
-        let encoded_len_divisor = {
-            let len = self.alpha.len();
-            if len.is_power_of_two() {
-                len.trailing_zeros() as usize
-            } else {
-                (0usize.leading_zeros() - len.leading_zeros() - 1) as usize
-            }
-        };
-        let max_encoded_len = (self.input.as_ref().len() * 8) / encoded_len_divisor + 1;
-        output.encode_with(max_encoded_len, |output| {
-            encode_into(self.input.as_ref(), output, &self.alpha)
+    pub fn into(self, output: impl EncodeTarget) -> Result<usize> {
+        self.encode_to(output)
+    }
+
+    /// Returns a value that encodes the input each time it is formatted with
+    /// [`Display`](fmt::Display), without allocating.
+    ///
+    /// This uses the same stack buffer as [`into_fmt`](Self::into_fmt), formatting returns an
+    /// error if the encoding fails or (without the `alloc` feature) is longer than the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    /// let display = bsx::encode(input).with_alphabet(bsx::StaticAlphabet::BITCOIN).into_display();
+    /// assert_eq!("key: he11owor1d", format!("key: {}", display));
+    /// ```
+    pub fn into_display(self) -> EncodeDisplay<I, A> {
+        EncodeDisplay { builder: self }
+    }
+
+    fn encode_to(&self, mut output: impl EncodeTarget) -> Result<usize> {
+        let max_encoded_len = self.checked_max_encoded_len().ok_or(Error::Overflow)?;
+        // SAFETY: `encode_into` and `Format::apply` only write initialized bytes, and return the
+        // length they have initialized
+        unsafe {
+            output.encode_with(max_encoded_len, |output| {
+                let prefix = match self.prefix {
+                    Some(prefix) => {
+                        output
+                            .first_mut()
+                            .ok_or(Error::BufferTooSmall { required: 1 })?
+                            .write(prefix);
+                        1
+                    }
+                    None => 0,
+                };
+                let encoded = &mut output[prefix..];
+                let input = self.input.as_ref();
+                let zeros = input.iter().take_while(|&&byte| byte == 0).count();
+                let (count, input) = match self.format.zeros {
+                    LeadingZeros::CountPrefix => {
+                        let count = *self
+                            .alpha
+                            .encode()
+                            .get(zeros)
+                            .ok_or(Error::TooManyLeadingZeros)?;
+                        encoded
+                            .first_mut()
+                            .ok_or(Error::BufferTooSmall {
+                                required: prefix + 1,
+                            })?
+                            .write(count);
+                        (1, &input[zeros..])
+                    }
+                    // Bijective input is treated purely as an integer, without leading zeros
+                    _ if self.format.bijective => (0, &input[zeros..]),
+                    // Keep a single zero byte so that zero encodes as the zero character
+                    LeadingZeros::Ignore => (0, &input[zeros.min(input.len().saturating_sub(1))..]),
+                    LeadingZeros::Preserve => (0, input),
+                };
+                let encoded = &mut encoded[count..];
+                let len = self.alpha.encode_into(input, encoded)?;
+                let mut len = prefix + count + self.format.apply(encoded, len, &self.alpha)?;
+                if self.format.check_symbol {
+                    let check = CHECK_SYMBOLS[check_value(self.input.as_ref())];
+                    output
+                        .get_mut(len)
+                        .ok_or(Error::BufferTooSmall { required: len + 1 })?
+                        .write(check);
+                    len += 1;
+                }
+                match self.format.wrap {
+                    Some(columns) => insert_separators(output, len, b'\n', columns),
+                    None => Ok(len),
+                }
+            })
+        }
+        .map_err(|err| match err {
+            Error::BufferTooSmall { .. } => Error::BufferTooSmall {
+                required: max_encoded_len,
+            },
+            other => other,
         })
     }
 }
 
-fn encode_into(input: &[u8], output: &mut [u8], alpha: impl Alphabet) -> Result<usize> {
-    let (len, encode) = (alpha.len(), alpha.encode());
+/// The size of the stack buffer used when encoding into a sink, enough for around 180 bytes of
+/// input in base58.
+const STACK_BUFFER_LEN: usize = 256;
 
-    let mut index = 0;
-    for &val in input {
-        let mut carry = val as usize;
-        for byte in &mut output[..index] {
-            carry += (*byte as usize) << 8;
-            *byte = (carry % len) as u8;
-            carry /= len;
+impl<I: AsRef<[u8]>, A: Alphabet> EncodeBuilder<I, A> {
+    /// Encode into a stack buffer and pass the encoded bytes to `f`, falling back to a heap
+    /// allocation for outputs too long for the stack buffer when `alloc` is available.
+    fn encode_buffered<R>(&self, f: impl FnOnce(&[u8]) -> R) -> Result<R> {
+        #[cfg(feature = "alloc")]
+        if self.max_encoded_len() > STACK_BUFFER_LEN {
+            let mut output = Vec::new();
+            self.encode_to(&mut output)?;
+            return Ok(f(&output));
+        }
+        let mut buffer = [0; STACK_BUFFER_LEN];
+        let len = self.encode_to(&mut buffer[..])?;
+        Ok(f(&buffer[..len]))
+    }
+}
+
+/// Lazily encodes its input when formatted, returned from [`EncodeBuilder::into_display`].
+#[derive(Clone, Debug)]
+pub struct EncodeDisplay<I: AsRef<[u8]>, A> {
+    builder: EncodeBuilder<I, A>,
+}
+
+impl<I: AsRef<[u8]>, A: Alphabet> fmt::Display for EncodeDisplay<I, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.builder
+            .encode_buffered(|encoded| {
+                f.write_str(core::str::from_utf8(encoded).map_err(|_| fmt::Error)?)
+            })
+            .map_err(|_| fmt::Error)?
+    }
+}
+
+/// A reusable encoder for a single alphabet, that keeps its output buffer between calls to
+/// amortize allocation when encoding many values.
+///
+/// # Examples
+///
+/// ```rust
+/// let mut encoder = bsx::encode::Encoder::new(bsx::StaticAlphabet::BITCOIN);
+/// assert_eq!("he11owor1d", encoder.encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]));
+/// assert_eq!("2g", encoder.encode([0x61]));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+#[allow(missing_debug_implementations)]
+pub struct Encoder<A> {
+    alpha: A,
+    output: String,
+}
+
+#[cfg(feature = "alloc")]
+impl<A: Alphabet> Encoder<A> {
+    /// Create an encoder for the given alphabet.
+    pub fn new(alpha: A) -> Self {
+        Encoder {
+            alpha,
+            output: String::new(),
+        }
+    }
+
+    /// Encode the input, returning a reference to the encoder's internal buffer.
+    ///
+    /// # Panics
+    ///
+    /// If the encoded output is not valid UTF-8, which is only possible with an
+    /// [extended alphabet](crate::StaticAlphabet::new_extended), or the output length overflows.
+    pub fn encode(&mut self, input: impl AsRef<[u8]>) -> &str {
+        crate::encode(input)
+            .with_alphabet(&self.alpha)
+            .into(&mut self.output)
+            .unwrap();
+        &self.output
+    }
+}
+
+/// Encode many inputs with the same alphabet, reusing a single scratch buffer so that each output
+/// is allocated exactly once at its final size.
+///
+/// # Panics
+///
+/// If any encoded output is not valid UTF-8, which is only possible with an
+/// [extended alphabet](crate::StaticAlphabet::new_extended), or an output length overflows.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(
+///     vec!["he11owor1d", "2g"],
+///     bsx::encode::encode_many(
+///         &[&[0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58][..], &[0x61]],
+///         bsx::StaticAlphabet::BITCOIN));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub fn encode_many<I: AsRef<[u8]>>(inputs: &[I], alpha: impl Alphabet) -> Vec<String> {
+    let mut encoder = Encoder::new(alpha);
+    inputs
+        .iter()
+        .map(|input| String::from(encoder.encode(input)))
+        .collect()
+}
+
+/// Encode `input` in a `const` context, returning the characters along with the encoded length.
+pub(crate) const fn encode_const_raw<const N: usize, const LEN: usize>(
+    input: &[u8],
+    alpha: &StaticAlphabet<LEN>,
+) -> ([u8; N], usize) {
+    // Mutable references are not available in `const fn`, so this is a simple digit at a time
+    // conversion written out in full with `while` loops
+    let mut digits = [0u8; N];
+    let mut len = 0;
+
+    let mut i = 0;
+    while i < input.len() {
+        let mut carry = input[i] as usize;
+        let mut j = 0;
+        while j < len {
+            carry += (digits[j] as usize) << 8;
+            digits[j] = (carry % LEN) as u8;
+            carry /= LEN;
+            j += 1;
         }
         while carry > 0 {
-            if index == output.len() {
-                return Err(Error::BufferTooSmall);
+            if len == N {
+                panic!("encoded output is too long for the array");
+            }
+            digits[len] = (carry % LEN) as u8;
+            carry /= LEN;
+            len += 1;
+        }
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < input.len() && input[i] == 0 {
+        if len == N {
+            panic!("encoded output is too long for the array");
+        }
+        digits[len] = 0;
+        len += 1;
+        i += 1;
+    }
+
+    let mut bytes = [0; N];
+    let mut i = 0;
+    while i < len {
+        bytes[i] = alpha.encode[digits[len - 1 - i] as usize];
+        i += 1;
+    }
+
+    (bytes, len)
+}
+
+/// Encode `input` in a `const` context, see [`bsx::encode_const`](crate::encode_const()).
+pub(crate) const fn encode_const<const N: usize, const LEN: usize>(
+    input: &[u8],
+    alpha: &StaticAlphabet<LEN>,
+) -> EncodedArray<N> {
+    let (bytes, len) = encode_const_raw::<N, LEN>(input, alpha);
+    if core::str::from_utf8(bytes.split_at(len).0).is_err() {
+        panic!("encoded output is not valid UTF-8");
+    }
+    EncodedArray { bytes, len }
+}
+
+/// The largest power of `len` that fits in a `u32` limb, along with how many digits that is.
+pub(crate) const fn limb_base(len: usize) -> (u64, usize) {
+    let len = len as u64;
+    let (mut base, mut digits) = (len, 1);
+    while len > 1 && base * len <= 1 << 32 {
+        base *= len;
+        digits += 1;
+    }
+    (base, digits)
+}
+
+/// Write the little-endian digits of `value` into `output`, filling it entirely.
+fn write_digits(output: &mut [MaybeUninit<u8>], mut value: u64, len: impl Len) {
+    let len = len.get() as u64;
+    for digit in output {
+        digit.write((value % len) as u8);
+        value /= len;
+    }
+}
+
+/// Write the little-endian digits of an input of at most 16 bytes, using native `u128` arithmetic
+/// instead of limbs.
+fn encode_u128(input: &[u8], output: &mut [MaybeUninit<u8>], len: impl Len) -> Result<usize> {
+    let (limb_base, limb_digits) = len.limb_base();
+    let mut value = input
+        .iter()
+        .fold(0u128, |acc, &val| (acc << 8) | u128::from(val));
+    let mut index = 0;
+
+    // Peel off a limb's worth of digits at a time to minimize the slow `u128` divisions
+    while value >= u128::from(limb_base) {
+        let digits = output
+            .get_mut(index..index + limb_digits)
+            .ok_or(Error::BufferTooSmall {
+                required: index + limb_digits,
+            })?;
+        write_digits(digits, (value % u128::from(limb_base)) as u64, len);
+        value /= u128::from(limb_base);
+        index += limb_digits;
+    }
+
+    let (mut value, len) = (value as u64, len.get() as u64);
+    while value > 0 {
+        output
+            .get_mut(index)
+            .ok_or(Error::BufferTooSmall {
+                required: index + 1,
+            })?
+            .write((value % len) as u8);
+        value /= len;
+        index += 1;
+    }
+
+    Ok(index)
+}
+
+/// Write the little-endian digits of an arbitrary length input.
+fn encode_limbs(input: &[u8], output: &mut [MaybeUninit<u8>], len: impl Len) -> Result<usize> {
+    // Up to three bytes are folded in at a time, the most that can be shifted onto a limb
+    // without overflowing the `u64` intermediate
+    let groups = input.chunks(3).map(|group| {
+        let value = group
+            .iter()
+            .fold(0, |acc, &val| (acc << 8) | u64::from(val));
+        (value, 1 << (8 * group.len()))
+    });
+    encode_groups(groups, output, len)
+}
+
+/// Write the little-endian digits of a value given as big-endian `(value, base)` groups, each
+/// value less than its base and each base at most `2^32`.
+pub(crate) fn encode_groups(
+    groups: impl IntoIterator<Item = (u64, u64)>,
+    output: &mut [MaybeUninit<u8>],
+    len: impl Len,
+) -> Result<usize> {
+    let (limb_base, limb_digits) = len.limb_base();
+
+    // The value is accumulated as little-endian `u32` limbs of `limb_digits` digits each, stored
+    // at the start of `output`. The most significant limb is kept separate in `top` so that the
+    // stored limbs never need more space than their expanded digits will.
+    let mut limbs = 0;
+    let mut top = 0u64;
+    for (mut carry, base) in groups {
+        // SAFETY: the first `limbs` limbs have always been initialized
+        for chunk in unsafe { assume_init_mut(&mut output[..limbs * 4]) }.chunks_exact_mut(4) {
+            carry += u64::from(u32::from_le_bytes(chunk.try_into().unwrap())) * base;
+            chunk.copy_from_slice(&((carry % limb_base) as u32).to_le_bytes());
+            carry /= limb_base;
+        }
+        top = top * base + carry;
+        while top >= limb_base {
+            let chunk = output
+                .get_mut(limbs * 4..limbs * 4 + 4)
+                .ok_or(Error::BufferTooSmall {
+                    required: limbs * 4 + 4,
+                })?;
+            for (byte, &limb) in chunk
+                .iter_mut()
+                .zip(&((top % limb_base) as u32).to_le_bytes())
+            {
+                byte.write(limb);
+            }
+            limbs += 1;
+            top /= limb_base;
+        }
+    }
+
+    let top_digits = {
+        let (mut digits, mut value) = (0, top);
+        while value > 0 {
+            digits += 1;
+            value /= len.get() as u64;
+        }
+        digits
+    };
+    let index = limbs * limb_digits + top_digits;
+    if index > output.len() {
+        return Err(Error::BufferTooSmall { required: index });
+    }
+
+    // Expand the limbs into digits starting from the most significant, each limb's digits are
+    // written at or after its own position so this never overwrites a limb not yet expanded
+    write_digits(&mut output[limbs * limb_digits..index], top, len);
+    for i in (0..limbs).rev() {
+        // SAFETY: this limb was initialized above and has not yet been overwritten
+        let limb = unsafe { assume_init_mut(&mut output[i * 4..i * 4 + 4]) };
+        let limb = u32::from_le_bytes((&*limb).try_into().unwrap());
+        write_digits(
+            &mut output[i * limb_digits..(i + 1) * limb_digits],
+            u64::from(limb),
+            len,
+        );
+    }
+
+    Ok(index)
+}
+
+/// Encode `input` using the alphabet `encode`, `len` is its length which may be a compile time
+/// constant.
+pub(crate) fn encode_into<L: Len>(
+    input: &[u8],
+    output: &mut [MaybeUninit<u8>],
+    len: L,
+    encode: &[u8],
+) -> Result<usize> {
+    #[cfg(feature = "rayon")]
+    {
+        let len = len.get();
+        if len > 1 && len.is_power_of_two() && input.len() >= crate::parallel::THRESHOLD {
+            return crate::parallel::encode(input, output, encode);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    {
+        let len = len.get();
+        if len > 1 && input.len() >= crate::bignum::ENCODE_THRESHOLD {
+            let digits = crate::bignum::encode(input, len);
+            let output = output
+                .get_mut(..digits.len())
+                .ok_or(Error::BufferTooSmall {
+                    required: digits.len(),
+                })?;
+            for (out, &digit) in output.iter_mut().zip(&digits) {
+                out.write(encode[digit as usize]);
             }
-            output[index] = (carry % len) as u8;
-            index += 1;
-            carry /= len;
+            return Ok(digits.len());
         }
     }
 
+    let mut index = if input.len() <= 16 {
+        encode_u128(input, output, len)?
+    } else {
+        encode_limbs(input, output, len)?
+    };
+
     for _ in input.iter().take_while(|&&v| v == 0) {
         if index == output.len() {
-            return Err(Error::BufferTooSmall);
+            return Err(Error::BufferTooSmall {
+                required: index + 1,
+            });
         }
-        output[index] = 0;
+        output[index].write(0);
         index += 1;
     }
 
-    for val in &mut output[..index] {
-        *val = encode[*val as usize];
-    }
+    // SAFETY: the first `index` bytes have all been initialized
+    let output = unsafe { assume_init_mut(&mut output[..index]) };
+    crate::simd::translate_reverse(output, encode);
 
-    output[..index].reverse();
     Ok(index)
 }
 
@@ -317,8 +1817,19 @@ impl std::error::Error for Error {}
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            Error::BufferTooSmall => {
-                write!(f, "buffer provided to encode string into was too small")
+            Error::BufferTooSmall { required } => write!(
+                f,
+                "buffer provided to encode string into was too small, {} bytes are required",
+                required
+            ),
+            Error::InvalidUtf8 => write!(f, "encoded output was not valid utf-8"),
+            Error::Fmt => write!(f, "formatter returned an error"),
+            Error::Overflow => write!(f, "encoded length would overflow usize"),
+            Error::TooManyLeadingZeros => {
+                write!(
+                    f,
+                    "input had too many leading zeros to count in one character"
+                )
             }
         }
     }
@@ -0,0 +1,81 @@
+//! The crate-wide error type.
+
+use core::fmt;
+
+use crate::{alphabet, decode, encode};
+
+/// Any error from preparing an alphabet, encoding, or decoding.
+///
+/// Each module's own error converts into this, so `?` can be used across the whole API while the
+/// module errors are still available for precise matching.
+///
+/// # Examples
+///
+/// ```rust
+/// fn reencode(input: &str, from: &str, to: &str) -> Result<String, bsx::Error> {
+///     let from = bsx::DynamicAlphabet::new(from)?;
+///     let to = bsx::DynamicAlphabet::new(to)?;
+///     let decoded = bsx::decode(input).with_alphabet(&from).into_array::<8>()?;
+///     Ok(bsx::encode(decoded).with_alphabet(&to).into_array::<10>()?.to_string())
+/// }
+///
+/// let bitcoin = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+/// let flickr = "123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ";
+/// assert_eq!("GD11NWNR1C", reencode("he11owor1d", bitcoin, flickr)?);
+///
+/// assert!(matches!(
+///     reencode("he11owor1d", "aa", flickr),
+///     Err(bsx::Error::Alphabet(_))));
+/// assert_eq!(
+///     Err(bsx::Error::Decode(bsx::decode::Error::InvalidCharacter { character: '0', index: 4 })),
+///     reencode("he110", bitcoin, flickr));
+/// assert_eq!(
+///     Err(bsx::Error::Encode(bsx::encode::Error::BufferTooSmall { required: 11 })),
+///     reencode("jpXCZedGfVQ", bitcoin, flickr));
+/// # Ok::<(), bsx::Error>(())
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum Error {
+    /// An error preparing an alphabet.
+    Alphabet(alphabet::Error),
+
+    /// An error encoding to a string.
+    Encode(encode::Error),
+
+    /// An error decoding a string.
+    Decode(decode::Error),
+}
+
+impl From<alphabet::Error> for Error {
+    fn from(err: alphabet::Error) -> Self {
+        Error::Alphabet(err)
+    }
+}
+
+impl From<encode::Error> for Error {
+    fn from(err: encode::Error) -> Self {
+        Error::Encode(err)
+    }
+}
+
+impl From<decode::Error> for Error {
+    fn from(err: decode::Error) -> Self {
+        Error::Decode(err)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Alphabet(err) => err.fmt(f),
+            Error::Encode(err) => err.fmt(f),
+            Error::Decode(err) => err.fmt(f),
+        }
+    }
+}
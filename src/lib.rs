@@ -10,16 +10,33 @@
 #![warn(variant_size_differences)]
 #![warn(rust_2018_idioms)]
 #![doc(test(attr(deny(warnings))))]
-#![feature(min_const_generics)]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
 
-//! Another arbitrary base codec implementation, using min-const-generics.
+//! Another arbitrary base codec implementation, using const generics.
 //!
 //! # Features
 //!
 //!  Feature | Activation         | Effect
 //! ---------|--------------------|--------
-//!  `std`   | **on**-by-default  | Implement [`Error`](std::error::Error) for error types
+//!  `std`   | **on**-by-default  | Implement [`Error`](std::error::Error) for error types, and enable the [`stream`](mod@crate::stream) module and [`io`](std::io) targets
 //!  `alloc` | implied by `std`   | Support encoding/decoding to [`Vec`](alloc::vec::Vec) and [`String`](alloc::string::String) as appropriate
+//!  `bigint` | off-by-default    | Support encoding/decoding [`num_bigint::BigUint`] values
+//!  `uuid`  | off-by-default     | Enable the [`uuid`](mod@crate::uuid) module for encoding/decoding [`Uuid`](::uuid::Uuid) values
+//!  `bech32` | off-by-default    | Enable the [`bech32`](mod@crate::bech32) module for checksummed bech32 and bech32m strings
+//!  `base91` | off-by-default    | Enable the [`base91`](mod@crate::base91) module for the basE91 encoding
+//!  `sqids` | off-by-default     | Enable the [`sqids`](mod@crate::sqids) module for short obfuscated IDs compatible with Sqids
+//!  `arrayvec` | off-by-default  | Support encoding/decoding into [`arrayvec::ArrayString`] and [`arrayvec::ArrayVec`] buffers
+//!  `bytes` | off-by-default  | Support encoding/decoding into [`bytes::BytesMut`] buffers
+//!  `heapless` | off-by-default  | Support encoding/decoding into [`heapless::String`] and [`heapless::Vec`] buffers
+//!  `smallvec` | off-by-default  | Support encoding into [`smallvec::SmallVec`] buffers
+//!  `serde` | off-by-default     | Implement `Serialize` and `Deserialize` for [`DynamicAlphabet`] as its alphabet string, and enable the [`serde`](mod@crate::serde) module of field helpers
+//!  `simd`  | off-by-default     | Vectorize the character lookups using whichever of AVX2, SSSE3 or NEON are enabled at compile time
+//!  `portable-simd` | off-by-default | Vectorize the character lookups using the nightly-only [`core::simd`] module
+//!  `async` | off-by-default     | Enable the async streaming adapters in the [`stream`](mod@crate::stream) module
+//!  `rayon` | off-by-default     | Encode and decode large inputs with power-of-two bases in parallel using [`rayon`]
+//!  `macros` | off-by-default    | Enable the [`alphabet!`] macro for defining alphabets with spanned compile errors
+//!  `zeroize` | off-by-default   | Wipe temporary decoding buffers with [`zeroize`], and enable [`DecodeBuilder::into_zeroizing_vec`](decode::DecodeBuilder::into_zeroizing_vec)
+//!  `defmt` | off-by-default    | Implement [`defmt::Format`] for the [`alphabet`](mod@crate::alphabet), [`encode`](mod@crate::encode) and [`decode`](mod@crate::decode) error types
 //!
 //! # Examples
 //!
@@ -43,7 +60,7 @@
 //! bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut decoded)?;
 //! bsx::encode(decoded).with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut encoded)?;
 //! assert_eq!("he11owor1d", encoded);
-//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! # Ok::<(), bsx::Error>(())
 //! ```
 
 #[cfg(feature = "std")]
@@ -52,12 +69,184 @@ extern crate std;
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+pub mod a85;
 pub mod alphabet;
+pub mod base45;
+#[cfg(feature = "base91")]
+#[cfg_attr(docsrs, doc(cfg(feature = "base91")))]
+pub mod base91;
+#[cfg(feature = "bech32")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bech32")))]
+pub mod bech32;
+#[cfg(feature = "alloc")]
+mod bignum;
+mod bits;
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use alphabet::CharAlphabet;
 #[doc(inline)]
 pub use alphabet::{Alphabet, DynamicAlphabet, StaticAlphabet};
 
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use bsx_macros::alphabet;
+
 pub mod decode;
+pub mod detect;
 pub mod encode;
+mod error;
+#[doc(inline)]
+pub use error::Error;
+mod macros;
+pub mod multibase;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
+mod simd;
+#[cfg(feature = "sqids")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sqids")))]
+pub mod sqids;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod stream;
+pub mod transcode;
+pub mod ulid;
+
+#[cfg(feature = "uuid")]
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+pub mod uuid;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::{alphabets, as_str, decoded_len, encode, encoded_len, max_encoded_len};
+}
+
+/// The number of bytes and digits in each independently convertible unit for a base of
+/// `2^bits`.
+const fn pow2_unit(bits: u32) -> (usize, usize) {
+    let gcd = 1
+        << if bits.trailing_zeros() < 3 {
+            bits.trailing_zeros()
+        } else {
+            3
+        };
+    ((bits / gcd) as usize, (8 / gcd) as usize)
+}
+
+/// A temporary buffer of input characters or decoded bytes, wiped when dropped if the `zeroize`
+/// feature is enabled.
+pub(crate) struct Scratch<T: AsMut<[u8]>>(T);
+
+impl<T: AsMut<[u8]>> Scratch<T> {
+    pub(crate) fn new(buffer: T) -> Self {
+        Scratch(buffer)
+    }
+}
+
+impl<T: AsMut<[u8]>> core::ops::Deref for Scratch<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: AsMut<[u8]>> core::ops::DerefMut for Scratch<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: AsMut<[u8]>> Drop for Scratch<T> {
+    fn drop(&mut self) {
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(self.0.as_mut());
+    }
+}
+
+/// The number of fractional bits used in [`log2_fixed`].
+const LOG2_FRAC_BITS: u32 = 16;
+
+/// Calculate a lower bound of `log2(base)`, as a fixed point value with [`LOG2_FRAC_BITS`]
+/// fractional bits.
+const fn log2_fixed(base: usize) -> u128 {
+    if base < 2 {
+        return 0;
+    }
+    let int = 0usize.leading_zeros() - 1 - base.leading_zeros();
+    let mut result = (int as u128) << LOG2_FRAC_BITS;
+
+    // Normalize into [1, 2) with 62 fractional bits, then repeatedly square to extract each
+    // fractional bit of the logarithm. Truncation only ever rounds down, so this remains a lower
+    // bound.
+    let mut y = (base as u128) << (62 - int);
+    let mut bit = LOG2_FRAC_BITS;
+    while bit > 0 {
+        bit -= 1;
+        y = (y * y) >> 62;
+        if y >= 2 << 62 {
+            y >>= 1;
+            result |= 1 << bit;
+        }
+    }
+
+    result
+}
+
+/// An upper bound on the length of the string produced by encoding `input_len` bytes with an
+/// alphabet of length `base`.
+///
+/// This can be used to size a buffer for [`EncodeBuilder::into`](encode::EncodeBuilder::into),
+/// including in `const` contexts. If you have the data available
+/// [`EncodeBuilder::max_encoded_len`](encode::EncodeBuilder::max_encoded_len) can give a tighter
+/// bound.
+///
+/// Saturates at `usize::MAX` for inputs too long for the encoded length to be represented.
+///
+/// ```rust
+/// assert_eq!(44, bsx::encoded_len(32, 58));
+/// assert_eq!(64, bsx::encoded_len(32, 16));
+///
+/// let mut output = [0; bsx::encoded_len(32, 58)];
+/// bsx::encode([0xFF; 32]).with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output[..])?;
+/// # Ok::<(), bsx::encode::Error>(())
+/// ```
+pub const fn encoded_len(input_len: usize, base: usize) -> usize {
+    let bits = (input_len as u128 * 8) << LOG2_FRAC_BITS;
+    let log = log2_fixed(base);
+    let len = bits.div_ceil(log);
+    if len > usize::MAX as u128 {
+        usize::MAX
+    } else {
+        len as usize
+    }
+}
+
+/// An upper bound on the length of the bytes produced by decoding a string of `input_len`
+/// characters with an alphabet of length `base`, when the string has no leading zero characters.
+///
+/// Each leading zero character decodes to an additional zero byte, so in the worst case a string
+/// decodes to `input_len` bytes. If you have the data available
+/// [`DecodeBuilder::max_decoded_len`](decode::DecodeBuilder::max_decoded_len) will take this into
+/// account.
+///
+/// ```rust
+/// assert_eq!(33, bsx::decoded_len(44, 58));
+/// assert_eq!(32, bsx::decoded_len(64, 16));
+/// ```
+pub const fn decoded_len(input_len: usize, base: usize) -> usize {
+    let log = if base.is_power_of_two() {
+        log2_fixed(base)
+    } else {
+        // Round up to get an upper bound on the logarithm instead
+        log2_fixed(base) + 2
+    };
+    let bits = input_len as u128 * log;
+    let divisor = 8 << LOG2_FRAC_BITS;
+    bits.div_ceil(divisor) as usize
+}
 
 /// Setup decoder for the given string using the given alphabet
 ///
@@ -112,13 +301,64 @@ pub mod encode;
 /// ```rust
 /// let mut output = [0; 7];
 /// assert_eq!(
-///     bsx::decode::Error::BufferTooSmall,
+///     bsx::decode::Error::BufferTooSmall { required: 8 },
 ///     bsx::decode("he11owor1d").with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output).unwrap_err());
 /// ```
 pub fn decode<I: AsRef<[u8]>>(input: I) -> decode::DecodeBuilder<I, alphabet::Unspecified> {
     decode::DecodeBuilder::new(input)
 }
 
+/// Decode the given string using the given alphabet in a `const` context
+///
+/// The decoded data must be exactly `N` bytes long. This uses a simple quadratic conversion so is
+/// intended for short inputs such as keys and addresses, at runtime prefer
+/// [`bsx::decode`](crate::decode()).
+///
+/// # Examples
+///
+/// ```rust
+/// const KEY: [u8; 8] = bsx::decode_const("he11owor1d", bsx::StaticAlphabet::BITCOIN);
+/// assert_eq!([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], KEY);
+/// ```
+///
+/// ## Panics
+///
+/// If the input contains a character that is not part of the alphabet, or decodes to a different
+/// length than the array, which fails compilation in a `const` context:
+///
+/// ```compile_fail
+/// const KEY: [u8; 8] = bsx::decode_const("hello world", bsx::StaticAlphabet::BITCOIN);
+/// ```
+///
+/// ```compile_fail
+/// const KEY: [u8; 4] = bsx::decode_const("he11owor1d", bsx::StaticAlphabet::BITCOIN);
+/// ```
+pub const fn decode_const<const N: usize, const LEN: usize>(
+    input: &str,
+    alpha: &StaticAlphabet<LEN>,
+) -> [u8; N] {
+    decode::decode_const(input, alpha)
+}
+
+/// Setup decoder for the given string and alphabet in a single call
+///
+/// This is the same as [`bsx::decode(input).with_alphabet(alpha)`](crate::decode()).
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(
+///     vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+///     bsx::decode_with_alphabet("he11owor1d", bsx::StaticAlphabet::BITCOIN).into_vec()?);
+/// # Ok::<(), bsx::decode::Error>(())
+/// ```
+pub fn decode_with_alphabet<I: AsRef<[u8]>, A: Alphabet>(
+    input: I,
+    alpha: A,
+) -> decode::DecodeBuilder<I, A> {
+    decode(input).with_alphabet(alpha)
+}
+
 /// Setup encoder for the given bytes using the given alphabet
 ///
 /// # Examples
@@ -161,9 +401,286 @@ pub fn decode<I: AsRef<[u8]>>(input: I) -> decode::DecodeBuilder<I, alphabet::Un
 /// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
 /// let mut output = [0; 7];
 /// assert_eq!(
-///     bsx::encode::Error::BufferTooSmall,
+///     bsx::encode::Error::BufferTooSmall { required: 11 },
 ///     bsx::encode(input).with_alphabet(bsx::StaticAlphabet::BITCOIN).into(&mut output[..]).unwrap_err());
 /// ```
+///
+/// ### Invalid UTF-8
+///
+/// This error can only occur when encoding into a string with an
+/// [extended alphabet](StaticAlphabet::new_extended) containing non-ASCII bytes.
+///
+/// ```rust
+/// let extended = bsx::StaticAlphabet::new_extended(b"ab\xFF")?;
+/// assert_eq!(
+///     bsx::encode::Error::InvalidUtf8,
+///     bsx::encode([0x02]).with_alphabet(&extended).into(&mut String::new()).unwrap_err());
+/// # Ok::<(), bsx::alphabet::Error>(())
+/// ```
 pub fn encode<I: AsRef<[u8]>>(input: I) -> encode::EncodeBuilder<I, alphabet::Unspecified> {
     encode::EncodeBuilder::new(input)
 }
+
+/// Setup streaming encoder for the bytes read from the given reader
+///
+/// This only supports alphabets with a power of two length, which can be encoded with constant
+/// memory, see the [`stream`](mod@crate::stream) module for details.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::Read;
+///
+/// let mut output = Vec::new();
+/// bsx::encode_reader(std::io::repeat(0xff).take(3))
+///     .with_alphabet(bsx::StaticAlphabet::BASE16)
+///     .into_writer(&mut output)?;
+/// assert_eq!(b"ffffff", &*output);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn encode_reader<R: std::io::Read>(
+    reader: R,
+) -> stream::EncodeReaderBuilder<R, alphabet::Unspecified> {
+    stream::EncodeReaderBuilder::new(reader)
+}
+
+/// Setup encoder for the given bytes and alphabet in a single call
+///
+/// This is the same as [`bsx::encode(input).with_alphabet(alpha)`](crate::encode()).
+///
+/// # Examples
+///
+/// ```rust
+/// let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+/// assert_eq!(
+///     "he11owor1d",
+///     bsx::encode_with_alphabet(input, bsx::StaticAlphabet::BITCOIN).into_string());
+/// ```
+pub fn encode_with_alphabet<I: AsRef<[u8]>, A: Alphabet>(
+    input: I,
+    alpha: A,
+) -> encode::EncodeBuilder<I, A> {
+    encode(input).with_alphabet(alpha)
+}
+
+/// Encode the given bytes using the given alphabet in a `const` context
+///
+/// The output is written into a fixed capacity [`EncodedArray`](encode::EncodedArray), which
+/// must be large enough to hold it, [`bsx::encoded_len`](crate::encoded_len()) can be used to
+/// calculate a capacity that is always sufficient. This uses a simple quadratic conversion so is
+/// intended for short inputs such as key identifiers, at runtime prefer
+/// [`bsx::encode`](crate::encode()).
+///
+/// # Examples
+///
+/// ```rust
+/// const KEY_ID: bsx::encode::EncodedArray<16> = bsx::encode_const(
+///     &[0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+///     bsx::StaticAlphabet::BITCOIN);
+/// const KEY_ID_STR: &str = KEY_ID.as_str();
+/// assert_eq!("he11owor1d", KEY_ID_STR);
+/// ```
+///
+/// ## Panics
+///
+/// If the array is too small to hold the encoded output, which fails compilation in a `const`
+/// context:
+///
+/// ```compile_fail
+/// const KEY_ID: bsx::encode::EncodedArray<8> = bsx::encode_const(
+///     &[0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+///     bsx::StaticAlphabet::BITCOIN);
+/// ```
+pub const fn encode_const<const N: usize, const LEN: usize>(
+    input: &[u8],
+    alpha: &StaticAlphabet<LEN>,
+) -> encode::EncodedArray<N> {
+    encode::encode_const(input, alpha)
+}
+
+/// Setup encoder for the given integer using the given alphabet
+///
+/// This encodes the integer as a numeral in the alphabet's base, so a zero value is encoded as a
+/// single zero character and there are never any leading zeros. This does not require allocation
+/// when encoding into an existing buffer.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(
+///     "BukQL",
+///     bsx::encode_int(123456789u32).with_alphabet(bsx::StaticAlphabet::BITCOIN).into_string());
+/// assert_eq!(
+///     "1",
+///     bsx::encode_int(0u32).with_alphabet(bsx::StaticAlphabet::BITCOIN).into_string());
+/// ```
+///
+/// ## Encoding into a stack buffer
+///
+/// ```rust
+/// let mut output = [0; 22];
+/// let len = bsx::encode_int(u128::MAX)
+///     .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+///     .into(&mut output[..])?;
+/// assert_eq!(b"YcVfxkQb6JRzqk5kF2tNLv", &output[..len]);
+/// # Ok::<(), bsx::encode::Error>(())
+/// ```
+pub fn encode_int(
+    value: impl Into<u128>,
+) -> encode::EncodeBuilder<encode::IntInput, alphabet::Unspecified> {
+    encode::EncodeBuilder::new(encode::IntInput::new(value.into()))
+}
+
+/// Setup encoder for the given arbitrary precision integer using the given alphabet
+///
+/// This has the same integer numeral semantics as [`bsx::encode_int`](crate::encode_int()).
+///
+/// # Examples
+///
+/// ```rust
+/// let value = num_bigint::BigUint::from(u128::MAX) << 64;
+/// assert_eq!(
+///     "QLbz7JHiBTspS962RLKV8G3oz4P4cKApw",
+///     bsx::encode_biguint(&value).with_alphabet(bsx::StaticAlphabet::BITCOIN).into_string());
+/// ```
+#[cfg(feature = "bigint")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bigint")))]
+pub fn encode_biguint(
+    value: &num_bigint::BigUint,
+) -> encode::EncodeBuilder<alloc::vec::Vec<u8>, alphabet::Unspecified> {
+    encode::EncodeBuilder::new(value.to_bytes_be())
+}
+
+/// Setup transcoder for the given string from one alphabet into another
+///
+/// This is equivalent to decoding then re-encoding the string, but works directly on the output
+/// buffer without an intermediate decoded buffer.
+///
+/// # Examples
+///
+/// ## Basic example
+///
+/// ```rust
+/// assert_eq!(
+///     "4DSSNaN1SC",
+///     bsx::transcode("he11owor1d")
+///         .from_alphabet(bsx::StaticAlphabet::RIPPLE)
+///         .to_alphabet(bsx::StaticAlphabet::FLICKR)
+///         .into_string()?);
+/// # Ok::<(), bsx::transcode::Error>(())
+/// ```
+///
+/// ## Transcoding into an existing buffer
+///
+/// ```rust
+/// let mut output = [0; 10];
+/// bsx::transcode("he11owor1d")
+///     .from_alphabet(bsx::StaticAlphabet::RIPPLE)
+///     .to_alphabet(bsx::StaticAlphabet::FLICKR)
+///     .into(&mut output[..])?;
+/// assert_eq!(b"4DSSNaN1SC", &output);
+/// # Ok::<(), bsx::transcode::Error>(())
+/// ```
+///
+/// ## Errors
+///
+/// ### Invalid Character
+///
+/// ```rust
+/// assert_eq!(
+///     bsx::transcode::Error::InvalidCharacter { character: 'l', index: 2 },
+///     bsx::transcode("hello world")
+///         .from_alphabet(bsx::StaticAlphabet::BITCOIN)
+///         .to_alphabet(bsx::StaticAlphabet::FLICKR)
+///         .into_string()
+///         .unwrap_err());
+/// ```
+///
+/// ### Too Small Buffer
+///
+/// This error can only occur when transcoding into an unresizeable buffer.
+///
+/// ```rust
+/// let mut output = [0; 7];
+/// assert_eq!(
+///     bsx::transcode::Error::BufferTooSmall { required: 10 },
+///     bsx::transcode("he11owor1d")
+///         .from_alphabet(bsx::StaticAlphabet::RIPPLE)
+///         .to_alphabet(bsx::StaticAlphabet::FLICKR)
+///         .into(&mut output[..])
+///         .unwrap_err());
+/// ```
+pub fn transcode<I: AsRef<[u8]>>(
+    input: I,
+) -> transcode::TranscodeBuilder<I, alphabet::Unspecified, alphabet::Unspecified> {
+    transcode::TranscodeBuilder::new(input)
+}
+
+/// Setup checking which alphabets the given string could be encoded in.
+///
+/// Every built-in alphabet, and optionally any given to
+/// [`with_alphabets`](detect::DetectBuilder::with_alphabets), is checked for containing all the
+/// characters of the input. This only looks at the characters used, so short inputs will
+/// commonly be valid in many alphabets.
+///
+/// # Examples
+///
+/// ```rust
+/// let names: Vec<_> = bsx::detect("3BukQL").candidates().map(|candidate| candidate.name).collect();
+/// assert_eq!(
+///     vec!["bitcoin", "ripple", "flickr", "base36", "base62", "base64", "base64url", "ascii85", "z85", "base91"],
+///     names);
+///
+/// let (name, alpha) = bsx::detect("4DSSNaN1SC")
+///     .candidates()
+///     .map(|candidate| (candidate.name, candidate.alphabet))
+///     .next()
+///     .unwrap();
+/// assert_eq!("bitcoin", name);
+/// assert_eq!(
+///     vec![0x54, 0xd3, 0x00, 0xdb, 0xa3, 0x9c, 0xed],
+///     bsx::decode("4DSSNaN1SC").with_alphabet(alpha).into_vec()?);
+/// # Ok::<(), bsx::decode::Error>(())
+/// ```
+pub fn detect<I: AsRef<[u8]>>(input: I) -> detect::DetectBuilder<'static, 'static, I> {
+    detect::DetectBuilder::new(input)
+}
+
+/// Setup translation for the given string from one alphabet into another of the same length
+///
+/// When both alphabets are the same length transcoding is a simple per-character substitution, so
+/// this runs in linear time. [`bsx::transcode`](crate::transcode()) will automatically use this
+/// when possible, this function is useful when you want to guarantee it.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(
+///     "he11owor1d",
+///     bsx::translate("4essoAo1sd")
+///         .from(bsx::StaticAlphabet::BITCOIN)
+///         .to(bsx::StaticAlphabet::RIPPLE)
+///         .into_string()?);
+/// # Ok::<(), bsx::transcode::Error>(())
+/// ```
+///
+/// ## Errors
+///
+/// ### Alphabet Length Mismatch
+///
+/// ```rust
+/// assert_eq!(
+///     bsx::transcode::Error::AlphabetLengthMismatch { from: 58, to: 10 },
+///     bsx::translate("he11owor1d")
+///         .from(bsx::StaticAlphabet::BITCOIN)
+///         .to(bsx::multibase::Base::Base10)
+///         .into_string()
+///         .unwrap_err());
+/// ```
+pub fn translate<I: AsRef<[u8]>>(
+    input: I,
+) -> transcode::TranslateBuilder<I, alphabet::Unspecified, alphabet::Unspecified> {
+    transcode::TranslateBuilder::new(input)
+}
@@ -0,0 +1,235 @@
+//! Macros for encoding and decoding at compile time without spelling out the output length.
+
+use crate::StaticAlphabet;
+
+/// Encode the given bytes using the given alphabet at compile time, evaluating to a
+/// `&'static str`.
+///
+/// This is the same as [`bsx::encode_const`](crate::encode_const()) but calculates the exact
+/// output length itself. The alphabet can be given as a bare name of one of the
+/// [`StaticAlphabet`] associated alphabets, or as any other `const` expression evaluating to a
+/// `&StaticAlphabet`.
+///
+/// # Examples
+///
+/// ```rust
+/// const KEY_ID: &str = bsx::encode_const!(&[0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], BITCOIN);
+/// assert_eq!("he11owor1d", KEY_ID);
+///
+/// const DECIMAL: &bsx::StaticAlphabet<10> = &bsx::StaticAlphabet::new_unwrap(b"0123456789");
+/// assert_eq!("258", bsx::encode_const!(&[0x01, 0x02], DECIMAL));
+/// ```
+#[macro_export]
+macro_rules! encode_const {
+    ($input:expr, $alpha:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::alphabets::*;
+        const ENCODED: &[u8] = &$crate::__private::encode(
+            $input,
+            $alpha,
+            [0; $crate::__private::encoded_len(
+                $input,
+                $alpha,
+                [0; $crate::__private::max_encoded_len($input, $alpha)],
+            )],
+        );
+        $crate::__private::as_str(ENCODED)
+    }};
+}
+
+/// Decode the given string using the given alphabet at compile time, evaluating to a byte array
+/// of exactly the decoded length.
+///
+/// This is the same as [`bsx::decode_const`](crate::decode_const()) but calculates the output
+/// length itself. The alphabet can be given as a bare name of one of the [`StaticAlphabet`]
+/// associated alphabets, or as any other `const` expression evaluating to a `&StaticAlphabet`.
+///
+/// # Examples
+///
+/// ```rust
+/// const KEY: &[u8] = &bsx::decode_const!("he11owor1d", BITCOIN);
+/// assert_eq!([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], KEY);
+///
+/// let key = bsx::decode_const!("he11owor1d", bsx::StaticAlphabet::RIPPLE);
+/// assert_eq!([0x60, 0x65, 0xe7, 0x9b, 0xba, 0x2f, 0x78], key);
+/// ```
+///
+/// An invalid character fails compilation:
+///
+/// ```compile_fail
+/// let key = bsx::decode_const!("hello world", BITCOIN);
+/// ```
+#[macro_export]
+macro_rules! decode_const {
+    ($input:expr, $alpha:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::alphabets::*;
+        const DECODED: [u8; $crate::__private::decoded_len($input, $alpha, [0; $input.len()])] =
+            $crate::decode_const($input, $alpha);
+        DECODED
+    }};
+}
+
+/// Define a newtype around `[u8; N]` or `Vec<u8>` that uses an encoded string for its
+/// [`Display`](core::fmt::Display), [`Debug`](core::fmt::Debug) and
+/// [`FromStr`](core::str::FromStr) implementations.
+///
+/// The alphabet can be given as a bare name of one of the [`StaticAlphabet`] associated
+/// alphabets, or as any other `const` expression evaluating to a reference to an
+/// [`Alphabet`](crate::Alphabet). The newtype also implements [`AsRef<[u8]>`](AsRef) and
+/// [`From`] the wrapped type, any other traits can be derived as normal.
+///
+/// # Examples
+///
+/// ```rust
+/// bsx::newtype! {
+///     /// A key identifier.
+///     #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+///     pub struct KeyId([u8; 8]) = BITCOIN;
+/// }
+///
+/// let id: KeyId = "he11owor1d".parse()?;
+/// assert_eq!([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58], id.0);
+/// assert_eq!("he11owor1d", id.to_string());
+/// assert_eq!("KeyId(\"he11owor1d\")", format!("{:?}", id));
+///
+/// assert_eq!(
+///     bsx::decode::Error::InvalidLength { expected: 8 },
+///     "2g".parse::<KeyId>().unwrap_err());
+/// # Ok::<(), bsx::decode::Error>(())
+/// ```
+///
+/// ```rust
+/// const DECIMAL: &bsx::StaticAlphabet<10> = &bsx::StaticAlphabet::new_unwrap(b"0123456789");
+///
+/// bsx::newtype! {
+///     struct Payload(Vec<u8>) = DECIMAL;
+/// }
+///
+/// let payload = Payload::from(vec![0x01, 0x02]);
+/// assert_eq!("258", payload.to_string());
+/// assert_eq!(&[0x01, 0x02], "258".parse::<Payload>()?.as_ref());
+/// # Ok::<(), bsx::decode::Error>(())
+/// ```
+#[macro_export]
+macro_rules! newtype {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident($inner_vis:vis $ty:ty) = $alpha:expr;) => {
+        $(#[$meta])*
+        $vis struct $name($inner_vis $ty);
+
+        impl $name {
+            #[allow(unused_imports)]
+            const __BSX_ALPHABET: &'static dyn $crate::Alphabet = {
+                use $crate::__private::alphabets::*;
+                $alpha
+            };
+        }
+
+        impl ::core::convert::AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_ref()
+            }
+        }
+
+        impl ::core::convert::From<$ty> for $name {
+            fn from(value: $ty) -> Self {
+                $name(value)
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::encode(&self.0)
+                    .with_alphabet(Self::__BSX_ALPHABET)
+                    .into_fmt(f)
+                    .map_err(|_| ::core::fmt::Error)?;
+                Ok(())
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&format_args!("\"{}\"", self))
+                    .finish()
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::decode::Error;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                <$ty as $crate::decode::FromEncoded>::from_encoded(s, Self::__BSX_ALPHABET).map($name)
+            }
+        }
+    };
+}
+
+/// The [`StaticAlphabet`] associated alphabets, so they can be named directly in the macros.
+#[allow(missing_docs)]
+pub mod alphabets {
+    use crate::StaticAlphabet;
+
+    pub const BITCOIN: &StaticAlphabet<58> = StaticAlphabet::BITCOIN;
+    pub const MONERO: &StaticAlphabet<58> = StaticAlphabet::MONERO;
+    pub const RIPPLE: &StaticAlphabet<58> = StaticAlphabet::RIPPLE;
+    pub const FLICKR: &StaticAlphabet<58> = StaticAlphabet::FLICKR;
+    pub const CROCKFORD: &StaticAlphabet<32> = StaticAlphabet::CROCKFORD;
+    pub const ZBASE32: &StaticAlphabet<32> = StaticAlphabet::ZBASE32;
+    pub const BASE32HEX: &StaticAlphabet<32> = StaticAlphabet::BASE32HEX;
+    pub const GEOHASH: &StaticAlphabet<32> = StaticAlphabet::GEOHASH;
+    pub const BECH32: &StaticAlphabet<32> = StaticAlphabet::BECH32;
+    pub const BASE16: &StaticAlphabet<16> = StaticAlphabet::BASE16;
+    pub const BASE16_UPPER: &StaticAlphabet<16> = StaticAlphabet::BASE16_UPPER;
+    pub const BASE36: &StaticAlphabet<36> = StaticAlphabet::BASE36;
+    pub const BASE45: &StaticAlphabet<45> = StaticAlphabet::BASE45;
+    pub const BASE62: &StaticAlphabet<62> = StaticAlphabet::BASE62;
+    pub const BASE64: &StaticAlphabet<64> = StaticAlphabet::BASE64;
+    pub const BASE64URL: &StaticAlphabet<64> = StaticAlphabet::BASE64URL;
+    pub const ASCII85: &StaticAlphabet<85> = StaticAlphabet::ASCII85;
+    pub const Z85: &StaticAlphabet<85> = StaticAlphabet::Z85;
+    pub const BASE91: &StaticAlphabet<91> = StaticAlphabet::BASE91;
+}
+
+/// An upper bound on the encoded length of `input`.
+pub const fn max_encoded_len<const LEN: usize>(
+    input: &[u8],
+    _alpha: &StaticAlphabet<LEN>,
+) -> usize {
+    crate::encoded_len(input.len(), LEN)
+}
+
+/// The exact encoded length of `input`, `M` must be at least that long.
+pub const fn encoded_len<const M: usize, const LEN: usize>(
+    input: &[u8],
+    alpha: &StaticAlphabet<LEN>,
+    _scratch: [u8; M],
+) -> usize {
+    crate::encode::encode_const_raw::<M, LEN>(input, alpha).1
+}
+
+/// Encode `input` into an array of exactly its encoded length `N`.
+pub const fn encode<const N: usize, const LEN: usize>(
+    input: &[u8],
+    alpha: &StaticAlphabet<LEN>,
+    _scratch: [u8; N],
+) -> [u8; N] {
+    crate::encode::encode_const_raw::<N, LEN>(input, alpha).0
+}
+
+/// The exact decoded length of `input`, `M` must be at least that long.
+pub const fn decoded_len<const M: usize, const LEN: usize>(
+    input: &str,
+    alpha: &StaticAlphabet<LEN>,
+    _scratch: [u8; M],
+) -> usize {
+    crate::decode::decode_const_le::<M, LEN>(input, alpha).1
+}
+
+/// View encoded bytes as a string.
+pub const fn as_str(bytes: &[u8]) -> &str {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(_) => panic!("encoded output is not valid UTF-8"),
+    }
+}
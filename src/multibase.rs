@@ -0,0 +1,252 @@
+//! Support for [multibase](https://github.com/multiformats/multibase) prefixed strings.
+//!
+//! The multibase encodings that use the same arbitrary base algorithm as the rest of this crate
+//! are supported, along with the bit-packed RFC 4648 `base32`. The other RFC 4648 style encodings
+//! (`base32pad`, `base64`, etc.) are not.
+
+use core::{fmt, mem::MaybeUninit};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    alphabet::sealed,
+    bits,
+    decode::{self, Error as DecodeError},
+    encode, Alphabet, StaticAlphabet,
+};
+
+/// The multibase encodings supported by this crate.
+///
+/// This can be passed to
+/// [`EncodeBuilder::with_multibase_prefix`](crate::encode::EncodeBuilder::with_multibase_prefix)
+/// to produce a prefixed string, or used directly as an [`Alphabet`] to get the unprefixed
+/// encoding.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Base {
+    /// Decimal, prefix `9`.
+    Base10,
+    /// Lowercase unpadded RFC 4648 base32, prefix `b`.
+    ///
+    /// Unlike the other encodings the input bits are packed from the start, so leading zero
+    /// bytes don't map to whole zero characters.
+    Base32,
+    /// Lowercase base36, prefix `k`.
+    Base36,
+    /// Uppercase base36, prefix `K`.
+    Base36Upper,
+    /// Base58 using the [Bitcoin alphabet](crate::StaticAlphabet::BITCOIN), prefix `z`.
+    Base58Btc,
+    /// Base58 using the [Flickr alphabet](crate::StaticAlphabet::FLICKR), prefix `Z`.
+    Base58Flickr,
+}
+
+const BASE10: &StaticAlphabet<10> = &StaticAlphabet::new_unwrap(b"0123456789");
+const BASE32: &StaticAlphabet<32> =
+    &StaticAlphabet::new_unwrap(b"abcdefghijklmnopqrstuvwxyz234567");
+const BASE36: &StaticAlphabet<36> =
+    &StaticAlphabet::new_unwrap(b"0123456789abcdefghijklmnopqrstuvwxyz");
+const BASE36_UPPER: &StaticAlphabet<36> =
+    &StaticAlphabet::new_unwrap(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+
+impl Base {
+    /// The prefix character that identifies this encoding.
+    ///
+    /// ```rust
+    /// assert_eq!('z', bsx::multibase::Base::Base58Btc.code());
+    /// ```
+    pub const fn code(self) -> char {
+        match self {
+            Base::Base10 => '9',
+            Base::Base32 => 'b',
+            Base::Base36 => 'k',
+            Base::Base36Upper => 'K',
+            Base::Base58Btc => 'z',
+            Base::Base58Flickr => 'Z',
+        }
+    }
+
+    /// Lookup the encoding identified by a prefix character, if it is supported.
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     Some(bsx::multibase::Base::Base58Flickr),
+    ///     bsx::multibase::Base::from_code('Z'));
+    /// assert_eq!(None, bsx::multibase::Base::from_code('m'));
+    /// ```
+    pub const fn from_code(code: char) -> Option<Self> {
+        Some(match code {
+            '9' => Base::Base10,
+            'b' => Base::Base32,
+            'k' => Base::Base36,
+            'K' => Base::Base36Upper,
+            'z' => Base::Base58Btc,
+            'Z' => Base::Base58Flickr,
+            _ => return None,
+        })
+    }
+
+    /// The alphabet used by this encoding.
+    pub const fn alphabet(self) -> &'static dyn Alphabet {
+        match self {
+            Base::Base10 => BASE10,
+            Base::Base32 => BASE32,
+            Base::Base36 => BASE36,
+            Base::Base36Upper => BASE36_UPPER,
+            Base::Base58Btc => StaticAlphabet::BITCOIN,
+            Base::Base58Flickr => StaticAlphabet::FLICKR,
+        }
+    }
+}
+
+impl sealed::Sealed for Base {
+    fn encode_into(&self, input: &[u8], output: &mut [MaybeUninit<u8>]) -> encode::Result<usize> {
+        match self {
+            Base::Base32 => bits::encode_packed(input, output, &BASE32.encode),
+            _ => self.alphabet().encode_into(input, output),
+        }
+    }
+
+    fn decode_into(&self, input: &[u8], output: &mut [u8]) -> decode::Result<usize> {
+        match self {
+            Base::Base32 => {
+                let mut input = input.iter().copied().enumerate();
+                bits::decode_packed(&mut input, output, &BASE32.decode, 5)
+            }
+            _ => self.alphabet().decode_into(input, output),
+        }
+    }
+
+    fn decode_iter(
+        &self,
+        input: &mut dyn Iterator<Item = (usize, u8)>,
+        output: &mut [u8],
+    ) -> decode::Result<usize> {
+        match self {
+            Base::Base32 => bits::decode_packed(input, output, &BASE32.decode, 5),
+            _ => self.alphabet().decode_iter(input, output),
+        }
+    }
+
+    fn bit_packed(&self) -> bool {
+        *self == Base::Base32
+    }
+}
+
+impl Alphabet for Base {
+    fn len(&self) -> usize {
+        self.alphabet().len()
+    }
+
+    fn encode(&self) -> &[u8] {
+        self.alphabet().encode()
+    }
+
+    fn decode(&self) -> &[u8] {
+        self.alphabet().decode()
+    }
+}
+
+/// A specialized [`Result`](core::result::Result) type for [`bsx::multibase`](module@crate::multibase)
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Errors that could occur when decoding a multibase prefixed string.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input was empty, so did not contain a prefix character.
+    MissingPrefix,
+
+    /// The prefix character does not identify a supported encoding.
+    UnknownPrefix {
+        /// The unrecognised prefix character.
+        character: char,
+    },
+
+    /// Decoding the data following the prefix failed, any indexes are relative to the start of
+    /// the whole input including the prefix.
+    Decode(DecodeError),
+}
+
+/// Decode a multibase prefixed string, detecting the encoding from its prefix character.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(
+///     (bsx::multibase::Base::Base58Btc, vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]),
+///     bsx::multibase::decode("zhe11owor1d")?);
+/// # Ok::<(), bsx::multibase::Error>(())
+/// ```
+///
+/// ## Errors
+///
+/// ### Unknown Prefix
+///
+/// ```rust
+/// assert_eq!(
+///     bsx::multibase::Error::UnknownPrefix { character: 'm' },
+///     bsx::multibase::decode("mhe11owor1d").unwrap_err());
+/// ```
+///
+/// ### Invalid Character
+///
+/// ```rust
+/// assert_eq!(
+///     bsx::multibase::Error::Decode(
+///         bsx::decode::Error::InvalidCharacter { character: 'l', index: 3 }),
+///     bsx::multibase::decode("zhello world").unwrap_err());
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub fn decode<I: AsRef<[u8]>>(input: I) -> Result<(Base, Vec<u8>)> {
+    let (&code, rest) = input.as_ref().split_first().ok_or(Error::MissingPrefix)?;
+    if code > 127 {
+        return Err(Error::Decode(DecodeError::NonAsciiCharacter { index: 0 }));
+    }
+    let base = Base::from_code(code as char).ok_or(Error::UnknownPrefix {
+        character: code as char,
+    })?;
+    let output = crate::decode(rest)
+        .with_alphabet(base)
+        .into_vec()
+        .map_err(|err| {
+            Error::Decode(match err {
+                DecodeError::InvalidCharacter { character, index } => {
+                    DecodeError::InvalidCharacter {
+                        character,
+                        index: index + 1,
+                    }
+                }
+                DecodeError::NonAsciiCharacter { index } => {
+                    DecodeError::NonAsciiCharacter { index: index + 1 }
+                }
+                other => other,
+            })
+        })?;
+    Ok((base, output))
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Decode(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::MissingPrefix => write!(f, "provided string was missing a multibase prefix"),
+            Error::UnknownPrefix { character } => {
+                write!(f, "unknown multibase prefix {:?}", character)
+            }
+            Error::Decode(_) => write!(f, "failed to decode multibase string"),
+        }
+    }
+}
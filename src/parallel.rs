@@ -0,0 +1,154 @@
+//! Parallel conversions of large inputs for power-of-two bases.
+//!
+//! With a base of `2^bits` every `lcm(8, bits)` bits of the value map to a fixed number of
+//! digits, so splitting the input into units aligned from the least significant end lets each
+//! part of it be converted independently.
+
+use alloc::vec;
+use core::mem::MaybeUninit;
+
+use rayon::prelude::*;
+
+use crate::{
+    decode::{self as decoding, decode_digit},
+    encode as encoding,
+};
+
+/// Inputs with at least this many bytes (or characters when decoding) are converted in parallel.
+pub(crate) const THRESHOLD: usize = 1 << 16;
+
+/// The number of units converted by each parallel task.
+const UNITS_PER_TASK: usize = 4096;
+
+fn encode_unit(unit: &[u8], digits: &mut [u8], bits: u32) {
+    let value = unit.iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+    for (i, digit) in digits.iter_mut().rev().enumerate() {
+        *digit = ((value >> (i as u32 * bits)) & ((1 << bits) - 1)) as u8;
+    }
+}
+
+fn decode_unit(digits: &[u8], unit: &mut [u8], bits: u32) {
+    let value = digits
+        .iter()
+        .fold(0u64, |acc, &digit| (acc << bits) | u64::from(digit));
+    for (i, byte) in unit.iter_mut().rev().enumerate() {
+        *byte = (value >> (i * 8)) as u8;
+    }
+}
+
+/// Encode `input` using the alphabet `table`, whose length must be a power of two.
+pub(crate) fn encode(
+    input: &[u8],
+    output: &mut [MaybeUninit<u8>],
+    table: &[u8],
+) -> encoding::Result<usize> {
+    let bits = table.len().trailing_zeros();
+    let (unit_bytes, unit_digits) = crate::pow2_unit(bits);
+    let zeros = input.iter().take_while(|&&b| b == 0).count();
+    let input = &input[zeros..];
+
+    // The partial unit at the start is converted as if it were zero padded
+    let (head, body) = input.split_at(input.len() % unit_bytes);
+    let head_digits = if head.is_empty() { 0 } else { unit_digits };
+    let mut digits = vec![0; head_digits + body.len() / unit_bytes * unit_digits];
+    let (head_output, body_output) = digits.split_at_mut(head_digits);
+    encode_unit(head, head_output, bits);
+    body.par_chunks(unit_bytes * UNITS_PER_TASK)
+        .zip(body_output.par_chunks_mut(unit_digits * UNITS_PER_TASK))
+        .for_each(|(input, output)| {
+            for (unit, digits) in input
+                .chunks_exact(unit_bytes)
+                .zip(output.chunks_exact_mut(unit_digits))
+            {
+                encode_unit(unit, digits, bits);
+            }
+        });
+
+    let digits = &digits[digits.iter().take_while(|&&d| d == 0).count()..];
+    let output = output
+        .get_mut(..zeros + digits.len())
+        .ok_or(encoding::Error::BufferTooSmall {
+            required: zeros + digits.len(),
+        })?;
+    let (leading, rest) = output.split_at_mut(zeros);
+    for c in leading {
+        c.write(table[0]);
+    }
+    rest.par_iter_mut()
+        .zip(digits.par_iter())
+        .for_each(|(c, &digit)| {
+            c.write(table[digit as usize]);
+        });
+
+    Ok(zeros + digits.len())
+}
+
+/// Decode `input` using the 256 entry decoding `table` for an alphabet of length `len`, which
+/// must be a power of two.
+pub(crate) fn decode(
+    input: &[u8],
+    output: &mut [u8],
+    table: &[u8],
+    len: usize,
+) -> decoding::Result<usize> {
+    let bits = len.trailing_zeros();
+    let (unit_bytes, unit_digits) = crate::pow2_unit(bits);
+
+    let mut digits = vec![0; input.len()];
+    let task_len = unit_digits * UNITS_PER_TASK;
+    let error = input
+        .par_chunks(task_len)
+        .zip(digits.par_chunks_mut(task_len))
+        .enumerate()
+        .find_map_first(|(task, (input, digits))| {
+            if crate::simd::decode(input, digits, &table[..128]) {
+                return None;
+            }
+            // Redo the lookups one at a time, either finding which character was invalid or
+            // handling non-ASCII characters of an extended alphabet
+            input
+                .iter()
+                .zip(digits)
+                .enumerate()
+                .find_map(|(i, (&c, digit))| {
+                    decode_digit(table, task * task_len + i, c)
+                        .map(|value| *digit = value)
+                        .err()
+                })
+        });
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    let zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+    let digits = &digits[zeros..];
+
+    // The partial unit at the start is converted as if it were zero padded
+    let (head, body) = digits.split_at(digits.len() % unit_digits);
+    let head_bytes = if head.is_empty() { 0 } else { unit_bytes };
+    let mut bytes = vec![0; head_bytes + body.len() / unit_digits * unit_bytes];
+    let (head_output, body_output) = bytes.split_at_mut(head_bytes);
+    decode_unit(head, head_output, bits);
+    body.par_chunks(task_len)
+        .zip(body_output.par_chunks_mut(unit_bytes * UNITS_PER_TASK))
+        .for_each(|(digits, output)| {
+            for (digits, unit) in digits
+                .chunks_exact(unit_digits)
+                .zip(output.chunks_exact_mut(unit_bytes))
+            {
+                decode_unit(digits, unit, bits);
+            }
+        });
+
+    let bytes = &bytes[bytes.iter().take_while(|&&b| b == 0).count()..];
+    let output = output
+        .get_mut(..zeros + bytes.len())
+        .ok_or(decoding::Error::BufferTooSmall {
+            required: zeros + bytes.len(),
+        })?;
+    let (leading, rest) = output.split_at_mut(zeros);
+    leading.fill(0);
+    rest.copy_from_slice(bytes);
+
+    Ok(zeros + bytes.len())
+}
@@ -0,0 +1,307 @@
+//! Helpers for serializing bytes as encoded strings with [`serde`](::serde).
+//!
+//! Each of the [`StaticAlphabet`] associated alphabets has a module for use with
+//! `#[serde(with = "...")]` on `Vec<u8>` or `[u8; N]` fields:
+//!
+//! ```rust
+//! #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+//! struct Key {
+//!     #[serde(with = "bsx::serde::bitcoin")]
+//!     id: [u8; 8],
+//!     #[serde(with = "bsx::serde::base62")]
+//!     data: Vec<u8>,
+//! }
+//!
+//! let key = Key {
+//!     id: [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+//!     data: vec![0xff; 4],
+//! };
+//! let json = r#"{"id":"he11owor1d","data":"4gfFC3"}"#;
+//! assert_eq!(json, serde_json::to_string(&key)?);
+//! assert_eq!(key, serde_json::from_str(json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Other alphabets can be used with the generic [`serialize`] and [`deserialize`] functions, by
+//! naming the alphabet with a type implementing [`AlphabetType`]:
+//!
+//! ```rust
+//! struct Decimal;
+//!
+//! impl bsx::serde::AlphabetType for Decimal {
+//!     const ALPHABET: &'static dyn bsx::Alphabet = &bsx::StaticAlphabet::new_unwrap(b"0123456789");
+//! }
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Value {
+//!     #[serde(
+//!         serialize_with = "bsx::serde::serialize::<Decimal, _, _>",
+//!         deserialize_with = "bsx::serde::deserialize::<Decimal, _, _>"
+//!     )]
+//!     bytes: Vec<u8>,
+//! }
+//!
+//! let value = Value { bytes: vec![0x01, 0x02] };
+//! assert_eq!(r#"{"bytes":"258"}"#, serde_json::to_string(&value)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Alternatively the [`Encoded`] wrapper type expresses the encoding in the field's type.
+
+use alloc::string::String;
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use ::serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{decode::FromEncoded, Alphabet, StaticAlphabet};
+
+/// Names an alphabet at the type level, so it can be used as a type parameter.
+pub trait AlphabetType {
+    /// The alphabet named by this type.
+    const ALPHABET: &'static dyn Alphabet;
+}
+
+/// Bytes that are serialized as a string encoded with the alphabet named by `A`.
+///
+/// This dereferences to the wrapped bytes, and also uses the encoded string for its
+/// [`Debug`](fmt::Debug) and [`Display`](fmt::Display) implementations.
+///
+/// # Examples
+///
+/// ```rust
+/// use bsx::serde::{Bitcoin, Encoded};
+///
+/// #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+/// struct Key {
+///     id: Encoded<Bitcoin, [u8; 8]>,
+/// }
+///
+/// let key = Key {
+///     id: Encoded::new([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58]),
+/// };
+/// let json = r#"{"id":"he11owor1d"}"#;
+/// assert_eq!(json, serde_json::to_string(&key)?);
+/// assert_eq!(key, serde_json::from_str(json)?);
+/// assert_eq!("Key { id: Encoded(\"he11owor1d\") }", format!("{:?}", key));
+///
+/// let err = serde_json::from_str::<Key>(r#"{"id":"2g"}"#).unwrap_err();
+/// assert!(err.to_string().starts_with("provided string did not decode to exactly 8 bytes"));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[repr(transparent)]
+pub struct Encoded<A, T> {
+    value: T,
+    alpha: PhantomData<fn() -> A>,
+}
+
+impl<A, T> Encoded<A, T> {
+    /// Wrap `value`.
+    pub const fn new(value: T) -> Self {
+        Encoded {
+            value,
+            alpha: PhantomData,
+        }
+    }
+
+    /// Unwrap the bytes.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<A, T> From<T> for Encoded<A, T> {
+    fn from(value: T) -> Self {
+        Encoded::new(value)
+    }
+}
+
+impl<A, T> Deref for Encoded<A, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<A, T> DerefMut for Encoded<A, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<A, T: AsRef<[u8]>> AsRef<[u8]> for Encoded<A, T> {
+    fn as_ref(&self) -> &[u8] {
+        self.value.as_ref()
+    }
+}
+
+impl<A, T: Clone> Clone for Encoded<A, T> {
+    fn clone(&self) -> Self {
+        Encoded::new(self.value.clone())
+    }
+}
+
+impl<A, T: Copy> Copy for Encoded<A, T> {}
+
+impl<A, T: Default> Default for Encoded<A, T> {
+    fn default() -> Self {
+        Encoded::new(T::default())
+    }
+}
+
+impl<A, T: PartialEq> PartialEq for Encoded<A, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<A, T: Eq> Eq for Encoded<A, T> {}
+
+impl<A, T: Hash> Hash for Encoded<A, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<A: AlphabetType, T: AsRef<[u8]>> fmt::Debug for Encoded<A, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Encoded")
+            .field(&format_args!("\"{}\"", self))
+            .finish()
+    }
+}
+
+impl<A: AlphabetType, T: AsRef<[u8]>> fmt::Display for Encoded<A, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::encode(&self.value)
+            .with_alphabet(A::ALPHABET)
+            .into_fmt(f)
+            .map_err(|_| fmt::Error)?;
+        Ok(())
+    }
+}
+
+impl<A: AlphabetType, T: AsRef<[u8]>> Serialize for Encoded<A, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize::<A, T, S>(&self.value, serializer)
+    }
+}
+
+impl<'de, A: AlphabetType, T: FromEncoded> Deserialize<'de> for Encoded<A, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize::<A, T, D>(deserializer).map(Encoded::new)
+    }
+}
+
+/// Serialize bytes as a string encoded with the alphabet named by `A`.
+pub fn serialize<A, T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    A: AlphabetType,
+    T: AsRef<[u8]> + ?Sized,
+    S: Serializer,
+{
+    serialize_with(value.as_ref(), A::ALPHABET, serializer)
+}
+
+/// Deserialize bytes from a string encoded with the alphabet named by `A`.
+pub fn deserialize<'de, A, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    A: AlphabetType,
+    T: FromEncoded,
+    D: Deserializer<'de>,
+{
+    deserialize_with(A::ALPHABET, deserializer)
+}
+
+fn serialize_with<S: Serializer>(
+    value: &[u8],
+    alpha: &dyn Alphabet,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut encoded = String::new();
+    crate::encode(value)
+        .with_alphabet(alpha)
+        .into(&mut encoded)
+        .map_err(ser::Error::custom)?;
+    serializer.serialize_str(&encoded)
+}
+
+fn deserialize_with<'de, T: FromEncoded, D: Deserializer<'de>>(
+    alpha: &'static dyn Alphabet,
+    deserializer: D,
+) -> Result<T, D::Error> {
+    struct Visitor<T>(&'static dyn Alphabet, PhantomData<T>);
+
+    impl<'de, T: FromEncoded> de::Visitor<'de> for Visitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an encoded string")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            T::from_encoded(v, self.0).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(Visitor(alpha, PhantomData))
+}
+
+macro_rules! alphabets {
+    ($($module:ident, $ty:ident => $alpha:ident;)*) => {$(
+        #[doc = concat!("Names the [`StaticAlphabet::", stringify!($alpha), "`] alphabet at the type level.")]
+        #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+        pub struct $ty;
+
+        impl AlphabetType for $ty {
+            const ALPHABET: &'static dyn Alphabet = StaticAlphabet::$alpha;
+        }
+
+        #[doc = concat!("Serialize bytes as strings encoded with the [`StaticAlphabet::", stringify!($alpha), "`] alphabet.")]
+        pub mod $module {
+            use super::*;
+
+            #[doc = concat!("Serialize bytes as a string encoded with the [`StaticAlphabet::", stringify!($alpha), "`] alphabet.")]
+            pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: AsRef<[u8]> + ?Sized,
+                S: Serializer,
+            {
+                super::serialize::<$ty, T, S>(value, serializer)
+            }
+
+            #[doc = concat!("Deserialize bytes from a string encoded with the [`StaticAlphabet::", stringify!($alpha), "`] alphabet.")]
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+            where
+                T: FromEncoded,
+                D: Deserializer<'de>,
+            {
+                super::deserialize::<$ty, T, D>(deserializer)
+            }
+        }
+    )*};
+}
+
+alphabets! {
+    bitcoin, Bitcoin => BITCOIN;
+    monero, Monero => MONERO;
+    ripple, Ripple => RIPPLE;
+    flickr, Flickr => FLICKR;
+    crockford, Crockford => CROCKFORD;
+    zbase32, Zbase32 => ZBASE32;
+    base32hex, Base32Hex => BASE32HEX;
+    geohash, Geohash => GEOHASH;
+    bech32, Bech32 => BECH32;
+    base16, Base16 => BASE16;
+    base16_upper, Base16Upper => BASE16_UPPER;
+    base36, Base36 => BASE36;
+    base45, Base45 => BASE45;
+    base62, Base62 => BASE62;
+    base64, Base64 => BASE64;
+    base64url, Base64Url => BASE64URL;
+}
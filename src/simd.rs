@@ -0,0 +1,432 @@
+//! The per-character table lookups done while encoding and decoding.
+//!
+//! With the `portable-simd` feature these use a `core::simd` implementation, and with the `simd`
+//! feature they use vectorized implementations selected at compile time from the enabled target
+//! features (AVX2 or SSSE3 on x86, NEON on aarch64). The scalar implementations handle any
+//! remaining tail, and are used alone when neither feature applies.
+
+/// Translate each digit in `digits` through `table` and reverse their order, every digit must be
+/// a valid index into `table`.
+pub(crate) fn translate_reverse(digits: &mut [u8], table: &[u8]) {
+    // The vectorized lookups only support tables of up to 128 entries
+    if table.len() > 128 {
+        return translate_reverse_scalar(digits, table);
+    }
+
+    #[cfg(feature = "portable-simd")]
+    let digits = portable::translate_reverse(digits, table);
+
+    #[cfg(all(
+        feature = "simd",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    let digits = avx2::translate_reverse(digits, table);
+
+    #[cfg(all(
+        feature = "simd",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "ssse3"
+    ))]
+    let digits = ssse3::translate_reverse(digits, table);
+
+    #[cfg(all(feature = "simd", target_arch = "aarch64", target_feature = "neon"))]
+    let digits = neon::translate_reverse(digits, table);
+
+    translate_reverse_scalar(digits, table)
+}
+
+fn translate_reverse_scalar(digits: &mut [u8], table: &[u8]) {
+    for digit in &mut *digits {
+        *digit = table[*digit as usize];
+    }
+    digits.reverse();
+}
+
+/// Translate each character of `input` through the ASCII half of a decoding table into `output`,
+/// which must be the same length, returns `false` if any character was non-ASCII or not part of
+/// the alphabet.
+pub(crate) fn decode(input: &[u8], output: &mut [u8], table: &[u8]) -> bool {
+    debug_assert_eq!(input.len(), output.len());
+    debug_assert!(table.len() <= 128);
+
+    #[cfg(feature = "portable-simd")]
+    let (input, output) = match portable::decode(input, output, table) {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    #[cfg(all(
+        feature = "simd",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    let (input, output) = match avx2::decode(input, output, table) {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    #[cfg(all(
+        feature = "simd",
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "ssse3"
+    ))]
+    let (input, output) = match ssse3::decode(input, output, table) {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    #[cfg(all(feature = "simd", target_arch = "aarch64", target_feature = "neon"))]
+    let (input, output) = match neon::decode(input, output, table) {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    input.iter().zip(output).all(|(&c, digit)| {
+        *digit = table.get(c as usize).copied().unwrap_or(0xFF);
+        *digit != 0xFF
+    })
+}
+
+/// Copy a table of up to 128 entries into a zero padded array.
+#[cfg(any(feature = "simd", feature = "portable-simd"))]
+#[allow(dead_code)] // unused on targets without a vectorized implementation
+fn pad_table(table: &[u8]) -> [u8; 128] {
+    let mut padded = [0; 128];
+    padded[..table.len()].copy_from_slice(table);
+    padded
+}
+
+#[cfg(feature = "portable-simd")]
+mod portable {
+    use core::simd::prelude::*;
+
+    type Table = [u8x16; 8];
+
+    fn load_table(table: &[u8]) -> Table {
+        let padded = super::pad_table(table);
+        let mut parts = [u8x16::splat(0); 8];
+        for (part, chunk) in parts.iter_mut().zip(padded.chunks_exact(16)) {
+            *part = u8x16::from_slice(chunk);
+        }
+        parts
+    }
+
+    /// Lookup each index in the 128 entry `table`, indexes of 128 or more give zero.
+    #[inline(always)]
+    fn lookup(table: &Table, indexes: u8x16) -> u8x16 {
+        // Dynamic swizzles give zero for out of range indexes, so offsetting the indexes for each
+        // part of the table selects only the lanes that fall within that part
+        let mut result = u8x16::splat(0);
+        for (i, part) in table.iter().enumerate() {
+            result |= part.swizzle_dyn(indexes - u8x16::splat(16 * i as u8));
+        }
+        result
+    }
+
+    /// Translate and reverse blocks from both ends of `digits`, returning the middle that is left.
+    pub(super) fn translate_reverse<'a>(mut digits: &'a mut [u8], table: &[u8]) -> &'a mut [u8] {
+        let table = load_table(table);
+        while digits.len() >= 32 {
+            let (front, rest) = { digits }.split_at_mut(16);
+            let (middle, back) = rest.split_at_mut(rest.len() - 16);
+            let first = u8x16::from_slice(front);
+            let last = u8x16::from_slice(back);
+            lookup(&table, last.reverse()).copy_to_slice(front);
+            lookup(&table, first.reverse()).copy_to_slice(back);
+            digits = middle;
+        }
+        digits
+    }
+
+    /// Decode whole blocks of `input`, returning the remaining input and output, or `None` if an
+    /// invalid character was seen.
+    pub(super) fn decode<'a, 'b>(
+        input: &'a [u8],
+        output: &'b mut [u8],
+        table: &[u8],
+    ) -> Option<(&'a [u8], &'b mut [u8])> {
+        let table = load_table(table);
+        let mut input = input.chunks_exact(16);
+        let mut output = output.chunks_exact_mut(16);
+        for (chars, digits) in (&mut input).zip(&mut output) {
+            let chars = u8x16::from_slice(chars);
+            let values = lookup(&table, chars);
+            let invalid = chars.simd_ge(u8x16::splat(128)) | values.simd_eq(u8x16::splat(0xFF));
+            if invalid.any() {
+                return None;
+            }
+            values.copy_to_slice(digits);
+        }
+        Some((input.remainder(), output.into_remainder()))
+    }
+}
+
+#[cfg(all(
+    feature = "simd",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "ssse3"
+))]
+mod ssse3 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    type Table = [__m128i; 8];
+
+    fn load_table(table: &[u8]) -> Table {
+        let padded = super::pad_table(table);
+        let mut parts = [unsafe { _mm_setzero_si128() }; 8];
+        for (part, chunk) in parts.iter_mut().zip(padded.chunks_exact(16)) {
+            // SAFETY: `chunk` is 16 bytes long, and `loadu` has no alignment requirement
+            *part = unsafe { _mm_loadu_si128(chunk.as_ptr().cast()) };
+        }
+        parts
+    }
+
+    /// Lookup each index in the 128 entry `table`, indexes of 128 or more give zero.
+    #[inline(always)]
+    unsafe fn lookup(table: &Table, indexes: __m128i) -> __m128i {
+        // `pshufb` only uses the low nibble of each index, so do one lookup for each possible
+        // high nibble and keep the lanes that match
+        let high = _mm_and_si128(_mm_srli_epi16(indexes, 4), _mm_set1_epi8(0x0F));
+        let mut result = _mm_setzero_si128();
+        for (i, part) in table.iter().enumerate() {
+            let selected = _mm_cmpeq_epi8(high, _mm_set1_epi8(i as i8));
+            let values = _mm_shuffle_epi8(*part, indexes);
+            result = _mm_or_si128(result, _mm_and_si128(values, selected));
+        }
+        result
+    }
+
+    #[inline(always)]
+    unsafe fn reverse(value: __m128i) -> __m128i {
+        _mm_shuffle_epi8(
+            value,
+            _mm_set_epi8(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15),
+        )
+    }
+
+    /// Translate and reverse blocks from both ends of `digits`, returning the middle that is left.
+    pub(super) fn translate_reverse<'a>(mut digits: &'a mut [u8], table: &[u8]) -> &'a mut [u8] {
+        let table = load_table(table);
+        while digits.len() >= 32 {
+            let (front, rest) = { digits }.split_at_mut(16);
+            let (middle, back) = rest.split_at_mut(rest.len() - 16);
+            // SAFETY: `front` and `back` are both 16 bytes long, and `loadu`/`storeu` have no
+            // alignment requirement
+            unsafe {
+                let first = _mm_loadu_si128(front.as_ptr().cast());
+                let last = _mm_loadu_si128(back.as_ptr().cast());
+                _mm_storeu_si128(front.as_mut_ptr().cast(), lookup(&table, reverse(last)));
+                _mm_storeu_si128(back.as_mut_ptr().cast(), lookup(&table, reverse(first)));
+            }
+            digits = middle;
+        }
+        digits
+    }
+
+    /// Decode whole blocks of `input`, returning the remaining input and output, or `None` if an
+    /// invalid character was seen.
+    pub(super) fn decode<'a, 'b>(
+        input: &'a [u8],
+        output: &'b mut [u8],
+        table: &[u8],
+    ) -> Option<(&'a [u8], &'b mut [u8])> {
+        let table = load_table(table);
+        let mut input = input.chunks_exact(16);
+        let mut output = output.chunks_exact_mut(16);
+        for (chars, digits) in (&mut input).zip(&mut output) {
+            // SAFETY: `chars` and `digits` are both 16 bytes long, and `loadu`/`storeu` have no
+            // alignment requirement
+            unsafe {
+                let chars = _mm_loadu_si128(chars.as_ptr().cast());
+                let values = lookup(&table, chars);
+                // The top bit is set for non-ASCII characters and those that mapped to `0xFF`
+                let invalid = _mm_or_si128(chars, _mm_cmpeq_epi8(values, _mm_set1_epi8(-1)));
+                if _mm_movemask_epi8(invalid) != 0 {
+                    return None;
+                }
+                _mm_storeu_si128(digits.as_mut_ptr().cast(), values);
+            }
+        }
+        Some((input.remainder(), output.into_remainder()))
+    }
+}
+
+#[cfg(all(
+    feature = "simd",
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "avx2"
+))]
+mod avx2 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    type Table = [__m256i; 8];
+
+    fn load_table(table: &[u8]) -> Table {
+        let padded = super::pad_table(table);
+        let mut parts = [unsafe { _mm256_setzero_si256() }; 8];
+        for (part, chunk) in parts.iter_mut().zip(padded.chunks_exact(16)) {
+            // SAFETY: `chunk` is 16 bytes long, and `loadu` has no alignment requirement
+            *part = unsafe { _mm256_broadcastsi128_si256(_mm_loadu_si128(chunk.as_ptr().cast())) };
+        }
+        parts
+    }
+
+    /// Lookup each index in the 128 entry `table`, indexes of 128 or more give zero.
+    #[inline(always)]
+    unsafe fn lookup(table: &Table, indexes: __m256i) -> __m256i {
+        // `vpshufb` only uses the low nibble of each index within each lane, so do one lookup
+        // for each possible high nibble and keep the lanes that match
+        let high = _mm256_and_si256(_mm256_srli_epi16(indexes, 4), _mm256_set1_epi8(0x0F));
+        let mut result = _mm256_setzero_si256();
+        for (i, part) in table.iter().enumerate() {
+            let selected = _mm256_cmpeq_epi8(high, _mm256_set1_epi8(i as i8));
+            let values = _mm256_shuffle_epi8(*part, indexes);
+            result = _mm256_or_si256(result, _mm256_and_si256(values, selected));
+        }
+        result
+    }
+
+    #[inline(always)]
+    unsafe fn reverse(value: __m256i) -> __m256i {
+        let reversed_lanes = _mm256_shuffle_epi8(
+            value,
+            _mm256_set_epi8(
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9,
+                10, 11, 12, 13, 14, 15,
+            ),
+        );
+        _mm256_permute4x64_epi64(reversed_lanes, 0x4E)
+    }
+
+    /// Translate and reverse blocks from both ends of `digits`, returning the middle that is left.
+    pub(super) fn translate_reverse<'a>(mut digits: &'a mut [u8], table: &[u8]) -> &'a mut [u8] {
+        let table = load_table(table);
+        while digits.len() >= 64 {
+            let (front, rest) = { digits }.split_at_mut(32);
+            let (middle, back) = rest.split_at_mut(rest.len() - 32);
+            // SAFETY: `front` and `back` are both 32 bytes long, and `loadu`/`storeu` have no
+            // alignment requirement
+            unsafe {
+                let first = _mm256_loadu_si256(front.as_ptr().cast());
+                let last = _mm256_loadu_si256(back.as_ptr().cast());
+                _mm256_storeu_si256(front.as_mut_ptr().cast(), lookup(&table, reverse(last)));
+                _mm256_storeu_si256(back.as_mut_ptr().cast(), lookup(&table, reverse(first)));
+            }
+            digits = middle;
+        }
+        digits
+    }
+
+    /// Decode whole blocks of `input`, returning the remaining input and output, or `None` if an
+    /// invalid character was seen.
+    pub(super) fn decode<'a, 'b>(
+        input: &'a [u8],
+        output: &'b mut [u8],
+        table: &[u8],
+    ) -> Option<(&'a [u8], &'b mut [u8])> {
+        let table = load_table(table);
+        let mut input = input.chunks_exact(32);
+        let mut output = output.chunks_exact_mut(32);
+        for (chars, digits) in (&mut input).zip(&mut output) {
+            // SAFETY: `chars` and `digits` are both 32 bytes long, and `loadu`/`storeu` have no
+            // alignment requirement
+            unsafe {
+                let chars = _mm256_loadu_si256(chars.as_ptr().cast());
+                let values = lookup(&table, chars);
+                // The top bit is set for non-ASCII characters and those that mapped to `0xFF`
+                let invalid =
+                    _mm256_or_si256(chars, _mm256_cmpeq_epi8(values, _mm256_set1_epi8(-1)));
+                if _mm256_movemask_epi8(invalid) != 0 {
+                    return None;
+                }
+                _mm256_storeu_si256(digits.as_mut_ptr().cast(), values);
+            }
+        }
+        Some((input.remainder(), output.into_remainder()))
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use core::arch::aarch64::*;
+
+    type Table = (uint8x16x4_t, uint8x16x4_t);
+
+    fn load_table(table: &[u8]) -> Table {
+        let padded = super::pad_table(table);
+        // SAFETY: `padded` is 128 bytes long, so has 64 bytes at both offsets
+        unsafe {
+            (
+                vld1q_u8_x4(padded.as_ptr()),
+                vld1q_u8_x4(padded.as_ptr().add(64)),
+            )
+        }
+    }
+
+    /// Lookup each index in the 128 entry `table`, indexes of 128 or more give zero.
+    #[inline(always)]
+    unsafe fn lookup(table: &Table, indexes: uint8x16_t) -> uint8x16_t {
+        // `tbl` gives zero for out of range indexes, then `tbx` leaves them unchanged
+        let low = vqtbl4q_u8(table.0, indexes);
+        vqtbx4q_u8(low, table.1, vsubq_u8(indexes, vdupq_n_u8(64)))
+    }
+
+    #[inline(always)]
+    unsafe fn reverse(value: uint8x16_t) -> uint8x16_t {
+        let reversed_halves = vrev64q_u8(value);
+        vextq_u8(reversed_halves, reversed_halves, 8)
+    }
+
+    /// Translate and reverse blocks from both ends of `digits`, returning the middle that is left.
+    pub(super) fn translate_reverse<'a>(mut digits: &'a mut [u8], table: &[u8]) -> &'a mut [u8] {
+        let table = load_table(table);
+        while digits.len() >= 32 {
+            let (front, rest) = { digits }.split_at_mut(16);
+            let (middle, back) = rest.split_at_mut(rest.len() - 16);
+            // SAFETY: `front` and `back` are both 16 bytes long
+            unsafe {
+                let first = vld1q_u8(front.as_ptr());
+                let last = vld1q_u8(back.as_ptr());
+                vst1q_u8(front.as_mut_ptr(), lookup(&table, reverse(last)));
+                vst1q_u8(back.as_mut_ptr(), lookup(&table, reverse(first)));
+            }
+            digits = middle;
+        }
+        digits
+    }
+
+    /// Decode whole blocks of `input`, returning the remaining input and output, or `None` if an
+    /// invalid character was seen.
+    pub(super) fn decode<'a, 'b>(
+        input: &'a [u8],
+        output: &'b mut [u8],
+        table: &[u8],
+    ) -> Option<(&'a [u8], &'b mut [u8])> {
+        let table = load_table(table);
+        let mut input = input.chunks_exact(16);
+        let mut output = output.chunks_exact_mut(16);
+        for (chars, digits) in (&mut input).zip(&mut output) {
+            // SAFETY: `chars` and `digits` are both 16 bytes long
+            unsafe {
+                let chars = vld1q_u8(chars.as_ptr());
+                let values = lookup(&table, chars);
+                let invalid = vorrq_u8(
+                    vcgeq_u8(chars, vdupq_n_u8(128)),
+                    vceqq_u8(values, vdupq_n_u8(0xFF)),
+                );
+                if vmaxvq_u8(invalid) != 0 {
+                    return None;
+                }
+                vst1q_u8(digits.as_mut_ptr(), values);
+            }
+        }
+        Some((input.remainder(), output.into_remainder()))
+    }
+}
@@ -0,0 +1,379 @@
+//! Support for [Sqids](https://sqids.org/), short obfuscated IDs generated from lists of numbers.
+//!
+//! The output is compatible with the reference implementations for the same alphabet, minimum
+//! length and blocklist. The reference default blocklist is not bundled, so by default no IDs are
+//! blocked; pass the same words to [`Sqids::with_blocklist`] to match its output exactly.
+//!
+//! ```rust
+//! let sqids = bsx::sqids::Sqids::new();
+//! assert_eq!("86Rf07", sqids.encode([1, 2, 3])?);
+//! assert_eq!(vec![1, 2, 3], sqids.decode("86Rf07")?);
+//! # Ok::<(), bsx::sqids::Error>(())
+//! ```
+
+use core::fmt;
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{alphabet, DynamicAlphabet};
+
+/// The default Sqids alphabet, before shuffling.
+pub const DEFAULT_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// A specialized [`Result`](core::result::Result) type for [`bsx::sqids`](module@crate::sqids)
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Errors that could occur when configuring Sqids, or encoding or decoding an ID.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The alphabet was invalid.
+    Alphabet(alphabet::Error),
+
+    /// The alphabet had fewer than 3 characters.
+    AlphabetTooShort,
+
+    /// Every attempt to generate an ID contained a blocked word.
+    Blocked,
+
+    /// The input contained a character that was not part of the alphabet.
+    InvalidCharacter {
+        /// The unexpected character.
+        character: char,
+        /// The (byte) index in the input string the character was at.
+        index: usize,
+    },
+
+    /// The input contained a multi-byte (or non-utf8) character.
+    NonAsciiCharacter {
+        /// The (byte) index in the input string the start of the character was at.
+        index: usize,
+    },
+
+    /// A number in the input was too large to fit in a `u64`.
+    Overflow {
+        /// The (byte) index in the input string the number started at.
+        index: usize,
+    },
+}
+
+/// A configured Sqids codec.
+#[derive(Clone, Debug)]
+pub struct Sqids {
+    /// The shuffled alphabet.
+    alphabet: Vec<u8>,
+    min_length: u8,
+    /// Lowercased words of at least 3 characters.
+    blocklist: Vec<String>,
+}
+
+/// The Sqids shuffle, a deterministic permutation depending only on the alphabet.
+fn shuffle(alphabet: &mut [u8]) {
+    let len = alphabet.len();
+    for (i, j) in (1..len).rev().enumerate() {
+        let r = (i * j + usize::from(alphabet[i]) + usize::from(alphabet[j])) % len;
+        alphabet.swap(i, r);
+    }
+}
+
+impl Sqids {
+    /// Create a codec with the default alphabet, no minimum length and an empty blocklist.
+    pub fn new() -> Self {
+        let mut alphabet = DEFAULT_ALPHABET.as_bytes().to_vec();
+        shuffle(&mut alphabet);
+        Sqids {
+            alphabet,
+            min_length: 0,
+            blocklist: Vec::new(),
+        }
+    }
+
+    /// Use a different alphabet, it must be at least 3 ASCII characters with no duplicates.
+    ///
+    /// ```rust
+    /// let sqids = bsx::sqids::Sqids::new().with_alphabet("0123456789abcdef")?;
+    /// assert_eq!("489158", sqids.encode([1, 2, 3])?);
+    /// # Ok::<(), bsx::sqids::Error>(())
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// ### Invalid Alphabet
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     bsx::sqids::Error::Alphabet(bsx::alphabet::Error::DuplicateCharacter {
+    ///         character: 'a', first: 0, second: 3 }),
+    ///     bsx::sqids::Sqids::new().with_alphabet("abca").unwrap_err());
+    /// assert_eq!(
+    ///     bsx::sqids::Error::AlphabetTooShort,
+    ///     bsx::sqids::Sqids::new().with_alphabet("ab").unwrap_err());
+    /// ```
+    pub fn with_alphabet(self, alphabet: impl AsRef<[u8]>) -> Result<Self> {
+        let alphabet = alphabet.as_ref();
+        DynamicAlphabet::new(alphabet).map_err(Error::Alphabet)?;
+        if alphabet.len() < 3 {
+            return Err(Error::AlphabetTooShort);
+        }
+        let mut alphabet = alphabet.to_vec();
+        shuffle(&mut alphabet);
+        Ok(Sqids { alphabet, ..self })
+    }
+
+    /// Pad generated IDs to at least `min_length` characters.
+    ///
+    /// ```rust
+    /// let sqids = bsx::sqids::Sqids::new().with_min_length(10);
+    /// assert_eq!("86Rf07xd4z", sqids.encode([1, 2, 3])?);
+    /// assert_eq!(vec![1, 2, 3], sqids.decode("86Rf07xd4z")?);
+    /// # Ok::<(), bsx::sqids::Error>(())
+    /// ```
+    pub fn with_min_length(self, min_length: u8) -> Self {
+        Sqids { min_length, ..self }
+    }
+
+    /// Avoid generating IDs containing these words, compared case-insensitively.
+    ///
+    /// Words shorter than 3 characters are ignored, words of 3 characters or containing digits
+    /// only block IDs that are equal to, or start or end with, them respectively.
+    ///
+    /// ```rust
+    /// let sqids = bsx::sqids::Sqids::new().with_blocklist(["86Rf07"]);
+    /// assert_eq!("se8ojk", sqids.encode([1, 2, 3])?);
+    /// assert_eq!(vec![1, 2, 3], sqids.decode("se8ojk")?);
+    /// # Ok::<(), bsx::sqids::Error>(())
+    /// ```
+    pub fn with_blocklist<I>(self, words: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let blocklist = words
+            .into_iter()
+            .filter(|word| word.as_ref().len() >= 3)
+            .map(|word| word.as_ref().to_lowercase())
+            .collect();
+        Sqids { blocklist, ..self }
+    }
+
+    /// Encode a list of numbers into an ID, an empty list encodes to an empty ID.
+    ///
+    /// ```rust
+    /// let sqids = bsx::sqids::Sqids::new();
+    /// assert_eq!("bM", sqids.encode([0])?);
+    /// assert_eq!("", sqids.encode([])?);
+    /// # Ok::<(), bsx::sqids::Error>(())
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// ### Blocked
+    ///
+    /// If every regeneration attempt is blocked.
+    ///
+    /// ```rust
+    /// let sqids = bsx::sqids::Sqids::new()
+    ///     .with_alphabet("abc")?
+    ///     .with_min_length(3)
+    ///     .with_blocklist(["cab", "abc", "bca"]);
+    /// assert_eq!(bsx::sqids::Error::Blocked, sqids.encode([0]).unwrap_err());
+    /// # Ok::<(), bsx::sqids::Error>(())
+    /// ```
+    pub fn encode(&self, numbers: impl AsRef<[u64]>) -> Result<String> {
+        let numbers = numbers.as_ref();
+        if numbers.is_empty() {
+            return Ok(String::new());
+        }
+        let len = self.alphabet.len();
+        let offset = numbers
+            .iter()
+            .enumerate()
+            .fold(numbers.len(), |offset, (i, &n)| {
+                offset + usize::from(self.alphabet[(n % len as u64) as usize]) + i
+            });
+        (0..=len)
+            .map(|increment| self.encode_with_offset(numbers, (offset + increment) % len))
+            .find(|id| !self.is_blocked(id))
+            .ok_or(Error::Blocked)
+    }
+
+    fn encode_with_offset(&self, numbers: &[u64], offset: usize) -> String {
+        let mut alphabet = self.alphabet.clone();
+        alphabet.rotate_left(offset);
+        let mut id = alloc::vec![alphabet[0]];
+        alphabet.reverse();
+
+        for (i, &n) in numbers.iter().enumerate() {
+            let digits = &alphabet[1..];
+            let start = id.len();
+            let mut n = n;
+            loop {
+                id.push(digits[(n % digits.len() as u64) as usize]);
+                n /= digits.len() as u64;
+                if n == 0 {
+                    break;
+                }
+            }
+            id[start..].reverse();
+
+            if i < numbers.len() - 1 {
+                id.push(alphabet[0]);
+                shuffle(&mut alphabet);
+            }
+        }
+
+        let min_length = usize::from(self.min_length);
+        if id.len() < min_length {
+            id.push(alphabet[0]);
+            while id.len() < min_length {
+                shuffle(&mut alphabet);
+                let extra = (min_length - id.len()).min(alphabet.len());
+                id.extend_from_slice(&alphabet[..extra]);
+            }
+        }
+
+        // The alphabet is pure ASCII so this is always valid
+        String::from_utf8(id).unwrap()
+    }
+
+    fn is_blocked(&self, id: &str) -> bool {
+        let id = id.to_ascii_lowercase();
+        self.blocklist.iter().any(|word| {
+            if word.len() > id.len() {
+                false
+            } else if id.len() <= 3 || word.len() <= 3 {
+                id == *word
+            } else if word.bytes().any(|c| c.is_ascii_digit()) {
+                id.starts_with(&**word) || id.ends_with(&**word)
+            } else {
+                id.contains(&**word)
+            }
+        })
+    }
+
+    /// Decode an ID back into its list of numbers.
+    ///
+    /// Any padding from [`Sqids::with_min_length`] is ignored, so different IDs can decode to the
+    /// same numbers, re-encode and compare if a canonical ID is required.
+    ///
+    /// ## Errors
+    ///
+    /// Unlike the reference implementations, which return an empty list for invalid IDs, these
+    /// are reported as errors.
+    ///
+    /// ### Invalid Character
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     bsx::sqids::Error::InvalidCharacter { character: '*', index: 2 },
+    ///     bsx::sqids::Sqids::new().decode("86*f07").unwrap_err());
+    /// ```
+    ///
+    /// ### Overflow
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     bsx::sqids::Error::Overflow { index: 1 },
+    ///     bsx::sqids::Sqids::new().decode("eIkvoXH40LmdI").unwrap_err());
+    /// ```
+    pub fn decode(&self, id: impl AsRef<[u8]>) -> Result<Vec<u64>> {
+        let id = id.as_ref();
+        for (index, &c) in id.iter().enumerate() {
+            if !self.alphabet.contains(&c) {
+                if c > 127 {
+                    return Err(Error::NonAsciiCharacter { index });
+                }
+                return Err(Error::InvalidCharacter {
+                    character: c as char,
+                    index,
+                });
+            }
+        }
+
+        let mut numbers = Vec::new();
+        let (&prefix, mut rest) = match id.split_first() {
+            Some(split) => split,
+            None => return Ok(numbers),
+        };
+
+        let mut alphabet = self.alphabet.clone();
+        let offset = alphabet.iter().position(|&c| c == prefix).unwrap();
+        alphabet.rotate_left(offset);
+        alphabet.reverse();
+
+        while !rest.is_empty() {
+            let separator = alphabet[0];
+            let (chunk, remaining) = match rest.iter().position(|&c| c == separator) {
+                Some(i) => (&rest[..i], Some(&rest[i + 1..])),
+                None => (rest, None),
+            };
+            // Padding starts with a separator, so an empty chunk marks the end of the numbers
+            if chunk.is_empty() {
+                break;
+            }
+
+            let index = id.len() - rest.len();
+            let digits = &alphabet[1..];
+            let mut n = 0u64;
+            for c in chunk {
+                let digit = digits.iter().position(|d| d == c).unwrap() as u64;
+                n = n
+                    .checked_mul(digits.len() as u64)
+                    .and_then(|n| n.checked_add(digit))
+                    .ok_or(Error::Overflow { index })?;
+            }
+            numbers.push(n);
+
+            if remaining.is_some() {
+                shuffle(&mut alphabet);
+            }
+            rest = remaining.unwrap_or(&[]);
+        }
+
+        Ok(numbers)
+    }
+}
+
+impl Default for Sqids {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Alphabet(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::Alphabet(_) => write!(f, "provided alphabet was invalid"),
+            Error::AlphabetTooShort => {
+                write!(f, "provided alphabet had fewer than 3 characters")
+            }
+            Error::Blocked => write!(f, "every generated id contained a blocked word"),
+            Error::InvalidCharacter { character, index } => write!(
+                f,
+                "provided string contained invalid character {:?} at byte {}",
+                character, index
+            ),
+            Error::NonAsciiCharacter { index } => write!(
+                f,
+                "provided string contained non-ascii character starting at byte {}",
+                index
+            ),
+            Error::Overflow { index } => write!(
+                f,
+                "provided string contained a number too large for a u64 at byte {}",
+                index
+            ),
+        }
+    }
+}
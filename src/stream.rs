@@ -0,0 +1,797 @@
+//! Streaming conversions for power-of-two bases.
+//!
+//! With a base of `2^bits` every `lcm(8, bits)` bits of the value map to a fixed number of
+//! digits, so the input can be encoded a unit at a time using constant memory. Units are aligned
+//! from the least significant end of the value, so for bases where `bits` doesn't divide 8 (such
+//! as base32 and base64) the total input length must be known before starting, see
+//! [`EncodeReaderBuilder::with_input_len`]. Bit-packed alphabets such as
+//! [`StaticAlphabet::BASE64`](crate::StaticAlphabet::BASE64) align the units from the start
+//! instead, so never need the length.
+//!
+//! The output is identical to encoding the whole input at once with
+//! [`bsx::encode`](crate::encode()).
+//!
+//! With the `async` feature the [`EncodeWriter`], [`EncodeReader`], [`DecodeWriter`] and
+//! [`DecodeReader`] adapters implement the [`futures_io`] traits to convert data on the fly, for
+//! tokio types these can be combined with the `tokio_util::compat` module.
+//!
+//! ```rust
+//! let mut output = Vec::new();
+//! bsx::encode_reader(&b"hello world"[..])
+//!     .with_alphabet(bsx::StaticAlphabet::BASE16)
+//!     .into_writer(&mut output)?;
+//! assert_eq!(b"68656c6c6f20776f726c64", &*output);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::{io, vec, vec::Vec};
+
+#[cfg(feature = "async")]
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use crate::{alphabet::Unspecified, Alphabet};
+
+/// The number of bytes read from the input at a time.
+const CHUNK_LEN: usize = 8192;
+
+/// A builder for setting up the alphabet and output of a streaming encode.
+///
+/// See the documentation for [`bsx::encode_reader`](crate::encode_reader()) for a more
+/// high level view of how to use this.
+#[derive(Clone, Debug)]
+pub struct EncodeReaderBuilder<R, A> {
+    reader: R,
+    alpha: A,
+    len: Option<u64>,
+}
+
+/// An incremental conversion, used by the streaming adapters.
+pub(crate) trait Convert {
+    /// Convert the next part of the input, appending the output to `output`.
+    fn push(&mut self, input: &[u8], alpha: &impl Alphabet, output: &mut Vec<u8>)
+        -> io::Result<()>;
+
+    /// Check that the whole input was converted, appending any final partial unit of a
+    /// bit-packed alphabet to `output`.
+    fn finish(&mut self, alpha: &impl Alphabet, output: &mut Vec<u8>) -> io::Result<()>;
+}
+
+/// Incrementally encodes bytes with a power-of-two alphabet.
+pub(crate) struct Encoder {
+    bits: u32,
+    unit_bytes: usize,
+    unit_digits: usize,
+    /// The total input length, needed to align the units when they are more than one byte.
+    len: Option<u64>,
+    /// Whether the units are aligned from the start, as for a bit-packed alphabet.
+    packed: bool,
+    /// The number of bytes seen so far.
+    seen: u64,
+    /// Whether all the bytes seen so far have been zero.
+    zeros: bool,
+    /// Whether a non-zero digit has been output, until then zero digits are skipped.
+    significant: bool,
+    value: u64,
+    filled: usize,
+}
+
+impl Encoder {
+    pub(crate) fn new(alpha: &impl Alphabet, len: Option<u64>) -> io::Result<Self> {
+        let (base, packed) = (alpha.len(), alpha.bit_packed());
+        if base < 2 || !base.is_power_of_two() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "streaming encode requires a power of two alphabet",
+            ));
+        }
+        let bits = base.trailing_zeros();
+        let (unit_bytes, unit_digits) = crate::pow2_unit(bits);
+        if unit_bytes > 1 && len.is_none() && !packed {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "streaming encode requires the input length for alphabets not aligned to bytes",
+            ));
+        }
+        Ok(Encoder {
+            bits,
+            unit_bytes,
+            unit_digits,
+            len,
+            packed,
+            seen: 0,
+            zeros: !packed,
+            significant: packed,
+            value: 0,
+            filled: 0,
+        })
+    }
+}
+
+impl Convert for Encoder {
+    fn push(
+        &mut self,
+        input: &[u8],
+        alpha: &impl Alphabet,
+        output: &mut Vec<u8>,
+    ) -> io::Result<()> {
+        let table = alpha.encode();
+        for &byte in input {
+            self.seen += 1;
+            if self.zeros {
+                if byte == 0 {
+                    output.push(table[0]);
+                    continue;
+                }
+                self.zeros = false;
+                // The partial unit at the start is converted as if it were zero padded
+                let rest = self.len.map_or(0, |len| len.saturating_sub(self.seen - 1));
+                let head = (rest % self.unit_bytes as u64) as usize;
+                self.filled = (self.unit_bytes - head) % self.unit_bytes;
+            }
+            self.value = (self.value << 8) | u64::from(byte);
+            self.filled += 1;
+            if self.filled == self.unit_bytes {
+                for i in (0..self.unit_digits as u32).rev() {
+                    let digit = (self.value >> (i * self.bits)) as usize & (table.len() - 1);
+                    self.significant |= digit != 0;
+                    if self.significant {
+                        output.push(table[digit]);
+                    }
+                }
+                self.value = 0;
+                self.filled = 0;
+            }
+        }
+        if self.len.is_some_and(|len| self.seen > len) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "input was longer than the given length",
+            ));
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self, alpha: &impl Alphabet, output: &mut Vec<u8>) -> io::Result<()> {
+        if self.len.is_some_and(|len| self.seen < len) {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "input was shorter than the given length",
+            ));
+        }
+        debug_assert!(self.packed || self.filled == 0);
+        if self.filled > 0 {
+            // The last unit is zero padded after the input, keeping only the digits it reaches
+            let table = alpha.encode();
+            let value = self.value << ((self.unit_bytes - self.filled) * 8);
+            let digits = (self.filled * 8).div_ceil(self.bits as usize);
+            for i in (self.unit_digits - digits..self.unit_digits).rev() {
+                let digit = (value >> (i as u32 * self.bits)) as usize & (table.len() - 1);
+                output.push(table[digit]);
+            }
+            self.value = 0;
+            self.filled = 0;
+        }
+        Ok(())
+    }
+}
+
+impl<R: io::Read> EncodeReaderBuilder<R, Unspecified> {
+    pub(crate) fn new(reader: R) -> Self {
+        EncodeReaderBuilder {
+            reader,
+            alpha: Unspecified,
+            len: None,
+        }
+    }
+}
+
+impl<R, A> EncodeReaderBuilder<R, A> {
+    /// Change the alphabet that will be used for encoding, it must have a power of two length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut output = Vec::new();
+    /// bsx::encode_reader(&[0x00, 0x01, 0xff][..])
+    ///     .with_alphabet(bsx::StaticAlphabet::BASE16_UPPER)
+    ///     .into_writer(&mut output)?;
+    /// assert_eq!(b"01FF", &*output);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn with_alphabet<B>(self, alpha: B) -> EncodeReaderBuilder<R, B> {
+        EncodeReaderBuilder {
+            reader: self.reader,
+            alpha,
+            len: self.len,
+        }
+    }
+
+    /// Provide the total length of the input, required for alphabets whose digits are not
+    /// aligned to bytes such as z-base-32, unless they are bit-packed. A file's length can be
+    /// taken from its metadata.
+    ///
+    /// If the reader returns a different number of bytes the encode fails with an
+    /// [`InvalidData`](io::ErrorKind::InvalidData) or
+    /// [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) error, after some of the output may
+    /// already have been written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let input = b"\0hello";
+    /// let mut output = Vec::new();
+    /// bsx::encode_reader(&input[..])
+    ///     .with_alphabet(bsx::StaticAlphabet::ZBASE32)
+    ///     .with_input_len(input.len() as u64)
+    ///     .into_writer(&mut output)?;
+    /// assert_eq!(b"ypb1sa5dx", &*output);
+    ///
+    /// let err = bsx::encode_reader(&input[..])
+    ///     .with_alphabet(bsx::StaticAlphabet::ZBASE32)
+    ///     .into_writer(&mut output)
+    ///     .unwrap_err();
+    /// assert_eq!(std::io::ErrorKind::InvalidInput, err.kind());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn with_input_len(mut self, len: u64) -> Self {
+        self.len = Some(len);
+        self
+    }
+}
+
+impl<R: io::Read, A: Alphabet> EncodeReaderBuilder<R, A> {
+    /// Encode the input into an [`io::Write`] sink, reading and writing in chunks.
+    ///
+    /// Returns the length written into the sink.
+    ///
+    /// # Errors
+    ///
+    /// Any error from the reader or writer, or an [`InvalidInput`](io::ErrorKind::InvalidInput)
+    /// error if the alphabet length is not a power of two, or it needs the input length and
+    /// [`with_input_len`](Self::with_input_len) wasn't used.
+    pub fn into_writer(mut self, mut output: impl io::Write) -> io::Result<u64> {
+        let mut encoder = Encoder::new(&self.alpha, self.len)?;
+        let mut input = vec![0; CHUNK_LEN];
+        let mut encoded = Vec::new();
+        let mut written = 0;
+        loop {
+            let len = match self.reader.read(&mut input) {
+                Ok(0) => break,
+                Ok(len) => len,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            encoder.push(&input[..len], &self.alpha, &mut encoded)?;
+            output.write_all(&encoded)?;
+            written += encoded.len() as u64;
+            encoded.clear();
+        }
+        encoder.finish(&self.alpha, &mut encoded)?;
+        output.write_all(&encoded)?;
+        written += encoded.len() as u64;
+        Ok(written)
+    }
+}
+
+/// Incrementally decodes characters with a power-of-two alphabet.
+#[cfg(feature = "async")]
+struct Decoder {
+    bits: u32,
+    unit_bytes: usize,
+    unit_digits: usize,
+    /// The total input length, needed to align the units when they are more than one digit.
+    len: Option<u64>,
+    /// Whether the units are aligned from the start, as for a bit-packed alphabet.
+    packed: bool,
+    /// The number of characters seen so far.
+    seen: u64,
+    /// Whether all the characters seen so far have been zero.
+    zeros: bool,
+    /// Whether a non-zero byte has been output, until then zero bytes are skipped.
+    significant: bool,
+    value: u64,
+    filled: usize,
+}
+
+#[cfg(feature = "async")]
+impl Decoder {
+    fn new(alpha: &impl Alphabet, len: Option<u64>) -> io::Result<Self> {
+        let (base, packed) = (alpha.len(), alpha.bit_packed());
+        if base < 2 || !base.is_power_of_two() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "streaming decode requires a power of two alphabet",
+            ));
+        }
+        let bits = base.trailing_zeros();
+        let (unit_bytes, unit_digits) = crate::pow2_unit(bits);
+        if unit_digits > 1 && len.is_none() && !packed {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "streaming decode requires the input length for alphabets not aligned to bytes",
+            ));
+        }
+        Ok(Decoder {
+            bits,
+            unit_bytes,
+            unit_digits,
+            len,
+            packed,
+            seen: 0,
+            zeros: !packed,
+            significant: packed,
+            value: 0,
+            filled: 0,
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+impl Convert for Decoder {
+    fn push(
+        &mut self,
+        input: &[u8],
+        alpha: &impl Alphabet,
+        output: &mut Vec<u8>,
+    ) -> io::Result<()> {
+        let table = alpha.decode();
+        for &c in input {
+            let digit = crate::decode::decode_digit(table, self.seen as usize, c)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            self.seen += 1;
+            if self.zeros {
+                if digit == 0 {
+                    output.push(0);
+                    continue;
+                }
+                self.zeros = false;
+                // The partial unit at the start is converted as if it were zero padded
+                let rest = self.len.map_or(0, |len| len.saturating_sub(self.seen - 1));
+                let head = (rest % self.unit_digits as u64) as usize;
+                self.filled = (self.unit_digits - head) % self.unit_digits;
+            }
+            self.value = (self.value << self.bits) | u64::from(digit);
+            self.filled += 1;
+            if self.filled == self.unit_digits {
+                for i in (0..self.unit_bytes as u32).rev() {
+                    let byte = (self.value >> (i * 8)) as u8;
+                    self.significant |= byte != 0;
+                    if self.significant {
+                        output.push(byte);
+                    }
+                }
+                self.value = 0;
+                self.filled = 0;
+            }
+        }
+        if self.len.is_some_and(|len| self.seen > len) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "input was longer than the given length",
+            ));
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self, _: &impl Alphabet, output: &mut Vec<u8>) -> io::Result<()> {
+        if self.len.is_some_and(|len| self.seen < len) {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "input was shorter than the given length",
+            ));
+        }
+        debug_assert!(self.packed || self.filled == 0);
+        if self.filled > 0 {
+            // Any bits left over after the last whole byte are discarded
+            let value = self.value << ((self.unit_digits - self.filled) * self.bits as usize);
+            let bytes = self.filled * self.bits as usize / 8;
+            for i in (self.unit_bytes - bytes..self.unit_bytes).rev() {
+                output.push((value >> (i * 8)) as u8);
+            }
+            self.value = 0;
+            self.filled = 0;
+        }
+        Ok(())
+    }
+}
+
+/// Converts everything written to it, buffering the output until it can be written to `inner`.
+#[cfg(feature = "async")]
+struct Writer<W, A, C> {
+    inner: W,
+    alpha: A,
+    convert: C,
+    buffer: Vec<u8>,
+    written: usize,
+    finished: bool,
+}
+
+#[cfg(feature = "async")]
+impl<W: futures_io::AsyncWrite + Unpin, A: Alphabet, C: Convert> Writer<W, A, C> {
+    fn new(inner: W, alpha: A, convert: C) -> Self {
+        Writer {
+            inner,
+            alpha,
+            convert,
+            buffer: Vec::new(),
+            written: 0,
+            finished: false,
+        }
+    }
+
+    fn poll_drain(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        while self.written < self.buffer.len() {
+            let len =
+                ready!(Pin::new(&mut self.inner).poll_write(cx, &self.buffer[self.written..]))?;
+            if len == 0 {
+                return Poll::Ready(Err(io::ErrorKind::WriteZero.into()));
+            }
+            self.written += len;
+        }
+        self.buffer.clear();
+        self.written = 0;
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_write(&mut self, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        ready!(self.poll_drain(cx))?;
+        let buf = &buf[..buf.len().min(CHUNK_LEN)];
+        self.convert.push(buf, &self.alpha, &mut self.buffer)?;
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.poll_drain(cx))?;
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        if !self.finished {
+            self.convert.finish(&self.alpha, &mut self.buffer)?;
+            self.finished = true;
+        }
+        ready!(self.poll_drain(cx))?;
+        Pin::new(&mut self.inner).poll_close(cx)
+    }
+}
+
+/// Converts everything read from `inner`, buffering the output until it is read.
+#[cfg(feature = "async")]
+struct Reader<R, A, C> {
+    inner: R,
+    alpha: A,
+    convert: C,
+    input: Vec<u8>,
+    buffer: Vec<u8>,
+    read: usize,
+    finished: bool,
+}
+
+#[cfg(feature = "async")]
+impl<R: futures_io::AsyncRead + Unpin, A: Alphabet, C: Convert> Reader<R, A, C> {
+    fn new(inner: R, alpha: A, convert: C) -> Self {
+        Reader {
+            inner,
+            alpha,
+            convert,
+            input: vec![0; CHUNK_LEN],
+            buffer: Vec::new(),
+            read: 0,
+            finished: false,
+        }
+    }
+
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        loop {
+            if self.read < self.buffer.len() || buf.is_empty() {
+                let len = buf.len().min(self.buffer.len() - self.read);
+                buf[..len].copy_from_slice(&self.buffer[self.read..][..len]);
+                self.read += len;
+                return Poll::Ready(Ok(len));
+            }
+            if self.finished {
+                return Poll::Ready(Ok(0));
+            }
+            self.buffer.clear();
+            self.read = 0;
+            let len = ready!(Pin::new(&mut self.inner).poll_read(cx, &mut self.input))?;
+            if len == 0 {
+                self.convert.finish(&self.alpha, &mut self.buffer)?;
+                self.finished = true;
+            } else {
+                self.convert
+                    .push(&self.input[..len], &self.alpha, &mut self.buffer)?;
+            }
+        }
+    }
+}
+
+/// Encodes the bytes written to it, writing the characters to the wrapped writer.
+///
+/// The encoding is checked and any buffered output written on
+/// [`poll_close`](futures_io::AsyncWrite::poll_close), which must be called once all the input has
+/// been written.
+///
+/// ```rust
+/// use futures::io::AsyncWriteExt;
+///
+/// # futures::executor::block_on(async {
+/// let mut writer = bsx::stream::EncodeWriter::new(Vec::new(), bsx::StaticAlphabet::BASE16)?;
+/// writer.write_all(b"hello").await?;
+/// writer.close().await?;
+/// assert_eq!(b"68656c6c6f", &*writer.into_inner());
+/// # Ok::<(), std::io::Error>(())
+/// # })?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[allow(missing_debug_implementations)]
+pub struct EncodeWriter<W, A> {
+    inner: Writer<W, A, Encoder>,
+}
+
+// The alphabet is never pinned, so the adapter only needs the wrapped stream to be `Unpin`
+#[cfg(feature = "async")]
+impl<W: Unpin, A> Unpin for EncodeWriter<W, A> {}
+
+#[cfg(feature = "async")]
+impl<W: futures_io::AsyncWrite + Unpin, A: Alphabet> EncodeWriter<W, A> {
+    /// Wrap `inner` to encode with `alpha`, which must have a power of two length whose digits
+    /// are aligned to bytes (base 2, 4, 16 or 256) or be bit-packed.
+    pub fn new(inner: W, alpha: A) -> io::Result<Self> {
+        let convert = Encoder::new(&alpha, None)?;
+        Ok(EncodeWriter {
+            inner: Writer::new(inner, alpha, convert),
+        })
+    }
+
+    /// Wrap `inner` to encode `len` bytes with `alpha`, which must have a power of two length.
+    pub fn with_len(inner: W, alpha: A, len: u64) -> io::Result<Self> {
+        let convert = Encoder::new(&alpha, Some(len))?;
+        Ok(EncodeWriter {
+            inner: Writer::new(inner, alpha, convert),
+        })
+    }
+
+    /// Unwrap this adapter, returning the underlying stream.
+    pub fn into_inner(self) -> W {
+        self.inner.inner
+    }
+}
+
+#[cfg(feature = "async")]
+impl<W: futures_io::AsyncWrite + Unpin, A: Alphabet> futures_io::AsyncWrite for EncodeWriter<W, A> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_mut().inner.poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().inner.poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().inner.poll_close(cx)
+    }
+}
+
+/// Encodes the bytes read from the wrapped reader, returning the characters.
+///
+/// ```rust
+/// use futures::io::AsyncReadExt;
+///
+/// # futures::executor::block_on(async {
+/// let input = b"\0hello";
+/// let mut reader = bsx::stream::EncodeReader::with_len(
+///     &input[..],
+///     bsx::StaticAlphabet::BASE64,
+///     input.len() as u64,
+/// )?;
+/// let mut output = String::new();
+/// reader.read_to_string(&mut output).await?;
+/// assert_eq!("AGhlbGxv", output);
+/// # Ok::<(), std::io::Error>(())
+/// # })?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[allow(missing_debug_implementations)]
+pub struct EncodeReader<R, A> {
+    inner: Reader<R, A, Encoder>,
+}
+
+#[cfg(feature = "async")]
+impl<R: Unpin, A> Unpin for EncodeReader<R, A> {}
+
+#[cfg(feature = "async")]
+impl<R: futures_io::AsyncRead + Unpin, A: Alphabet> EncodeReader<R, A> {
+    /// Wrap `inner` to encode with `alpha`, which must have a power of two length whose digits
+    /// are aligned to bytes (base 2, 4, 16 or 256) or be bit-packed.
+    pub fn new(inner: R, alpha: A) -> io::Result<Self> {
+        let convert = Encoder::new(&alpha, None)?;
+        Ok(EncodeReader {
+            inner: Reader::new(inner, alpha, convert),
+        })
+    }
+
+    /// Wrap `inner` to encode `len` bytes with `alpha`, which must have a power of two length.
+    pub fn with_len(inner: R, alpha: A, len: u64) -> io::Result<Self> {
+        let convert = Encoder::new(&alpha, Some(len))?;
+        Ok(EncodeReader {
+            inner: Reader::new(inner, alpha, convert),
+        })
+    }
+
+    /// Unwrap this adapter, returning the underlying stream.
+    pub fn into_inner(self) -> R {
+        self.inner.inner
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R: futures_io::AsyncRead + Unpin, A: Alphabet> futures_io::AsyncRead for EncodeReader<R, A> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_mut().inner.poll_read(cx, buf)
+    }
+}
+
+/// Decodes the characters written to it, writing the bytes to the wrapped writer.
+///
+/// The decoding is checked and any buffered output written on
+/// [`poll_close`](futures_io::AsyncWrite::poll_close), which must be called once all the input has
+/// been written.
+///
+/// ```rust
+/// use futures::io::AsyncWriteExt;
+///
+/// # futures::executor::block_on(async {
+/// let input = b"68656c6c6f";
+/// let mut writer = bsx::stream::DecodeWriter::with_len(
+///     Vec::new(),
+///     bsx::StaticAlphabet::BASE16,
+///     input.len() as u64,
+/// )?;
+/// writer.write_all(input).await?;
+/// writer.close().await?;
+/// assert_eq!(b"hello", &*writer.into_inner());
+/// # Ok::<(), std::io::Error>(())
+/// # })?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[allow(missing_debug_implementations)]
+pub struct DecodeWriter<W, A> {
+    inner: Writer<W, A, Decoder>,
+}
+
+#[cfg(feature = "async")]
+impl<W: Unpin, A> Unpin for DecodeWriter<W, A> {}
+
+#[cfg(feature = "async")]
+impl<W: futures_io::AsyncWrite + Unpin, A: Alphabet> DecodeWriter<W, A> {
+    /// Wrap `inner` to decode with `alpha`, this is only possible without knowing the input
+    /// length for base 256 where each character is a whole byte or a bit-packed alphabet, other
+    /// power of two alphabets need [`with_len`](Self::with_len).
+    pub fn new(inner: W, alpha: A) -> io::Result<Self> {
+        let convert = Decoder::new(&alpha, None)?;
+        Ok(DecodeWriter {
+            inner: Writer::new(inner, alpha, convert),
+        })
+    }
+
+    /// Wrap `inner` to decode `len` characters with `alpha`, which must have a power of two
+    /// length.
+    pub fn with_len(inner: W, alpha: A, len: u64) -> io::Result<Self> {
+        let convert = Decoder::new(&alpha, Some(len))?;
+        Ok(DecodeWriter {
+            inner: Writer::new(inner, alpha, convert),
+        })
+    }
+
+    /// Unwrap this adapter, returning the underlying stream.
+    pub fn into_inner(self) -> W {
+        self.inner.inner
+    }
+}
+
+#[cfg(feature = "async")]
+impl<W: futures_io::AsyncWrite + Unpin, A: Alphabet> futures_io::AsyncWrite for DecodeWriter<W, A> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_mut().inner.poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().inner.poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().inner.poll_close(cx)
+    }
+}
+
+/// Decodes the characters read from the wrapped reader, returning the bytes.
+///
+/// Invalid characters give an [`InvalidData`](io::ErrorKind::InvalidData) error wrapping a
+/// [`decode::Error`](crate::decode::Error).
+///
+/// ```rust
+/// use futures::io::AsyncReadExt;
+///
+/// # futures::executor::block_on(async {
+/// let input = b"AGhlbGxv";
+/// let mut reader = bsx::stream::DecodeReader::with_len(
+///     &input[..],
+///     bsx::StaticAlphabet::BASE64,
+///     input.len() as u64,
+/// )?;
+/// let mut output = Vec::new();
+/// reader.read_to_end(&mut output).await?;
+/// assert_eq!(b"\0hello", &*output);
+/// # Ok::<(), std::io::Error>(())
+/// # })?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[allow(missing_debug_implementations)]
+pub struct DecodeReader<R, A> {
+    inner: Reader<R, A, Decoder>,
+}
+
+#[cfg(feature = "async")]
+impl<R: Unpin, A> Unpin for DecodeReader<R, A> {}
+
+#[cfg(feature = "async")]
+impl<R: futures_io::AsyncRead + Unpin, A: Alphabet> DecodeReader<R, A> {
+    /// Wrap `inner` to decode with `alpha`, this is only possible without knowing the input
+    /// length for base 256 where each character is a whole byte or a bit-packed alphabet, other
+    /// power of two alphabets need [`with_len`](Self::with_len).
+    pub fn new(inner: R, alpha: A) -> io::Result<Self> {
+        let convert = Decoder::new(&alpha, None)?;
+        Ok(DecodeReader {
+            inner: Reader::new(inner, alpha, convert),
+        })
+    }
+
+    /// Wrap `inner` to decode `len` characters with `alpha`, which must have a power of two
+    /// length.
+    pub fn with_len(inner: R, alpha: A, len: u64) -> io::Result<Self> {
+        let convert = Decoder::new(&alpha, Some(len))?;
+        Ok(DecodeReader {
+            inner: Reader::new(inner, alpha, convert),
+        })
+    }
+
+    /// Unwrap this adapter, returning the underlying stream.
+    pub fn into_inner(self) -> R {
+        self.inner.inner
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R: futures_io::AsyncRead + Unpin, A: Alphabet> futures_io::AsyncRead for DecodeReader<R, A> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        self.get_mut().inner.poll_read(cx, buf)
+    }
+}
@@ -0,0 +1,502 @@
+//! Functions for converting strings between arbitrary base alphabets.
+
+use core::{fmt, mem::MaybeUninit};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    alphabet::Unspecified,
+    encode::{assume_init_mut, encode_groups, limb_base, EncodeTarget},
+    Alphabet,
+};
+
+/// A builder for setting up the alphabets and output of a transcode.
+///
+/// See the documentation for [`bsx::transcode`](crate::transcode()) for a more
+/// high level view of how to use this.
+#[derive(Clone, Debug)]
+pub struct TranscodeBuilder<I: AsRef<[u8]>, A, B> {
+    input: I,
+    from: A,
+    to: B,
+    integer: bool,
+}
+
+/// A builder for setting up the alphabets and output of a translation.
+///
+/// See the documentation for [`bsx::translate`](crate::translate()) for a more
+/// high level view of how to use this.
+#[derive(Clone, Debug)]
+pub struct TranslateBuilder<I: AsRef<[u8]>, A, B> {
+    input: I,
+    from: A,
+    to: B,
+}
+
+/// A specialized [`Result`](core::result::Result) type for [`bsx::transcode`](module@crate::transcode)
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Errors that could occur when transcoding an arbitrary base encoded string.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The output buffer was too small to contain the entire output.
+    BufferTooSmall {
+        /// A buffer length that is large enough for the transcoded output, so may be more than
+        /// the exact transcoded length.
+        required: usize,
+    },
+
+    /// The input contained a character that was not part of the source alphabet.
+    InvalidCharacter {
+        /// The unexpected character.
+        character: char,
+        /// The (byte) index in the input string the character was at.
+        index: usize,
+    },
+
+    /// The input contained a multi-byte (or non-utf8) character which is
+    /// unsupported by this transcoder.
+    NonAsciiCharacter {
+        /// The (byte) index in the input string the start of the character was
+        /// at.
+        index: usize,
+    },
+
+    /// The alphabets passed to [`bsx::translate`](crate::translate()) were not the same length.
+    AlphabetLengthMismatch {
+        /// The length of the source alphabet.
+        from: usize,
+        /// The length of the destination alphabet.
+        to: usize,
+    },
+
+    /// The length of the transcoded output would not fit in a `usize`.
+    Overflow,
+}
+
+impl<I: AsRef<[u8]>> TranscodeBuilder<I, Unspecified, Unspecified> {
+    pub(crate) fn new(input: I) -> Self {
+        TranscodeBuilder {
+            input,
+            from: Unspecified,
+            to: Unspecified,
+            integer: false,
+        }
+    }
+}
+
+impl<I: AsRef<[u8]>, A, B> TranscodeBuilder<I, A, B> {
+    /// Change the alphabet that the input is encoded with.
+    pub fn from_alphabet<C>(self, from: C) -> TranscodeBuilder<I, C, B> {
+        TranscodeBuilder {
+            input: self.input,
+            from,
+            to: self.to,
+            integer: self.integer,
+        }
+    }
+
+    /// Change the alphabet that the output will be encoded with.
+    pub fn to_alphabet<C>(self, to: C) -> TranscodeBuilder<I, A, C> {
+        TranscodeBuilder {
+            input: self.input,
+            from: self.from,
+            to,
+            integer: self.integer,
+        }
+    }
+
+    /// Treat the input as an integer numeral rather than an encoded byte string.
+    ///
+    /// By default leading zero characters are preserved one-to-one, matching what decoding then
+    /// re-encoding would produce. With this set leading zeros are ignored instead, and a zero
+    /// value is output as a single zero character, as is normal for integers written in any base.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// const DECIMAL: &bsx::StaticAlphabet<10> = &bsx::StaticAlphabet::new_unwrap(b"0123456789");
+    ///
+    /// assert_eq!(
+    ///     "BukQL",
+    ///     bsx::transcode("00123456789")
+    ///         .from_alphabet(DECIMAL)
+    ///         .to_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .as_integer()
+    ///         .into_string()?);
+    ///
+    /// assert_eq!(
+    ///     "1",
+    ///     bsx::transcode("000")
+    ///         .from_alphabet(DECIMAL)
+    ///         .to_alphabet(bsx::StaticAlphabet::BITCOIN)
+    ///         .as_integer()
+    ///         .into_string()?);
+    /// # Ok::<(), bsx::transcode::Error>(())
+    /// ```
+    pub fn as_integer(self) -> Self {
+        TranscodeBuilder {
+            integer: true,
+            ..self
+        }
+    }
+}
+
+impl<I: AsRef<[u8]>, A: Alphabet, B: Alphabet> TranscodeBuilder<I, A, B> {
+    /// Transcode into a new owned string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     "4DSSNaN1SC",
+    ///     bsx::transcode("he11owor1d")
+    ///         .from_alphabet(bsx::StaticAlphabet::RIPPLE)
+    ///         .to_alphabet(bsx::StaticAlphabet::FLICKR)
+    ///         .into_string()?);
+    /// # Ok::<(), bsx::transcode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn into_string(self) -> Result<String> {
+        let mut output = String::new();
+        self.into(&mut output)?;
+        Ok(output)
+    }
+
+    /// Transcode into a new owned vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     b"4DSSNaN1SC",
+    ///     &*bsx::transcode("he11owor1d")
+    ///         .from_alphabet(bsx::StaticAlphabet::RIPPLE)
+    ///         .to_alphabet(bsx::StaticAlphabet::FLICKR)
+    ///         .into_vec()?);
+    /// # Ok::<(), bsx::transcode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn into_vec(self) -> Result<Vec<u8>> {
+        let mut output = Vec::new();
+        self.into(&mut output)?;
+        Ok(output)
+    }
+
+    /// Transcode into the given buffer.
+    ///
+    /// Returns the length written into the buffer, this has the same behaviour for the different
+    /// kinds of buffer as [`EncodeBuilder::into`](crate::encode::EncodeBuilder::into).
+    ///
+    /// No intermediate buffer is used, the conversion happens directly in `output`.
+    ///
+    /// See the documentation for [`bsx::transcode`](crate::transcode()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut output = [0xFF; 12];
+    /// assert_eq!(
+    ///     10,
+    ///     bsx::transcode("he11owor1d")
+    ///         .from_alphabet(bsx::StaticAlphabet::RIPPLE)
+    ///         .to_alphabet(bsx::StaticAlphabet::FLICKR)
+    ///         .into(&mut output[..])?);
+    /// assert_eq!(b"4DSSNaN1SC\xFF\xFF", &output);
+    /// # Ok::<(), bsx::transcode::Error>(())
+    /// ```
+    pub fn into(self, mut output: impl EncodeTarget) -> Result<usize> {
+        let input = self.input.as_ref();
+        validate(input, &self.from)?;
+
+        let decode = self.from.decode();
+        let input = if self.integer {
+            // Keep a single zero character for a zero value so it's output as a zero
+            let zeros = input
+                .iter()
+                .take_while(|&&c| decode[c as usize] == 0)
+                .count();
+            &input[zeros.min(input.len().saturating_sub(1))..]
+        } else {
+            input
+        };
+
+        if self.from.len() == self.to.len() {
+            // SAFETY: `translate_into` only writes initialized bytes, and returns the length it
+            // has initialized
+            return unsafe {
+                output.encode_with(input.len(), |output| {
+                    translate_into(input, output, &self.from, &self.to)
+                })
+            }
+            .map_err(|_| Error::BufferTooSmall {
+                required: input.len(),
+            });
+        }
+
+        let zeros = input
+            .iter()
+            .take_while(|&&c| decode[c as usize] == 0)
+            .count();
+        let max_transcoded_len = (input.len() - zeros)
+            .checked_mul(ceil_log2(self.from.len()))
+            .map(|bits| bits / floor_log2(self.to.len()))
+            .and_then(|len| len.checked_add(zeros + 1))
+            .ok_or(Error::Overflow)?;
+
+        // SAFETY: `transcode_into` only writes initialized bytes, and returns the length it has
+        // initialized
+        unsafe {
+            output.encode_with(max_transcoded_len, |output| {
+                transcode_into(input, output, &self.from, &self.to)
+            })
+        }
+        .map_err(|_| Error::BufferTooSmall {
+            required: max_transcoded_len,
+        })
+    }
+}
+
+impl<I: AsRef<[u8]>> TranslateBuilder<I, Unspecified, Unspecified> {
+    pub(crate) fn new(input: I) -> Self {
+        TranslateBuilder {
+            input,
+            from: Unspecified,
+            to: Unspecified,
+        }
+    }
+}
+
+impl<I: AsRef<[u8]>, A, B> TranslateBuilder<I, A, B> {
+    /// Change the alphabet that the input is encoded with.
+    pub fn from<C>(self, from: C) -> TranslateBuilder<I, C, B> {
+        TranslateBuilder {
+            input: self.input,
+            from,
+            to: self.to,
+        }
+    }
+
+    /// Change the alphabet that the output will be encoded with.
+    pub fn to<C>(self, to: C) -> TranslateBuilder<I, A, C> {
+        TranslateBuilder {
+            input: self.input,
+            from: self.from,
+            to,
+        }
+    }
+}
+
+impl<I: AsRef<[u8]>, A: Alphabet, B: Alphabet> TranslateBuilder<I, A, B> {
+    /// Translate into a new owned string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     "he11owor1d",
+    ///     bsx::translate("4essoAo1sd")
+    ///         .from(bsx::StaticAlphabet::BITCOIN)
+    ///         .to(bsx::StaticAlphabet::RIPPLE)
+    ///         .into_string()?);
+    /// # Ok::<(), bsx::transcode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn into_string(self) -> Result<String> {
+        let mut output = String::new();
+        self.into(&mut output)?;
+        Ok(output)
+    }
+
+    /// Translate into a new owned vector.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     b"he11owor1d",
+    ///     &*bsx::translate("4essoAo1sd")
+    ///         .from(bsx::StaticAlphabet::BITCOIN)
+    ///         .to(bsx::StaticAlphabet::RIPPLE)
+    ///         .into_vec()?);
+    /// # Ok::<(), bsx::transcode::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+    pub fn into_vec(self) -> Result<Vec<u8>> {
+        let mut output = Vec::new();
+        self.into(&mut output)?;
+        Ok(output)
+    }
+
+    /// Translate into the given buffer.
+    ///
+    /// Returns the length written into the buffer, this has the same behaviour for the different
+    /// kinds of buffer as [`EncodeBuilder::into`](crate::encode::EncodeBuilder::into).
+    ///
+    /// See the documentation for [`bsx::translate`](crate::translate()) for an
+    /// explanation of the errors that may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let mut output = [0xFF; 12];
+    /// assert_eq!(
+    ///     10,
+    ///     bsx::translate("4essoAo1sd")
+    ///         .from(bsx::StaticAlphabet::BITCOIN)
+    ///         .to(bsx::StaticAlphabet::RIPPLE)
+    ///         .into(&mut output[..])?);
+    /// assert_eq!(b"he11owor1d\xFF\xFF", &output);
+    /// # Ok::<(), bsx::transcode::Error>(())
+    /// ```
+    pub fn into(self, mut output: impl EncodeTarget) -> Result<usize> {
+        let input = self.input.as_ref();
+        if self.from.len() != self.to.len() {
+            return Err(Error::AlphabetLengthMismatch {
+                from: self.from.len(),
+                to: self.to.len(),
+            });
+        }
+        validate(input, &self.from)?;
+        // SAFETY: `translate_into` only writes initialized bytes, and returns the length it has
+        // initialized
+        unsafe {
+            output.encode_with(input.len(), |output| {
+                translate_into(input, output, &self.from, &self.to)
+            })
+        }
+        .map_err(|_| Error::BufferTooSmall {
+            required: input.len(),
+        })
+    }
+}
+
+fn ceil_log2(len: usize) -> usize {
+    (0usize.leading_zeros() - (len - 1).leading_zeros()) as usize
+}
+
+fn floor_log2(len: usize) -> usize {
+    (0usize.leading_zeros() - len.leading_zeros() - 1) as usize
+}
+
+fn validate(input: &[u8], alpha: impl Alphabet) -> Result<()> {
+    let decode = alpha.decode();
+    for (i, &c) in input.iter().enumerate() {
+        if decode[c as usize] == 0xFF && c > 127 {
+            return Err(Error::NonAsciiCharacter { index: i });
+        }
+        if decode[c as usize] == 0xFF {
+            return Err(Error::InvalidCharacter {
+                character: c as char,
+                index: i,
+            });
+        }
+    }
+    Ok(())
+}
+
+fn translate_into(
+    input: &[u8],
+    output: &mut [MaybeUninit<u8>],
+    from: impl Alphabet,
+    to: impl Alphabet,
+) -> crate::encode::Result<usize> {
+    let output = output
+        .get_mut(..input.len())
+        .ok_or(crate::encode::Error::BufferTooSmall {
+            required: input.len(),
+        })?;
+
+    let (decode, encode) = (from.decode(), to.encode());
+    let mut table = [0; 256];
+    for (c, mapped) in table.iter_mut().enumerate() {
+        if let Some(&digit) = encode.get(decode[c] as usize) {
+            *mapped = digit;
+        }
+    }
+
+    for (out, &c) in output.iter_mut().zip(input) {
+        out.write(table[c as usize]);
+    }
+
+    Ok(input.len())
+}
+
+fn transcode_into(
+    input: &[u8],
+    output: &mut [MaybeUninit<u8>],
+    from: impl Alphabet,
+    to: impl Alphabet,
+) -> crate::encode::Result<usize> {
+    let (from_len, decode) = (from.len(), from.decode());
+    let (from_base, from_digits) = limb_base(from_len);
+
+    // Fold in a limb's worth of source digits at a time, the caller's buffer holds the limbs
+    // until they're expanded into the destination digits in place
+    let groups = input.chunks(from_digits).map(|group| {
+        let value = group.iter().fold(0, |acc, &c| {
+            acc * from_len as u64 + u64::from(decode[c as usize])
+        });
+        let base = if group.len() == from_digits {
+            from_base
+        } else {
+            (from_len as u64).pow(group.len() as u32)
+        };
+        (value, base)
+    });
+    let mut index = encode_groups(groups, output, to.len())?;
+
+    for _ in input.iter().take_while(|&&c| decode[c as usize] == 0) {
+        output
+            .get_mut(index)
+            .ok_or(crate::encode::Error::BufferTooSmall {
+                required: index + 1,
+            })?
+            .write(0);
+        index += 1;
+    }
+
+    // SAFETY: the first `index` bytes have all been initialized
+    let output = unsafe { assume_init_mut(&mut output[..index]) };
+    crate::simd::translate_reverse(output, to.encode());
+    Ok(index)
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::BufferTooSmall { required } => write!(
+                f,
+                "buffer provided to transcode string into was too small, {} bytes are required",
+                required
+            ),
+            Error::InvalidCharacter { character, index } => write!(
+                f,
+                "provided string contained invalid character {:?} at byte {}",
+                character, index
+            ),
+            Error::NonAsciiCharacter { index } => write!(
+                f,
+                "provided string contained non-ascii character starting at byte {}",
+                index
+            ),
+            Error::AlphabetLengthMismatch { from, to } => write!(
+                f,
+                "cannot translate between alphabets of different lengths {} and {}",
+                from, to
+            ),
+            Error::Overflow => write!(f, "transcoded length would overflow usize"),
+        }
+    }
+}
@@ -0,0 +1,210 @@
+//! Support for [ULID](https://github.com/ulid/spec)s, 128-bit identifiers encoded as fixed width
+//! 26 character Crockford base32 strings.
+
+use core::{fmt, str::FromStr};
+
+use crate::{Alphabet, StaticAlphabet};
+
+/// The length of an encoded [`Ulid`].
+pub const ENCODED_LEN: usize = 26;
+
+/// Crockford's base32 alphabet, as used for ULIDs, this is [`StaticAlphabet::CROCKFORD`].
+pub const ALPHABET: &StaticAlphabet<32> = StaticAlphabet::CROCKFORD;
+
+/// A 128-bit ULID.
+///
+/// The [`Display`](fmt::Display) and [`FromStr`] implementations use the canonical 26 character
+/// encoding.
+///
+/// ```rust
+/// let ulid: bsx::ulid::Ulid = "01ARZ3NDEKTSV4RRFFQ69G5FAV".parse()?;
+/// assert_eq!(1469922850259, ulid.timestamp_ms());
+/// assert_eq!("01ARZ3NDEKTSV4RRFFQ69G5FAV", ulid.to_string());
+/// # Ok::<(), bsx::ulid::Error>(())
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+pub struct Ulid(pub u128);
+
+/// A specialized [`Result`](core::result::Result) type for [`bsx::ulid`](module@crate::ulid)
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Errors that could occur when decoding a ULID.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input was not exactly [`ENCODED_LEN`] characters long.
+    InvalidLength {
+        /// The length of the input.
+        len: usize,
+    },
+
+    /// The input contained a character that was not part of the Crockford base32 alphabet.
+    InvalidCharacter {
+        /// The unexpected character.
+        character: char,
+        /// The (byte) index in the input string the character was at.
+        index: usize,
+    },
+
+    /// The input contained a multi-byte (or non-utf8) character.
+    NonAsciiCharacter {
+        /// The (byte) index in the input string the start of the character was at.
+        index: usize,
+    },
+
+    /// The input represented a value larger than 128 bits, i.e. the first character was greater
+    /// than `7`.
+    Overflow,
+}
+
+impl Ulid {
+    /// Create a ULID from its timestamp in milliseconds since the Unix epoch, and 80 bits of
+    /// randomness.
+    ///
+    /// Bits of `timestamp_ms` above 48 and `random` above 80 are ignored.
+    ///
+    /// ```rust
+    /// let ulid = bsx::ulid::Ulid::from_parts(1469922850259, 0);
+    /// assert_eq!("01ARZ3NDEK0000000000000000", ulid.to_string());
+    /// ```
+    pub const fn from_parts(timestamp_ms: u64, random: u128) -> Self {
+        let timestamp = (timestamp_ms as u128 & ((1 << 48) - 1)) << 80;
+        Ulid(timestamp | (random & ((1 << 80) - 1)))
+    }
+
+    /// The timestamp component, in milliseconds since the Unix epoch.
+    pub const fn timestamp_ms(self) -> u64 {
+        (self.0 >> 80) as u64
+    }
+
+    /// The 80 bit random component.
+    pub const fn random(self) -> u128 {
+        self.0 & ((1 << 80) - 1)
+    }
+
+    /// Encode into the canonical 26 character uppercase representation.
+    ///
+    /// ```rust
+    /// let ulid = bsx::ulid::Ulid(0x0156_3e3a_b5d3_d676_4c61_efb9_9302_bd5b);
+    /// assert_eq!(b"01ARZ3NDEKTSV4RRFFQ69G5FAV", &ulid.encode());
+    /// ```
+    pub fn encode(self) -> [u8; ENCODED_LEN] {
+        let encode = ALPHABET.encode();
+        let mut output = [0; ENCODED_LEN];
+        let mut value = self.0;
+        for byte in output.iter_mut().rev() {
+            *byte = encode[(value & 0x1F) as usize];
+            value >>= 5;
+        }
+        output
+    }
+
+    /// Decode from the 26 character representation, lowercase characters and Crockford's `I`, `L`
+    /// and `O` aliases are also accepted.
+    ///
+    /// ```rust
+    /// let ulid = bsx::ulid::Ulid::decode("olarz3ndektsv4rrffq69g5fav")?;
+    /// assert_eq!("01ARZ3NDEKTSV4RRFFQ69G5FAV", ulid.to_string());
+    /// # Ok::<(), bsx::ulid::Error>(())
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// ### Overflow
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     bsx::ulid::Error::Overflow,
+    ///     bsx::ulid::Ulid::decode("80000000000000000000000000").unwrap_err());
+    /// ```
+    ///
+    /// ### Invalid Length
+    ///
+    /// ```rust
+    /// assert_eq!(
+    ///     bsx::ulid::Error::InvalidLength { len: 10 },
+    ///     bsx::ulid::Ulid::decode("01ARZ3NDEK").unwrap_err());
+    /// ```
+    pub fn decode(input: impl AsRef<[u8]>) -> Result<Self> {
+        let input = input.as_ref();
+        if input.len() != ENCODED_LEN {
+            return Err(Error::InvalidLength { len: input.len() });
+        }
+
+        let decode = ALPHABET.decode();
+        let mut value = 0u128;
+        for (i, &c) in input.iter().enumerate() {
+            if c > 127 {
+                return Err(Error::NonAsciiCharacter { index: i });
+            }
+            let digit = decode[c as usize];
+            if digit == 0xFF {
+                return Err(Error::InvalidCharacter {
+                    character: c as char,
+                    index: i,
+                });
+            }
+            if i == 0 && digit > 7 {
+                return Err(Error::Overflow);
+            }
+            value = (value << 5) | u128::from(digit);
+        }
+
+        Ok(Ulid(value))
+    }
+}
+
+impl From<u128> for Ulid {
+    fn from(value: u128) -> Self {
+        Ulid(value)
+    }
+}
+
+impl From<Ulid> for u128 {
+    fn from(ulid: Ulid) -> Self {
+        ulid.0
+    }
+}
+
+impl fmt::Display for Ulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let encoded = self.encode();
+        // The alphabet is pure ASCII so this is always valid
+        f.write_str(core::str::from_utf8(&encoded).unwrap())
+    }
+}
+
+impl FromStr for Ulid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ulid::decode(s)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::InvalidLength { len } => write!(
+                f,
+                "provided string was {} characters long, expected {}",
+                len, ENCODED_LEN
+            ),
+            Error::InvalidCharacter { character, index } => write!(
+                f,
+                "provided string contained invalid character {:?} at byte {}",
+                character, index
+            ),
+            Error::NonAsciiCharacter { index } => write!(
+                f,
+                "provided string contained non-ascii character starting at byte {}",
+                index
+            ),
+            Error::Overflow => write!(f, "provided string was larger than 128 bits"),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Helpers for encoding [`Uuid`]s as short identifiers.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use ::uuid::Uuid;
+
+use crate::{decode::Error as DecodeError, Alphabet};
+
+/// A specialized [`Result`](core::result::Result) type for [`bsx::uuid`](module@crate::uuid)
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Errors that could occur when decoding a [`Uuid`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input did not decode to exactly 16 bytes.
+    InvalidLength,
+
+    /// Decoding the input failed.
+    Decode(DecodeError),
+}
+
+/// Encode a [`Uuid`] into a new owned string using the given alphabet.
+///
+/// # Examples
+///
+/// ```rust
+/// let uuid = uuid::Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+/// assert_eq!(
+///     "Dq7QdGPZBdz9vwjm3jLQSB",
+///     bsx::uuid::encode(&uuid, bsx::StaticAlphabet::BITCOIN));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "alloc", feature = "std"))))]
+pub fn encode(uuid: &Uuid, alphabet: impl Alphabet) -> String {
+    crate::encode(uuid.as_bytes())
+        .with_alphabet(alphabet)
+        .into_string()
+}
+
+/// Decode a [`Uuid`] from a string using the given alphabet, validating that it contains exactly
+/// 16 bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(
+///     uuid::Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8),
+///     bsx::uuid::decode("Dq7QdGPZBdz9vwjm3jLQSB", bsx::StaticAlphabet::BITCOIN)?);
+/// # Ok::<(), bsx::uuid::Error>(())
+/// ```
+///
+/// ## Errors
+///
+/// ### Invalid Length
+///
+/// ```rust
+/// assert_eq!(
+///     bsx::uuid::Error::InvalidLength,
+///     bsx::uuid::decode("he11owor1d", bsx::StaticAlphabet::BITCOIN).unwrap_err());
+/// ```
+pub fn decode(input: impl AsRef<[u8]>, alphabet: impl Alphabet) -> Result<Uuid> {
+    let bytes = crate::decode(input)
+        .with_alphabet(alphabet)
+        .into_array()
+        .map_err(|err| match err {
+            DecodeError::InvalidLength { .. } => Error::InvalidLength,
+            other => Error::Decode(other),
+        })?;
+    Ok(Uuid::from_bytes(bytes))
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Decode(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::InvalidLength => write!(f, "provided string did not decode to 16 bytes"),
+            Error::Decode(_) => write!(f, "failed to decode uuid"),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+mod cases;
+
+use bsx::a85::{Error, Variant};
+
+#[test]
+fn test_roundtrip() {
+    for variant in [Variant::Ascii85, Variant::Z85] {
+        for len in [0, 1, 2, 3, 4, 100] {
+            let mut input = cases::input(len);
+            input.extend([0; 9]);
+            let encoded = bsx::a85::encode(&input, variant);
+            assert!(bsx::a85::encoded_len(input.len()) >= encoded.len());
+            assert_eq!(input, bsx::a85::decode(&encoded, variant).unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_vectors() {
+    assert_eq!(
+        vec![0, 0, 0, 0, 0xff],
+        bsx::a85::decode("z\nrr", Variant::Ascii85).unwrap()
+    );
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        Error::InvalidCharacter {
+            character: 'z',
+            index: 2
+        },
+        bsx::a85::decode("s8z", Variant::Ascii85).unwrap_err()
+    );
+    assert_eq!(
+        Error::InvalidCharacter {
+            character: ' ',
+            index: 5
+        },
+        bsx::a85::decode("Hello World", Variant::Z85).unwrap_err()
+    );
+    assert_eq!(
+        Error::Overflow { index: 10 },
+        bsx::a85::decode("HelloWorld%%", Variant::Z85).unwrap_err()
+    );
+    assert_eq!(
+        Error::BufferTooSmall { required: 8 },
+        bsx::a85::decode_into("zz", &mut [0; 4], Variant::Ascii85).unwrap_err()
+    );
+}
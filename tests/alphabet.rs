@@ -0,0 +1,56 @@
+#[test]
+fn test_alphabet_by_name() {
+    let input = b"hello world";
+    let alphabets: [(&str, &dyn bsx::Alphabet); 5] = [
+        ("bitcoin", bsx::StaticAlphabet::BITCOIN),
+        ("hex", bsx::StaticAlphabet::BASE16),
+        ("HEX", bsx::StaticAlphabet::BASE16_UPPER),
+        ("z85", bsx::StaticAlphabet::Z85),
+        ("base91", bsx::StaticAlphabet::BASE91),
+    ];
+    for (name, alpha) in alphabets {
+        let named = bsx::alphabet::by_name(name).unwrap();
+        assert_eq!(alpha.encode(), named.encode());
+        let encoded = bsx::encode(input).with_alphabet(named).into_string();
+        assert_eq!(
+            input.to_vec(),
+            bsx::decode(&encoded)
+                .with_alphabet(named)
+                .into_vec()
+                .unwrap()
+        );
+    }
+    assert!(bsx::alphabet::by_name("Bitcoin").is_none());
+    assert!(bsx::alphabet::by_name("custom(abc)").is_none());
+}
+
+#[test]
+fn test_alphabet_parse() {
+    let crockford: bsx::DynamicAlphabet<Vec<u8>> = "crockford".parse().unwrap();
+    assert_eq!(
+        bsx::decode("1o")
+            .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+            .into_vec()
+            .unwrap(),
+        bsx::decode("1o")
+            .with_alphabet(&crockford)
+            .into_vec()
+            .unwrap()
+    );
+
+    let custom: bsx::DynamicAlphabet<Vec<u8>> = "custom(01)".parse().unwrap();
+    assert_eq!(
+        vec![5],
+        bsx::decode("101")
+            .with_alphabet(&custom)
+            .into_vec()
+            .unwrap()
+    );
+
+    for invalid in ["", "custom(01", "custom01)", "Bitcoin"] {
+        assert_eq!(
+            bsx::alphabet::Error::UnknownName,
+            bsx::DynamicAlphabet::parse(invalid).unwrap_err()
+        );
+    }
+}
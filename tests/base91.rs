@@ -0,0 +1,44 @@
+#![cfg(feature = "base91")]
+
+mod cases;
+
+use bsx::base91::Error;
+
+#[test]
+fn test_roundtrip() {
+    for len in [0, 1, 2, 3, 100] {
+        let input = cases::input(len);
+        let encoded = bsx::base91::encode(&input);
+        assert!(bsx::base91::encoded_len(len) >= encoded.len());
+        assert!(bsx::base91::decoded_len(encoded.len()) >= len);
+        assert_eq!(input, bsx::base91::decode(&encoded).unwrap());
+    }
+}
+
+#[test]
+fn test_vectors() {
+    assert_eq!("", bsx::base91::encode(b""));
+    assert_eq!("AA", bsx::base91::encode([0]));
+    assert_eq!("GB", bsx::base91::encode(b"a"));
+    assert_eq!(">OwJh>}AQ;r@@Y?F", bsx::base91::encode(b"Hello, World!"));
+    assert_eq!(b"a", &*bsx::base91::decode("GB").unwrap());
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        Error::InvalidCharacter {
+            character: ' ',
+            index: 2
+        },
+        bsx::base91::decode("GB GB").unwrap_err()
+    );
+    assert_eq!(
+        Error::NonAsciiCharacter { index: 1 },
+        bsx::base91::decode("Gé").unwrap_err()
+    );
+    assert_eq!(
+        Error::BufferTooSmall { required: 14 },
+        bsx::base91::decode_into(">OwJh>}AQ;r@@Y?F", &mut [0; 12]).unwrap_err()
+    );
+}
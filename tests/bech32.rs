@@ -0,0 +1,91 @@
+#![cfg(feature = "bech32")]
+
+mod cases;
+
+use bsx::bech32::{Error, Variant};
+
+#[test]
+fn test_bech32_valid() {
+    let cases = [
+        ("A12UEL5L", Variant::Bech32),
+        ("a12uel5l", Variant::Bech32),
+        ("an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs", Variant::Bech32),
+        ("abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw", Variant::Bech32),
+        ("split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w", Variant::Bech32),
+        ("?1ezyfcl", Variant::Bech32),
+        ("A1LQFN3A", Variant::Bech32m),
+        ("a1lqfn3a", Variant::Bech32m),
+        ("an83characterlonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11sg7hg6", Variant::Bech32m),
+        ("abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx", Variant::Bech32m),
+        ("split1checkupstagehandshakeupstreamerranterredcaperredlc445v", Variant::Bech32m),
+        ("?1v759aa", Variant::Bech32m),
+    ];
+    let long_bech32 = format!("11{}c8247j", "q".repeat(82));
+    let long_bech32m = format!("11{}udsr8", "l".repeat(83));
+    let cases = cases.iter().copied().chain([
+        (&*long_bech32, Variant::Bech32),
+        (&*long_bech32m, Variant::Bech32m),
+    ]);
+    for (input, expected) in cases {
+        let (hrp, values, variant) = bsx::bech32::decode_values(input).unwrap();
+        assert_eq!(expected, variant, "{}", input);
+        assert_eq!(
+            input.to_ascii_lowercase(),
+            bsx::bech32::encode_values(&hrp, &values, variant).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_bech32_invalid() {
+    let cases = [
+        ("pzry9x0s0muk", Error::MissingSeparator),
+        ("1pzry9x0s0muk", Error::InvalidHrp),
+        (
+            "x1b4n0q5v",
+            Error::InvalidCharacter {
+                character: 'b',
+                index: 2,
+            },
+        ),
+        ("li1dgmt3", Error::MissingSeparator),
+        ("A1G7SGD8", Error::InvalidChecksum),
+        ("10a06t8", Error::InvalidHrp),
+        ("1qzzfhee", Error::InvalidHrp),
+        ("\x201nwldj5", Error::InvalidHrp),
+        ("\x7f1axkwrx", Error::InvalidHrp),
+        (
+            "an84characterslonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1569pvx",
+            Error::TooLong { len: 91 },
+        ),
+        ("a1lqfN3a", Error::MixedCase),
+        ("M1VUXWEZ", Error::InvalidChecksum),
+    ];
+    for &(input, expected) in cases.iter() {
+        assert_eq!(
+            expected,
+            bsx::bech32::decode_values(input).unwrap_err(),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_bech32_bytes() {
+    for len in [0, 1, 10, 40] {
+        let input = cases::input(len);
+        for &variant in [Variant::Bech32, Variant::Bech32m].iter() {
+            let encoded = bsx::bech32::encode("test", &input, variant).unwrap();
+            assert_eq!(
+                ("test".to_owned(), input.clone(), variant),
+                bsx::bech32::decode(&encoded).unwrap()
+            );
+        }
+    }
+
+    assert_eq!(
+        Error::TooLong { len: 91 },
+        bsx::bech32::encode("test", [0; 50], Variant::Bech32).unwrap_err()
+    );
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "bigint")]
+
+mod cases;
+
+use num_bigint::BigUint;
+
+#[test]
+fn test_encode_biguint() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+
+    // Zero is a single zero digit, like `encode_int`
+    assert_eq!(
+        "1",
+        bsx::encode_biguint(&BigUint::default())
+            .with_alphabet(alpha)
+            .into_string()
+    );
+
+    for value in [1, 57, 58, u64::MAX.into(), u128::MAX] {
+        assert_eq!(
+            bsx::encode_int(value).with_alphabet(alpha).into_string(),
+            bsx::encode_biguint(&BigUint::from(value))
+                .with_alphabet(alpha)
+                .into_string(),
+            "value {}",
+            value,
+        );
+    }
+
+    // Past the bignum threshold
+    let value = BigUint::from_bytes_be(&cases::input(3000)) | BigUint::from(1u32) << 23_999u32;
+    let encoded = bsx::encode_biguint(&value)
+        .with_alphabet(alpha)
+        .into_string();
+    assert_eq!(
+        value,
+        bsx::decode(&encoded)
+            .with_alphabet(alpha)
+            .into_biguint()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_decode_biguint() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+
+    assert_eq!(
+        BigUint::default(),
+        bsx::decode("").with_alphabet(alpha).into_biguint().unwrap()
+    );
+    // Leading zero characters are part of the numeral, not zero bytes
+    assert_eq!(
+        BigUint::default(),
+        bsx::decode("111")
+            .with_alphabet(alpha)
+            .into_biguint()
+            .unwrap()
+    );
+    assert_eq!(
+        BigUint::from(57u32),
+        bsx::decode("11z")
+            .with_alphabet(alpha)
+            .into_biguint()
+            .unwrap()
+    );
+    // Indexes still count the skipped leading zeros
+    assert_eq!(
+        bsx::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 2
+        },
+        bsx::decode("110z")
+            .with_alphabet(alpha)
+            .into_biguint()
+            .unwrap_err()
+    );
+}
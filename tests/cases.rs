@@ -1,3 +1,11 @@
+// Shared by several test crates, each of which only uses some of it
+#![allow(dead_code)]
+
+/// Deterministic input of `len` bytes, irregular enough to carry between digits in any base.
+pub fn input(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 7919 % 251) as u8).collect()
+}
+
 // Subset of test cases from https://github.com/cryptocoinjs/base-x/blob/master/test/fixtures.json
 pub const TEST_CASES: &[(&[u8], &str)] = &[
     (&[], ""),
@@ -13,6 +13,29 @@ fn test_decode() {
                 .into_vec()
                 .unwrap()
         );
+
+        {
+            let mut vec = vec![0xFF; 3];
+            assert_eq!(
+                Ok(val.len()),
+                bsx::decode(s)
+                    .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                    .into(&mut vec)
+            );
+            assert_eq!(val, &vec[..]);
+        }
+
+        {
+            let mut bytes = [0xFF; 64];
+            assert_eq!(
+                Ok(val.len()),
+                bsx::decode(s)
+                    .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                    .into(&mut bytes[..])
+            );
+            assert_eq!(val, &bytes[..val.len()]);
+            assert!(bytes[val.len()..].iter().all(|&b| b == 0xFF));
+        }
     }
 }
 
@@ -23,7 +46,15 @@ fn test_decode_small_buffer_err() {
         bsx::decode("a3gV")
             .with_alphabet(bsx::StaticAlphabet::BITCOIN)
             .into(&mut output),
-        Err(bsx::decode::Error::BufferTooSmall)
+        Err(bsx::decode::Error::BufferTooSmall { required: 3 })
+    );
+
+    let mut output = [0; 3];
+    assert_eq!(
+        bsx::decode("a3gV")
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .into(&mut output),
+        Ok(3)
     );
 }
 
@@ -41,3 +72,1104 @@ fn test_decode_invalid_char() {
         }
     );
 }
+
+#[test]
+fn test_decode_into_vec_allocation() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let decoded = bsx::decode(s)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .into_vec()
+            .unwrap();
+        assert_eq!(val, &decoded[..]);
+        assert!(decoded.capacity() <= (val.len() + 1).max(8), "{}", s);
+    }
+}
+
+#[test]
+fn test_decode_large() {
+    for &len in &[4095, 4096, 7000] {
+        let mut input: Vec<u8> = (0..len).map(|i| b"123456789abcdefghijk"[i % 20]).collect();
+        input[2] = b'1';
+        let decoded = bsx::decode(&input)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .into_vec()
+            .unwrap();
+        assert_eq!(
+            bs58::decode(&input).into_vec().unwrap(),
+            decoded,
+            "len {}",
+            len
+        );
+    }
+}
+
+#[test]
+fn test_decode_roundtrip_short() {
+    let chars: Vec<u8> = (0..128).collect();
+    for base in 2..=128 {
+        let alpha = bsx::DynamicAlphabet::new(&chars[..base]).unwrap();
+        for len in 14..=18 {
+            for &byte in &[0x01, 0xFF] {
+                let input = vec![byte; len];
+                let encoded = bsx::encode(&input).with_alphabet(&alpha).into_vec();
+                let decoded = bsx::decode(&encoded)
+                    .with_alphabet(&alpha)
+                    .into_vec()
+                    .unwrap();
+                assert_eq!(input, decoded, "base {} len {}", base, len);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_decode_invalid_char_long() {
+    let mut sample = vec![b'z'; 200];
+    for &index in &[0, 15, 16, 31, 32, 63, 64, 100, 199] {
+        sample[index] = b'0';
+        assert_eq!(
+            bsx::decode(&sample)
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .into_vec()
+                .unwrap_err(),
+            bsx::decode::Error::InvalidCharacter {
+                character: '0',
+                index
+            }
+        );
+        sample[index] = 0xC3;
+        assert_eq!(
+            bsx::decode(&sample)
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .into_vec()
+                .unwrap_err(),
+            bsx::decode::Error::NonAsciiCharacter { index }
+        );
+        sample[index] = b'z';
+    }
+}
+
+#[test]
+fn test_decode_const() {
+    const DECODED: [u8; 10] = bsx::decode_const("EJDM8drfXA6uyA", bsx::StaticAlphabet::BITCOIN);
+    assert_eq!(
+        [0xec, 0xac, 0x89, 0xca, 0xd9, 0x39, 0x23, 0xc0, 0x23, 0x21],
+        DECODED
+    );
+
+    assert_eq!(
+        [0; 10],
+        bsx::decode_const::<10, 58>("1111111111", bsx::StaticAlphabet::BITCOIN)
+    );
+    assert_eq!(
+        [0x00, 0x00, 0x01],
+        bsx::decode_const::<3, 58>("112", bsx::StaticAlphabet::BITCOIN)
+    );
+}
+
+#[test]
+#[should_panic(expected = "input contained a character that was not part of the alphabet")]
+fn test_decode_const_invalid_char() {
+    bsx::decode_const::<8, 58>("hello world", bsx::StaticAlphabet::BITCOIN);
+}
+
+#[test]
+fn test_decode_const_macro() {
+    const DECODED: &[u8] = &bsx::decode_const!("EJDM8drfXA6uyA", BITCOIN);
+    assert_eq!(
+        [0xec, 0xac, 0x89, 0xca, 0xd9, 0x39, 0x23, 0xc0, 0x23, 0x21],
+        DECODED
+    );
+    assert_eq!([0, 0, 1], bsx::decode_const!("112", BITCOIN));
+    assert_eq!([0u8; 0], bsx::decode_const!("", BITCOIN));
+}
+
+#[test]
+fn test_decode_case_insensitive() {
+    const BASE32: &bsx::StaticAlphabet<32> =
+        &match bsx::StaticAlphabet::new_case_insensitive(b"abcdefghijklmnopqrstuvwxyz234567") {
+            Ok(alpha) => alpha,
+            Err(_) => panic!(),
+        };
+
+    for len in [0, 1, 10] {
+        let mut input = cases::input(len);
+        if len > 1 {
+            input[0] = 0;
+        }
+        let encoded = bsx::encode(&input).with_alphabet(BASE32).into_string();
+        for encoded in [encoded.clone(), encoded.to_uppercase()] {
+            assert_eq!(
+                input,
+                bsx::decode(&encoded)
+                    .with_alphabet(BASE32)
+                    .into_vec()
+                    .unwrap(),
+                "len {}",
+                len,
+            );
+        }
+    }
+
+    assert_eq!(
+        bsx::decode::Error::InvalidCharacter {
+            character: '1',
+            index: 2
+        },
+        bsx::decode("Ab1")
+            .with_alphabet(BASE32)
+            .into_vec()
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_decode_builder_case_insensitive() {
+    let hex = bsx::DynamicAlphabet::new("0123456789abcdef").unwrap();
+    for len in [0, 1, 10] {
+        let mut input = cases::input(len);
+        if len > 1 {
+            input[0] = 0;
+        }
+        let encoded = bsx::encode(&input).with_alphabet(&hex).into_string();
+        assert_eq!(
+            input,
+            bsx::decode(encoded.to_uppercase())
+                .with_alphabet(&hex)
+                .case_insensitive()
+                .into_vec()
+                .unwrap(),
+            "len {}",
+            len,
+        );
+    }
+
+    assert_eq!(
+        0xABCDEF,
+        bsx::decode("00aBcDeF")
+            .case_insensitive()
+            .with_alphabet(&hex)
+            .into_u128()
+            .unwrap()
+    );
+
+    // Both cases are part of the alphabet, so keep their own values
+    assert_eq!(
+        bsx::decode("he11owor1d")
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .into_vec(),
+        bsx::decode("he11owor1d")
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .case_insensitive()
+            .into_vec(),
+    );
+}
+
+#[test]
+fn test_decode_aliases() {
+    let alpha = bsx::StaticAlphabet::BITCOIN
+        .with_aliases(&[(b'0', b'o'), (b'O', b'o'), (b'I', b'1'), (b'l', b'1')])
+        .unwrap();
+
+    let input = cases::input(100);
+    let encoded = bsx::encode(&input)
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .into_string();
+    let confused = encoded.replace('o', "0").replace('1', "l");
+    assert_eq!(
+        input,
+        bsx::decode(&confused)
+            .with_alphabet(&alpha)
+            .into_vec()
+            .unwrap()
+    );
+
+    // An alias of the zero character is still a leading zero
+    assert_eq!(
+        vec![0, 0, 0x01],
+        bsx::decode("Il2").with_alphabet(&alpha).into_vec().unwrap()
+    );
+}
+
+#[test]
+fn test_decode_ignore_chars() {
+    for len in [100, 4000] {
+        let input = cases::input(len);
+        let encoded = bsx::encode(&input)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .into_string();
+        let grouped: Vec<&str> = encoded
+            .as_bytes()
+            .chunks(5)
+            .map(|chunk| core::str::from_utf8(chunk).unwrap())
+            .collect();
+        assert_eq!(
+            input,
+            bsx::decode(grouped.join("-\n"))
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .ignore_chars("-\n")
+                .into_vec()
+                .unwrap()
+        );
+    }
+
+    // Leading zeros and errors past the first block of kept characters
+    let zeros = "1-".repeat(100);
+    let mut output = [0xFF; 128];
+    assert_eq!(
+        101,
+        bsx::decode(format!("{}2", zeros))
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .ignore_chars("-")
+            .into(&mut output)
+            .unwrap()
+    );
+    assert_eq!([0; 100], output[..100]);
+    assert_eq!(1, output[100]);
+    assert_eq!(
+        bsx::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 200
+        },
+        bsx::decode(format!("{}0", zeros))
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .ignore_chars("-")
+            .into(&mut output)
+            .unwrap_err()
+    );
+
+    assert_eq!(
+        vec![0, 0, 0x01],
+        bsx::decode(" 1 1-2 ")
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .ignore_chars(" -")
+            .into_vec()
+            .unwrap()
+    );
+    assert_eq!(
+        57,
+        bsx::decode("1_1_z")
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .ignore_chars("_")
+            .into_u128()
+            .unwrap()
+    );
+    assert_eq!(
+        bsx::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 4
+        },
+        bsx::decode("1_1_0")
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .ignore_chars("_")
+            .into_u128()
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_decode_crockford_check_symbol() {
+    for len in [0, 1, 10] {
+        let input = cases::input(len);
+        let encoded = bsx::encode(&input)
+            .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+            .with_check_symbol()
+            .into_string();
+        assert_eq!(
+            input,
+            bsx::decode(encoded.to_lowercase())
+                .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+                .with_check_symbol()
+                .into_vec()
+                .unwrap()
+        );
+    }
+
+    let encoded = bsx::encode_int(u64::MAX)
+        .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+        .with_separator('-', 4)
+        .with_check_symbol()
+        .into_string();
+    assert_eq!("FZZZ-ZZZZ-ZZZZ-ZB", encoded);
+    assert_eq!(
+        u64::MAX,
+        bsx::decode(&encoded)
+            .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+            .ignore_chars("-")
+            .with_check_symbol()
+            .into_u64()
+            .unwrap()
+    );
+    assert_eq!(
+        bsx::decode::Error::InvalidChecksum,
+        bsx::decode("FZZZZZZZZZZZZ*")
+            .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+            .with_check_symbol()
+            .into_u64()
+            .unwrap_err()
+    );
+    assert_eq!(
+        bsx::decode::Error::InvalidCharacter {
+            character: '#',
+            index: 3
+        },
+        bsx::decode("3NQ#")
+            .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+            .with_check_symbol()
+            .into_u64()
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_decode_base45() {
+    for len in [0, 1, 2, 3, 100] {
+        let input = cases::input(len);
+        let encoded = bsx::base45::encode(&input);
+        assert_eq!(bsx::base45::encoded_len(len), encoded.len());
+        assert_eq!(input, bsx::base45::decode(&encoded).unwrap());
+    }
+
+    assert_eq!(vec![0xff, 0xff], bsx::base45::decode("FGW").unwrap());
+    assert_eq!(
+        bsx::base45::Error::Overflow { index: 0 },
+        bsx::base45::decode("GGW").unwrap_err()
+    );
+    assert_eq!(
+        bsx::base45::Error::Overflow { index: 3 },
+        bsx::base45::decode("FGW:5").unwrap_err()
+    );
+    assert_eq!(
+        bsx::base45::Error::InvalidCharacter {
+            character: 'a',
+            index: 2
+        },
+        bsx::base45::decode("FGa").unwrap_err()
+    );
+    assert_eq!(
+        bsx::base45::Error::BufferTooSmall { required: 5 },
+        bsx::base45::decode_into("QED8WEX0", &mut [0; 4]).unwrap_err()
+    );
+}
+
+#[test]
+fn test_decode_shuffled() {
+    use bsx::Alphabet;
+
+    let shuffled = bsx::DynamicAlphabet::shuffled(bsx::StaticAlphabet::BASE36, 7);
+    assert_eq!(
+        Some("2ar5wlij9n18hzvf4bk7xymugtedps6q0co3"),
+        shuffled.as_str()
+    );
+    assert_eq!(
+        bsx::DynamicAlphabet::shuffled(bsx::StaticAlphabet::BASE36, 7).as_str(),
+        shuffled.as_str()
+    );
+    assert_ne!(
+        bsx::DynamicAlphabet::shuffled(bsx::StaticAlphabet::BASE36, 8).as_str(),
+        shuffled.as_str()
+    );
+
+    let input = cases::input(100);
+    let encoded = bsx::encode(&input).with_alphabet(&shuffled).into_string();
+    assert_eq!(
+        input,
+        bsx::decode(encoded.to_uppercase())
+            .with_alphabet(&shuffled)
+            .into_vec()
+            .unwrap()
+    );
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_decode_arrayvec() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let encoded = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let mut vec = arrayvec::ArrayVec::<u8, 100>::new();
+    vec.push(0xFF);
+    bsx::decode(&encoded)
+        .with_alphabet(alpha)
+        .into(&mut vec)
+        .unwrap();
+    assert_eq!(input, &vec[..]);
+
+    let mut small = arrayvec::ArrayVec::<u8, 50>::new();
+    assert_eq!(
+        bsx::decode::Error::BufferTooSmall { required: 101 },
+        bsx::decode(&encoded)
+            .with_alphabet(alpha)
+            .into(&mut small)
+            .unwrap_err()
+    );
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_decode_heapless() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let encoded = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let mut vec = heapless::Vec::<u8, 100>::new();
+    vec.push(0xFF).unwrap();
+    bsx::decode(&encoded)
+        .with_alphabet(alpha)
+        .into(&mut vec)
+        .unwrap();
+    assert_eq!(input, &vec[..]);
+
+    let mut small = heapless::Vec::<u8, 50>::new();
+    assert_eq!(
+        bsx::decode::Error::BufferTooSmall { required: 101 },
+        bsx::decode(&encoded)
+            .with_alphabet(alpha)
+            .into(&mut small)
+            .unwrap_err()
+    );
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_decode_bytes() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let encoded = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let mut output = bytes::BytesMut::from(&b"stale"[..]);
+    bsx::decode(&encoded)
+        .with_alphabet(alpha)
+        .into(&mut output)
+        .unwrap();
+    assert_eq!(input, &output[..]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_decode_writer() {
+    use std::io;
+
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+
+    for len in [0, 100, 1000] {
+        let input = cases::input(len);
+        let encoded = bsx::encode(&input).with_alphabet(alpha).into_string();
+        let mut output = io::Cursor::new(b"prefix".to_vec());
+        output.set_position(6);
+        let written = bsx::decode(&encoded)
+            .with_alphabet(alpha)
+            .into_writer(&mut output)
+            .unwrap();
+        assert_eq!(input.len(), written);
+        assert_eq!(&output.get_ref()[..6], b"prefix");
+        assert_eq!(input, &output.get_ref()[6..]);
+    }
+
+    let mut output = [0; 4];
+    let err = bsx::decode("he11owor1d")
+        .with_alphabet(alpha)
+        .into_writer(&mut output[..])
+        .unwrap_err();
+    assert_eq!(io::ErrorKind::WriteZero, err.kind());
+}
+
+#[test]
+fn test_decode_uninit() {
+    use core::mem::MaybeUninit;
+
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let encoded = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let mut output = [MaybeUninit::uninit(); 256];
+    let decoded = bsx::decode(&encoded)
+        .with_alphabet(alpha)
+        .into_uninit(&mut output)
+        .unwrap();
+    assert_eq!(input, decoded);
+
+    let mut small = [MaybeUninit::uninit(); 16];
+    assert_eq!(
+        bsx::decode::Error::BufferTooSmall { required: 101 },
+        bsx::decode(&encoded)
+            .with_alphabet(alpha)
+            .into(&mut small[..])
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_decode_onto() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let encoded = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let mut output = b"prefix:".to_vec();
+    bsx::decode(&encoded)
+        .with_alphabet(alpha)
+        .onto_vec(&mut output)
+        .unwrap();
+    assert_eq!(b"prefix:", &output[..7]);
+    assert_eq!(input, &output[7..]);
+
+    let mut output = b"prefix:".to_vec();
+    assert!(bsx::decode("he11o0")
+        .with_alphabet(alpha)
+        .onto_vec(&mut output)
+        .is_err());
+    assert_eq!(b"prefix:", &output[..]);
+}
+
+#[test]
+fn test_decode_chars() {
+    use bsx::decode::AsciiInput;
+
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let encoded = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    // Characters filtered out of a larger stream, without collecting into a string
+    let chars = || encoded.chars().flat_map(|c| [c, '-']).filter(|&c| c != '-');
+    let decoded = AsciiInput::from_chars(chars()).into_vec(alpha).unwrap();
+    assert_eq!(input, decoded);
+    let mut output = [0; 100];
+    assert_eq!(
+        100,
+        AsciiInput::from_chars(chars())
+            .decode_into(alpha, &mut output)
+            .unwrap()
+    );
+    assert_eq!(input, output);
+
+    // Iterators without an upper bound on their length
+    let mut chars = encoded.chars();
+    let decoded = AsciiInput::from_chars(core::iter::from_fn(|| chars.next()))
+        .into_vec(alpha)
+        .unwrap();
+    assert_eq!(input, decoded);
+
+    // Errors index by character
+    let chars: Vec<char> = "1ü1l".chars().collect();
+    assert_eq!(
+        bsx::decode::Error::NonAsciiCharacter { index: 1 },
+        AsciiInput::from_chars(chars.iter().copied())
+            .into_vec(alpha)
+            .unwrap_err()
+    );
+    assert_eq!(
+        bsx::decode::Error::InvalidCharacter {
+            character: 'l',
+            index: 2
+        },
+        AsciiInput::from_chars("11l".chars())
+            .decode_into(alpha, &mut [0; 3])
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_decode_utf16() {
+    use bsx::decode::AsciiInput;
+
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let encoded = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let wide: Vec<u16> = encoded.encode_utf16().collect();
+    let decoded = AsciiInput::from_utf16(wide.iter().copied())
+        .into_vec(alpha)
+        .unwrap();
+    assert_eq!(input, decoded);
+
+    // Latin-1 range units are still non-ASCII
+    assert_eq!(
+        bsx::decode::Error::NonAsciiCharacter { index: 2 },
+        AsciiInput::from_utf16([0x31, 0x31, 0xE9])
+            .into_vec(alpha)
+            .unwrap_err()
+    );
+    // Lone surrogates
+    assert_eq!(
+        bsx::decode::Error::NonAsciiCharacter { index: 0 },
+        AsciiInput::from_utf16([0xDC00, 0x31])
+            .into_vec(alpha)
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_decode_newtype() {
+    bsx::newtype! {
+        #[derive(Clone, PartialEq, Eq)]
+        struct Bytes(Vec<u8>) = BITCOIN;
+    }
+
+    bsx::newtype! {
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        struct Id([u8; 4]) = FLICKR;
+    }
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let bytes: Bytes = s.parse().unwrap();
+        assert_eq!(val, bytes.as_ref());
+        assert_eq!(s, bytes.to_string());
+        assert_eq!(format!("Bytes({:?})", s), format!("{:?}", bytes));
+    }
+
+    let id = Id::from([0xde, 0xad, 0xbe, 0xef]);
+    let encoded = bsx::encode(id.0)
+        .with_alphabet(bsx::StaticAlphabet::FLICKR)
+        .into_string();
+    assert_eq!(encoded, id.to_string());
+    assert_eq!(Ok(id), encoded.parse());
+    assert_eq!(
+        Err(bsx::decode::Error::InvalidLength { expected: 4 }),
+        "2g".parse::<Id>()
+    );
+    assert_eq!(
+        Err(bsx::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 0
+        }),
+        "0".parse::<Id>()
+    );
+}
+
+#[test]
+#[cfg(feature = "zeroize")]
+fn test_decode_zeroizing() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    for len in [0, 100, 1000] {
+        let input = cases::input(len);
+        let encoded = bsx::encode(&input).with_alphabet(alpha).into_string();
+        let decoded = bsx::decode(&encoded)
+            .with_alphabet(alpha)
+            .into_zeroizing_vec()
+            .unwrap();
+        assert_eq!(input, *decoded);
+    }
+
+    assert_eq!(
+        bsx::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 3
+        },
+        bsx::decode("he10owor1d")
+            .with_alphabet(alpha)
+            .into_zeroizing_vec()
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_decode_max_len() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let encoded = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    assert_eq!(
+        input,
+        bsx::decode(&encoded)
+            .with_alphabet(alpha)
+            .with_max_len(encoded.len())
+            .into_vec()
+            .unwrap()
+    );
+
+    let mut output = [0; 10];
+    assert_eq!(
+        bsx::decode::Error::InputTooLong {
+            max: encoded.len() - 1
+        },
+        bsx::decode(&encoded)
+            .with_alphabet(alpha)
+            .with_max_len(encoded.len() - 1)
+            .into(&mut output[..])
+            .unwrap_err()
+    );
+    assert_eq!(
+        bsx::decode::Error::InputTooLong { max: 0 },
+        bsx::decode("1")
+            .with_alphabet(alpha)
+            .with_max_len(0)
+            .into_u128()
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_decode_lenient() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let encoded = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let mangled: String = encoded
+        .chars()
+        .enumerate()
+        .flat_map(|(i, c)| {
+            let junk = match i % 4 {
+                0 => "",
+                1 => " ",
+                2 => "\u{e9}0",
+                _ => "\r\n",
+            };
+            junk.chars().chain(Some(c))
+        })
+        .collect();
+    assert_eq!(
+        input,
+        bsx::decode(&mangled)
+            .with_alphabet(alpha)
+            .lenient()
+            .into_vec()
+            .unwrap()
+    );
+    assert_matches::assert_matches!(
+        bsx::decode(&mangled).with_alphabet(alpha).into_vec(),
+        Err(bsx::decode::Error::InvalidCharacter { .. })
+    );
+
+    // Leading junk doesn't count as zeros
+    assert_eq!(
+        [0x00, 0x61],
+        bsx::decode("--12g")
+            .with_alphabet(alpha)
+            .lenient()
+            .into_array()
+            .unwrap()
+    );
+
+    // Case folding happens before judging characters invalid
+    assert_eq!(
+        vec![0xde, 0xad],
+        bsx::decode("De-Ad")
+            .with_alphabet(bsx::StaticAlphabet::BASE16)
+            .case_insensitive()
+            .lenient()
+            .into_vec()
+            .unwrap()
+    );
+
+    let checked = bsx::encode([0x12, 0x34])
+        .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+        .with_check_symbol()
+        .into_string();
+    assert_eq!(
+        vec![0x12, 0x34],
+        bsx::decode(&format!("#{}", checked))
+            .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+            .with_check_symbol()
+            .lenient()
+            .into_vec()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_decode_trim_whitespace() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let padded = format!(" \t{}\r\n", s);
+        assert_eq!(
+            val.to_vec(),
+            bsx::decode(&padded)
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .trim_whitespace()
+                .into_vec()
+                .unwrap()
+        );
+    }
+
+    assert_eq!(
+        Vec::<u8>::new(),
+        bsx::decode(" \n ")
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .trim_whitespace()
+            .into_vec()
+            .unwrap()
+    );
+
+    // Leading zeros are still counted after the whitespace
+    let decoder = bsx::decode("\n\n\n\n\n\n\n\n\n1111111111")
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .trim_whitespace();
+    assert_eq!(10, decoder.max_decoded_len());
+    let mut output = [0xFF; 10];
+    assert_eq!(10, decoder.into(&mut output[..]).unwrap());
+
+    assert_eq!(
+        bsx::decode::Error::InvalidChecksum,
+        bsx::decode(" 3NQK8MU\n")
+            .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+            .with_check_symbol()
+            .trim_whitespace()
+            .into_u128()
+            .unwrap_err()
+    );
+    assert_eq!(
+        bsx::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 3
+        },
+        bsx::decode("  \n0\n")
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .with_leading_zeros(bsx::encode::LeadingZeros::CountPrefix)
+            .trim_whitespace()
+            .into_vec()
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_decode_error_context() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let decode = |input: &str| {
+        bsx::decode(input)
+            .with_alphabet(alpha)
+            .into_vec()
+            .unwrap_err()
+            .with_context(input)
+    };
+
+    let err = decode("he11o0");
+    assert_eq!(
+        bsx::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 5
+        },
+        err.error()
+    );
+    assert_eq!(Some("he11o0"), err.snippet());
+    assert_eq!(
+        r#"provided string contained invalid character '0' at byte 5 (line 1, column 6), near "he11o0""#,
+        err.to_string()
+    );
+
+    // The window is widened to keep whole characters
+    let err = bsx::decode::Error::InvalidCharacter {
+        character: '0',
+        index: 15,
+    }
+    .with_context("1111é1111111110");
+    assert_eq!(Some("é1111111110"), err.snippet());
+    let err = decode("0111111111é1");
+    assert_eq!(Some("0111111111é"), err.snippet());
+    assert_eq!(
+        r#"provided string contained invalid character '0' at byte 0 (line 1, column 1), near "0111111111é...""#,
+        err.to_string()
+    );
+    let err = decode("1111111111111111111111111é1");
+    assert_eq!(
+        bsx::decode::Error::NonAsciiCharacter { index: 25 },
+        err.error()
+    );
+    assert_eq!(Some("1111111111é1"), err.snippet());
+
+    // Control characters are escaped in the message
+    let err = decode("he11\nowor1d");
+    assert_eq!(
+        r#"provided string contained invalid character '\n' at byte 4 (line 1, column 5), near "he11\nowor1d""#,
+        err.to_string()
+    );
+
+    let err = bsx::decode::Error::InvalidChecksum.with_context("he11owor1d");
+    assert_eq!(None, err.snippet());
+    assert_eq!("provided string had an invalid checksum", err.to_string());
+}
+
+#[test]
+fn test_decode_error_position() {
+    use bsx::decode::{Error, Position};
+
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(200);
+    let mut encoded = bsx::encode(&input)
+        .with_alphabet(alpha)
+        .with_line_wrap(76)
+        .into_string();
+    // Replace the 10th character of the third line
+    let index = 76 * 2 + 2 + 9;
+    encoded.replace_range(index..index + 1, "0");
+
+    let err = bsx::decode(&encoded)
+        .with_alphabet(alpha)
+        .wrapped()
+        .into_vec()
+        .unwrap_err();
+    assert_eq!(
+        Error::InvalidCharacter {
+            character: '0',
+            index
+        },
+        err
+    );
+    assert_eq!(
+        Some(Position {
+            line: 3,
+            column: 10
+        }),
+        err.position(&encoded)
+    );
+    assert_eq!(
+        Some(Position {
+            line: 3,
+            column: 10
+        }),
+        err.with_context(&encoded).position()
+    );
+
+    // Columns count characters
+    assert_eq!(
+        Some(Position { line: 2, column: 3 }),
+        Error::NonAsciiCharacter { index: 8 }.position("a\né\u{1f600}\u{1f600}")
+    );
+    // Lines are only split on `\n`
+    assert_eq!(
+        Some(Position { line: 2, column: 4 }),
+        Error::NonAsciiCharacter { index: 5 }.position("\r\n\r\r\ré")
+    );
+
+    assert_eq!(None, Error::InvalidChecksum.position(&encoded));
+    assert_eq!(None, Error::NonAsciiCharacter { index: 6 }.position("abc"));
+}
+
+#[test]
+fn test_decode_builder_clone_debug() {
+    let builder = bsx::decode("he11owor1d")
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .with_max_len(10);
+    let debug = format!("{:?}", builder);
+    assert!(debug.starts_with("DecodeBuilder {"), "{}", debug);
+    assert!(debug.contains("max_len: Some(10)"), "{}", debug);
+
+    let array = builder.clone().into_array::<8>().unwrap();
+    assert_eq!(array.to_vec(), builder.into_vec().unwrap());
+}
+
+#[test]
+fn test_decode_padding_wrapped() {
+    for len in [0, 1, 10, 100] {
+        let input = cases::input(len);
+        let mut encoded = bsx::encode(&input)
+            .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+            .with_check_symbol()
+            .with_padding('=', 8)
+            .with_line_wrap(5)
+            .into_string();
+        encoded.push('\n');
+        assert_eq!(
+            input,
+            bsx::decode(&encoded)
+                .with_alphabet(bsx::StaticAlphabet::CROCKFORD)
+                .with_check_symbol()
+                .with_padding('=')
+                .wrapped()
+                .into_vec()
+                .unwrap(),
+            "{:?}",
+            encoded
+        );
+    }
+
+    assert_eq!(
+        vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+        bsx::decode("he1\n1ow\nor1\nd==\n")
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .with_padding('=')
+            .wrapped()
+            .into_vec()
+            .unwrap()
+    );
+    assert_eq!(
+        [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+        bsx::decode("he11o\r\nwor1d")
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .wrapped()
+            .into_array()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_decode_base64_padding() {
+    let decode = |input| {
+        bsx::decode(input)
+            .with_alphabet(bsx::StaticAlphabet::BASE64)
+            .with_padding('=')
+            .wrapped()
+            .into_vec()
+    };
+    assert_eq!(b"hello", &*decode("aGVsbG8=").unwrap());
+    assert_eq!(b"hello", &*decode("aGVs\nbG8=\n").unwrap());
+    assert_eq!(b"hi", &*decode("aGk=").unwrap());
+    assert_eq!(b"h", &*decode("aA==").unwrap());
+    for input in ["aGVsbG8====", "aGVsbG8==", "aA=", "aGVsbG8A====", "a==="] {
+        assert_eq!(
+            Err(bsx::decode::Error::InvalidPadding),
+            decode(input),
+            "{}",
+            input
+        );
+    }
+
+    // Only bit-packed alphabets have a block size to check against
+    assert_eq!(
+        vec![0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58],
+        bsx::decode("he11owor1d===")
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .with_padding('=')
+            .into_vec()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_decoder() {
+    let mut decoder = bsx::decode::Decoder::new(bsx::StaticAlphabet::BITCOIN);
+
+    // The buffer is reused, a shorter output must not keep a tail of the previous one
+    let long = cases::input(100);
+    let encoded = bsx::encode(&long)
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .into_string();
+    assert_eq!(long, decoder.decode(&encoded).unwrap());
+    assert_eq!([0x61], decoder.decode("2g").unwrap());
+    assert_eq!(b"", decoder.decode("").unwrap());
+
+    // A failed decode leaves the decoder usable
+    assert_eq!(
+        bsx::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 3
+        },
+        decoder.decode("he10owor1d").unwrap_err()
+    );
+    assert_eq!([0, 0, 0], decoder.decode("111").unwrap());
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(val, decoder.decode(s).unwrap());
+    }
+}
+
+#[test]
+fn test_decode_many() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+
+    assert!(bsx::decode::decode_many::<&str>(&[], alpha)
+        .unwrap()
+        .is_empty());
+
+    let inputs: Vec<&str> = cases::TEST_CASES.iter().map(|&(_, s)| s).collect();
+    let expected: Vec<&[u8]> = cases::TEST_CASES.iter().map(|&(val, _)| val).collect();
+    assert_eq!(expected, bsx::decode::decode_many(&inputs, alpha).unwrap());
+
+    // The first error is returned, indexed within its own input
+    assert_eq!(
+        bsx::decode::Error::InvalidCharacter {
+            character: 'l',
+            index: 1
+        },
+        bsx::decode::decode_many(&["2g", "1l", "0"], alpha).unwrap_err()
+    );
+}
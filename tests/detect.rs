@@ -0,0 +1,67 @@
+mod cases;
+
+#[test]
+fn test_detect() {
+    let alpha = bsx::StaticAlphabet::BASE62;
+    let input = cases::input(100);
+    let encoded = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let candidates: Vec<_> = bsx::detect(&encoded).candidates().collect();
+    assert!(candidates
+        .iter()
+        .any(|candidate| candidate.name == "base62"));
+    for candidate in candidates {
+        assert!(bsx::decode(&encoded)
+            .with_alphabet(candidate.alphabet)
+            .into_vec()
+            .is_ok());
+    }
+
+    // Aliases are only reported once
+    let names: Vec<_> = bsx::detect("cafe")
+        .candidates()
+        .map(|candidate| candidate.name)
+        .collect();
+    assert!(names.contains(&"base16"));
+    assert!(!names.contains(&"hex"));
+    assert!(!names.contains(&"HEX"));
+
+    let binary = bsx::StaticAlphabet::new(b"01").unwrap();
+    let names: Vec<_> = bsx::detect("0110")
+        .with_alphabets(&[("binary", &binary)])
+        .without_builtins()
+        .candidates()
+        .map(|candidate| candidate.name)
+        .collect();
+    assert_eq!(vec!["binary"], names);
+    assert_eq!(
+        0,
+        bsx::detect("0112")
+            .with_alphabets(&[("binary", &binary)])
+            .without_builtins()
+            .candidates()
+            .count()
+    );
+}
+
+#[test]
+fn test_detect_builder_clone_debug() {
+    let binary = bsx::StaticAlphabet::new(b"01").unwrap();
+    let alphabets: [(&str, &dyn bsx::Alphabet); 1] = [("binary", &binary)];
+    let builder = bsx::detect("0110")
+        .with_alphabets(&alphabets)
+        .without_builtins();
+    let debug = format!("{:?}", builder);
+    assert!(debug.starts_with("DetectBuilder {"), "{}", debug);
+    assert!(debug.contains("builtins: false"), "{}", debug);
+    assert!(
+        debug.contains(r#"Candidate { name: "binary", len: 2 }"#),
+        "{}",
+        debug
+    );
+
+    assert_eq!(
+        builder.clone().candidates().count(),
+        builder.candidates().count()
+    );
+}
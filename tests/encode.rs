@@ -51,3 +51,981 @@ fn test_encode() {
         }
     }
 }
+
+#[test]
+fn test_encode_int() {
+    for &val in &[0, 1, 57, 58, u64::MAX as u128, u128::MAX] {
+        let encoded = bsx::encode_int(val)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .into_string();
+        assert_eq!(
+            val,
+            bsx::decode(&encoded)
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .into_u128()
+                .unwrap()
+        );
+        assert!(encoded.len() == 1 || !encoded.starts_with('1'));
+    }
+}
+
+#[test]
+fn test_encoded_len() {
+    let chars: Vec<u8> = (0..128).collect();
+    for base in 2..=128 {
+        let alpha = bsx::DynamicAlphabet::new(&chars[..base]).unwrap();
+        for len in 0..40 {
+            let encoded = bsx::encode(vec![0xFF; len])
+                .with_alphabet(&alpha)
+                .into_vec();
+            let bound = bsx::encoded_len(len, base);
+            assert!(encoded.len() <= bound, "base {} len {}", base, len);
+            assert!(encoded.len() + 1 >= bound, "base {} len {}", base, len);
+            assert!(bsx::decoded_len(encoded.len(), base) >= len);
+        }
+    }
+}
+
+#[test]
+fn test_encode_large() {
+    for &len in &[2047, 2048, 5000] {
+        let mut input = cases::input(len);
+        input[1] = 0;
+        let encoded = bsx::encode(&input)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .into_string();
+        assert_eq!(bs58::encode(&input).into_string(), encoded, "len {}", len);
+    }
+}
+
+#[test]
+fn test_encode_large_power_of_two() {
+    let chars: Vec<u8> = (0..128).collect();
+    let mut input = cases::input(70_001);
+    input[1] = 0;
+
+    let hex = bsx::DynamicAlphabet::new("0123456789abcdef").unwrap();
+    let expected: String = input.iter().map(|b| format!("{:02x}", b)).collect();
+    let encoded = bsx::encode(&input).with_alphabet(&hex).into_string();
+    // The leading zero bytes are each encoded as a single zero digit
+    assert_eq!(&expected[4..].trim_start_matches('0'), &&encoded[2..]);
+    assert_eq!("00", &encoded[..2]);
+
+    for bits in 1..=7 {
+        let alpha = bsx::DynamicAlphabet::new(&chars[..1 << bits]).unwrap();
+        let encoded = bsx::encode(&input).with_alphabet(&alpha).into_vec();
+        let decoded = bsx::decode(&encoded)
+            .with_alphabet(&alpha)
+            .into_vec()
+            .unwrap();
+        assert_eq!(input, decoded, "base {}", 1 << bits);
+    }
+}
+
+#[test]
+fn test_encode_static_matches_dynamic() {
+    const BASE10: &bsx::StaticAlphabet<10> = &bsx::StaticAlphabet::new_unwrap(b"0123456789");
+    let base10 = bsx::DynamicAlphabet::new("0123456789").unwrap();
+    let base58 =
+        bsx::DynamicAlphabet::new("123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz")
+            .unwrap();
+
+    for len in [0, 1, 15, 16, 17, 100, 3000] {
+        let input: Vec<u8> = (0..len).map(|i| (i * 37 % 256) as u8).collect();
+        assert_eq!(
+            bsx::encode(&input).with_alphabet(&base10).into_string(),
+            bsx::encode(&input).with_alphabet(BASE10).into_string(),
+        );
+        let encoded = bsx::encode(&input).with_alphabet(&base58).into_string();
+        assert_eq!(
+            encoded,
+            bsx::encode(&input)
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .into_string(),
+        );
+        assert_eq!(
+            input,
+            bsx::decode(&encoded)
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .into_vec()
+                .unwrap(),
+        );
+    }
+}
+
+#[test]
+fn test_encode_const() {
+    const ENCODED: bsx::encode::EncodedArray<14> = bsx::encode_const(
+        &[0xec, 0xac, 0x89, 0xca, 0xd9, 0x39, 0x23, 0xc0, 0x23, 0x21],
+        bsx::StaticAlphabet::BITCOIN,
+    );
+    assert_eq!("EJDM8drfXA6uyA", ENCODED.as_str());
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(
+            s,
+            bsx::encode_const::<64, 58>(val, bsx::StaticAlphabet::BITCOIN).as_str()
+        );
+    }
+}
+
+#[test]
+fn test_encode_const_macro() {
+    const ENCODED: &str = bsx::encode_const!(
+        &[0xec, 0xac, 0x89, 0xca, 0xd9, 0x39, 0x23, 0xc0, 0x23, 0x21],
+        BITCOIN
+    );
+    assert_eq!("EJDM8drfXA6uyA", ENCODED);
+    assert_eq!("1111", bsx::encode_const!(&[0, 0, 0, 0], BITCOIN));
+    assert_eq!("", bsx::encode_const!(&[], BITCOIN));
+}
+
+#[test]
+fn test_encode_extended() {
+    let chars: Vec<u8> = (0..=255).rev().collect();
+    for base in [129, 200, 255] {
+        let alpha = bsx::DynamicAlphabet::new_extended(&chars[..base]).unwrap();
+        for len in [0, 1, 10] {
+            let mut input = cases::input(len);
+            if len > 1 {
+                input[0] = 0;
+            }
+            let encoded = bsx::encode(&input).with_alphabet(&alpha).into_vec();
+            assert_eq!(
+                input,
+                bsx::decode(&encoded)
+                    .with_alphabet(&alpha)
+                    .into_vec()
+                    .unwrap(),
+                "base {} len {}",
+                base,
+                len,
+            );
+        }
+    }
+
+    assert_eq!(
+        Err(bsx::alphabet::Error::TooLong),
+        bsx::DynamicAlphabet::new_extended(&chars[..]).map(|_| ())
+    );
+    for short in [&chars[..0], &chars[..1]] {
+        assert_eq!(
+            Err(bsx::alphabet::Error::TooShort),
+            bsx::DynamicAlphabet::new_extended(short).map(|_| ())
+        );
+    }
+
+    let alpha = bsx::DynamicAlphabet::new_extended(&chars[..200]).unwrap();
+    let mut output = String::from("~~~~~~~~");
+    assert_eq!(
+        Err(bsx::encode::Error::InvalidUtf8),
+        bsx::encode([0x01])
+            .with_alphabet(&alpha)
+            .into(&mut output[..])
+    );
+    assert!(output.chars().all(|c| c == '~' || c == '\0'));
+}
+
+#[test]
+fn test_encode_char_alphabet() {
+    let cjk: Vec<char> = ('一'..).take(255).collect();
+    let ascii: Vec<char> = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+        .chars()
+        .collect();
+    let cjk = bsx::CharAlphabet::new(cjk).unwrap();
+    let bitcoin = bsx::CharAlphabet::new(ascii).unwrap();
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(s, bitcoin.encode(val));
+        assert_eq!(val, &*bitcoin.decode(s).unwrap());
+
+        let encoded = cjk.encode(val);
+        assert_eq!(val, &*cjk.decode(&encoded).unwrap());
+    }
+}
+
+#[test]
+fn test_encode_separator() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        for every in [1, 3, 4, 100] {
+            let expected: Vec<&str> = s
+                .as_bytes()
+                .chunks(every)
+                .map(|chunk| core::str::from_utf8(chunk).unwrap())
+                .collect();
+            let builder = bsx::encode(val)
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .with_separator('_', every);
+            assert!(builder.max_encoded_len() >= expected.join("_").len());
+            let encoded = builder.into_string();
+            assert_eq!(expected.join("_"), encoded);
+            assert_eq!(
+                val,
+                &*bsx::decode(&encoded)
+                    .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                    .ignore_chars("_")
+                    .into_vec()
+                    .unwrap()
+            );
+        }
+    }
+
+    assert_eq!(
+        "zhe1-1ow-or1-d",
+        bsx::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58])
+            .with_multibase_prefix(bsx::multibase::Base::Base58Btc)
+            .with_separator('-', 3)
+            .into_string()
+    );
+
+    let mut output = [0; 11];
+    assert_eq!(
+        Err(bsx::encode::Error::BufferTooSmall { required: 13 }),
+        bsx::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58])
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .with_separator('-', 4)
+            .into(&mut output[..])
+    );
+}
+
+#[test]
+fn test_encode_min_width() {
+    let width = bsx::encoded_len(16, 58);
+    let mut encoded: Vec<String> = Vec::new();
+    for value in [0, 1, 57, 58, u64::MAX as u128, u128::MAX - 1, u128::MAX] {
+        let input = value.to_be_bytes();
+        let output = bsx::encode(input)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .with_min_width(width)
+            .into_string();
+        assert_eq!(width, output.len());
+        assert_eq!(
+            input,
+            bsx::decode(&output)
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .padded()
+                .into_array()
+                .unwrap()
+        );
+        assert_eq!(
+            value,
+            bsx::decode(&output)
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .into_u128()
+                .unwrap()
+        );
+        encoded.push(output);
+    }
+    // The bitcoin alphabet is in ASCII order, so fixed width output sorts numerically
+    assert!(encoded.windows(2).all(|pair| pair[0] < pair[1]));
+
+    assert_eq!(
+        "1111-1Buk-QL",
+        bsx::encode_int(123456789u32)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .with_min_width(10)
+            .with_separator('-', 4)
+            .into_string()
+    );
+    assert_eq!(
+        "he11owor1d",
+        bsx::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58])
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .with_min_width(4)
+            .into_string()
+    );
+}
+
+#[test]
+fn test_encode_line_wrap() {
+    let input = cases::input(1000);
+    let unwrapped = bsx::encode(&input)
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .into_string();
+    let builder = bsx::encode(&input)
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .with_line_wrap(76);
+    let max_encoded_len = builder.max_encoded_len();
+    let wrapped = builder.into_string();
+    assert!(wrapped.len() <= max_encoded_len);
+
+    let lines: Vec<&str> = wrapped.split('\n').collect();
+    assert!(lines[..lines.len() - 1].iter().all(|line| line.len() == 76));
+    assert!(!lines[lines.len() - 1].is_empty());
+    assert_eq!(unwrapped, lines.concat());
+    assert_eq!(
+        input,
+        bsx::decode(&wrapped)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .wrapped()
+            .into_vec()
+            .unwrap()
+    );
+
+    assert_eq!(
+        "zhe\n11o\nwor\n1d",
+        bsx::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58])
+            .with_multibase_prefix(bsx::multibase::Base::Base58Btc)
+            .with_line_wrap(3)
+            .into_string()
+    );
+}
+
+#[test]
+fn test_encode_forced_case() {
+    let input = cases::input(100);
+    let base32 =
+        bsx::StaticAlphabet::new_case_insensitive(b"abcdefghijklmnopqrstuvwxyz234567").unwrap();
+    let lower = bsx::encode(&input).with_alphabet(&base32).into_string();
+    let upper = bsx::encode(&input)
+        .with_alphabet(&base32)
+        .uppercase()
+        .into_string();
+    assert_eq!(lower.to_uppercase(), upper);
+    assert_eq!(
+        lower,
+        bsx::encode(&input)
+            .with_alphabet(&base32)
+            .uppercase()
+            .lowercase()
+            .into_string()
+    );
+
+    // Only the letters without a distinct other case in the alphabet are changed
+    let encoded = bsx::encode(&input)
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .lowercase()
+        .into_string();
+    assert_eq!(
+        input,
+        bsx::decode(&encoded)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .case_insensitive()
+            .into_vec()
+            .unwrap()
+    );
+    assert!(!encoded.contains('L'));
+}
+
+#[test]
+fn test_encode_bijective() {
+    let chars: Vec<u8> = (0..=255).collect();
+    for base in [2, 3, 10, 26, 58, 200, 255] {
+        let alpha = bsx::DynamicAlphabet::new_extended(&chars[..base]).unwrap();
+        for value in (0..2000u128).chain([u64::MAX as u128, u128::MAX - 1, u128::MAX]) {
+            let mut expected = Vec::new();
+            let mut n = value;
+            while n > 0 {
+                n -= 1;
+                expected.push((n % base as u128) as u8);
+                n /= base as u128;
+            }
+            expected.reverse();
+
+            let encoded = bsx::encode_int(value)
+                .with_alphabet(&alpha)
+                .bijective()
+                .into_vec();
+            assert_eq!(expected, encoded, "base {} value {}", base, value);
+            assert_eq!(
+                value,
+                bsx::decode(&encoded)
+                    .with_alphabet(&alpha)
+                    .bijective()
+                    .into_u128()
+                    .unwrap(),
+                "base {} value {}",
+                base,
+                value,
+            );
+        }
+    }
+
+    let input: Vec<u8> = cases::input(3000).into_iter().map(|b| b | 1).collect();
+    let encoded = bsx::encode(&input)
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .bijective()
+        .into_string();
+    assert_eq!(
+        input,
+        bsx::decode(&encoded)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .bijective()
+            .into_vec()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_encode_little_endian() {
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let reversed: String = s.chars().rev().collect();
+        let encoded = bsx::encode(val)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .little_endian()
+            .into_string();
+        assert_eq!(reversed, encoded);
+        assert_eq!(
+            val,
+            &*bsx::decode(&encoded)
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .little_endian()
+                .into_vec()
+                .unwrap()
+        );
+    }
+
+    let encoded = bsx::encode_int(123456789u32)
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .little_endian()
+        .with_min_width(10)
+        .into_string();
+    assert_eq!("LQkuB11111", encoded);
+    assert_eq!(
+        [0x00, 0x07, 0x5b, 0xcd, 0x15],
+        bsx::decode(&encoded)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .little_endian()
+            .padded()
+            .into_array()
+            .unwrap()
+    );
+    assert_eq!(
+        123456789,
+        bsx::decode(&encoded)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .little_endian()
+            .into_u128()
+            .unwrap()
+    );
+    assert_eq!(
+        bsx::decode::Error::InvalidCharacter {
+            character: '0',
+            index: 1
+        },
+        bsx::decode("L0-kuB")
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .little_endian()
+            .ignore_chars("-")
+            .into_vec()
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_encode_leading_zeros() {
+    use bsx::{encode::LeadingZeros, Alphabet};
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        let zeros = val.iter().take_while(|&&byte| byte == 0).count();
+        let digits = s.trim_start_matches('1');
+
+        let encoded = bsx::encode(val)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .with_leading_zeros(LeadingZeros::Ignore)
+            .into_string();
+        let expected = if digits.is_empty() && !val.is_empty() {
+            "1"
+        } else {
+            digits
+        };
+        assert_eq!(expected, encoded);
+        assert_eq!(
+            &val[zeros..],
+            &*bsx::decode(&encoded)
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .with_leading_zeros(LeadingZeros::Ignore)
+                .into_vec()
+                .unwrap()
+        );
+
+        let encoded = bsx::encode(val)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .with_leading_zeros(LeadingZeros::CountPrefix)
+            .into_string();
+        let count = bsx::StaticAlphabet::BITCOIN.encode()[zeros] as char;
+        assert_eq!(format!("{}{}", count, digits), encoded);
+        assert_eq!(
+            val,
+            &*bsx::decode(&encoded)
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .with_leading_zeros(LeadingZeros::CountPrefix)
+                .into_vec()
+                .unwrap()
+        );
+    }
+
+    let encoded = bsx::encode([0x00, 0x00, 0x01])
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .with_leading_zeros(LeadingZeros::CountPrefix)
+        .little_endian()
+        .with_min_width(4)
+        .into_string();
+    assert_eq!("32111", encoded);
+    assert_eq!(
+        vec![0x00, 0x00, 0x01],
+        bsx::decode(&encoded)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .with_leading_zeros(LeadingZeros::CountPrefix)
+            .little_endian()
+            .into_vec()
+            .unwrap()
+    );
+
+    assert_eq!(
+        bsx::encode::Error::TooManyLeadingZeros,
+        bsx::encode([0; 58])
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .with_leading_zeros(LeadingZeros::CountPrefix)
+            .into(&mut String::new())
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_encode_base64() {
+    // RFC 4648 section 10
+    let vectors = [
+        ("", ""),
+        ("f", "Zg=="),
+        ("fo", "Zm8="),
+        ("foo", "Zm9v"),
+        ("foob", "Zm9vYg=="),
+        ("fooba", "Zm9vYmE="),
+        ("foobar", "Zm9vYmFy"),
+    ];
+    for (input, expected) in vectors {
+        assert_eq!(
+            expected,
+            bsx::encode(input)
+                .with_alphabet(bsx::StaticAlphabet::BASE64)
+                .with_padding('=', 4)
+                .into_string()
+        );
+        assert_eq!(
+            input.as_bytes(),
+            bsx::decode(expected)
+                .with_alphabet(bsx::StaticAlphabet::BASE64)
+                .with_padding('=')
+                .into_vec()
+                .unwrap()
+        );
+    }
+
+    // Leading zero bytes are packed like any other
+    assert_eq!(
+        "AAD/",
+        bsx::encode([0, 0, 0xff])
+            .with_alphabet(bsx::StaticAlphabet::BASE64)
+            .into_string()
+    );
+}
+
+#[test]
+fn test_encode_padding() {
+    for len in [0, 1, 2, 3, 100] {
+        let input = cases::input(len);
+        let unpadded = bsx::encode(&input)
+            .with_alphabet(bsx::StaticAlphabet::BASE64)
+            .into_string();
+        let encoded = bsx::encode(&input)
+            .with_alphabet(bsx::StaticAlphabet::BASE64)
+            .with_padding('=', 4)
+            .into_string();
+        assert_eq!(0, encoded.len() % 4);
+        assert_eq!(unpadded, encoded.trim_end_matches('='));
+        assert_eq!(
+            input,
+            bsx::decode(&encoded)
+                .with_alphabet(bsx::StaticAlphabet::BASE64)
+                .with_padding('=')
+                .into_vec()
+                .unwrap()
+        );
+    }
+
+    let encoded = bsx::encode([0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58])
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .with_separator('-', 4)
+        .with_padding('.', 8)
+        .into_string();
+    assert_eq!("he11-owor-1d....", encoded);
+}
+
+#[test]
+fn test_alphabet_too_short() {
+    use bsx::alphabet::Error;
+
+    assert_eq!(Some(Error::TooShort), bsx::StaticAlphabet::new(b"").err());
+    assert_eq!(Some(Error::TooShort), bsx::StaticAlphabet::new(b"a").err());
+    assert_eq!(
+        Some(Error::TooShort),
+        bsx::StaticAlphabet::new_case_insensitive(b"a").err()
+    );
+    assert_eq!(Some(Error::TooShort), bsx::DynamicAlphabet::new("a").err());
+    assert_eq!(
+        Some(Error::TooShort),
+        bsx::DynamicAlphabet::parse("custom()").err()
+    );
+    assert_eq!(Some(Error::TooShort), bsx::CharAlphabet::new(['あ']).err());
+
+    let binary = bsx::StaticAlphabet::new(b"01").unwrap();
+    assert_eq!(
+        "100000001",
+        bsx::encode([0x01, 0x01])
+            .with_alphabet(&binary)
+            .into_string()
+    );
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn test_encode_arrayvec() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let expected = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let mut string = arrayvec::ArrayString::<200>::new();
+    string.push_str("stale");
+    bsx::encode(&input)
+        .with_alphabet(alpha)
+        .into(&mut string)
+        .unwrap();
+    assert_eq!(expected, string.as_str());
+
+    let mut vec = arrayvec::ArrayVec::<u8, 200>::new();
+    bsx::encode(&input)
+        .with_alphabet(alpha)
+        .into(&mut vec)
+        .unwrap();
+    assert_eq!(expected.as_bytes(), &vec[..]);
+
+    let mut small = arrayvec::ArrayString::<100>::new();
+    assert_eq!(
+        bsx::encode::Error::BufferTooSmall { required: 137 },
+        bsx::encode(&input)
+            .with_alphabet(alpha)
+            .into(&mut small)
+            .unwrap_err()
+    );
+    assert_eq!("", small.as_str());
+
+    let extended = bsx::DynamicAlphabet::new_extended((128..=255).collect::<Vec<u8>>()).unwrap();
+    assert_eq!(
+        bsx::encode::Error::InvalidUtf8,
+        bsx::encode(&input)
+            .with_alphabet(&extended)
+            .into(&mut string)
+            .unwrap_err()
+    );
+    assert_eq!("", string.as_str());
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_encode_heapless() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let expected = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let mut string = heapless::String::<200>::new();
+    string.push_str("stale").unwrap();
+    bsx::encode(&input)
+        .with_alphabet(alpha)
+        .into(&mut string)
+        .unwrap();
+    assert_eq!(expected, string.as_str());
+
+    let mut vec = heapless::Vec::<u8, 200>::new();
+    bsx::encode(&input)
+        .with_alphabet(alpha)
+        .into(&mut vec)
+        .unwrap();
+    assert_eq!(expected.as_bytes(), &vec[..]);
+
+    let mut small = heapless::String::<100>::new();
+    assert_eq!(
+        bsx::encode::Error::BufferTooSmall { required: 137 },
+        bsx::encode(&input)
+            .with_alphabet(alpha)
+            .into(&mut small)
+            .unwrap_err()
+    );
+    assert_eq!("", small.as_str());
+
+    let extended = bsx::DynamicAlphabet::new_extended((128..=255).collect::<Vec<u8>>()).unwrap();
+    assert_eq!(
+        bsx::encode::Error::InvalidUtf8,
+        bsx::encode(&input)
+            .with_alphabet(&extended)
+            .into(&mut string)
+            .unwrap_err()
+    );
+    assert_eq!("", string.as_str());
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn test_encode_smallvec() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let expected = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let mut inline = smallvec::SmallVec::<[u8; 256]>::new();
+    inline.push(0xFF);
+    bsx::encode(&input)
+        .with_alphabet(alpha)
+        .into(&mut inline)
+        .unwrap();
+    assert_eq!(expected.as_bytes(), &inline[..]);
+    assert!(!inline.spilled());
+
+    let mut spilled = smallvec::SmallVec::<[u8; 16]>::new();
+    bsx::encode(&input)
+        .with_alphabet(alpha)
+        .into(&mut spilled)
+        .unwrap();
+    assert_eq!(expected.as_bytes(), &spilled[..]);
+    assert!(spilled.spilled());
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn test_encode_bytes() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let expected = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let mut output = bytes::BytesMut::from(&b"stale"[..]);
+    bsx::encode(&input)
+        .with_alphabet(alpha)
+        .into(&mut output)
+        .unwrap();
+    assert_eq!(expected.as_bytes(), &output[..]);
+}
+
+#[test]
+fn test_encode_fmt() {
+    use core::fmt::{self, Write};
+
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+
+    for len in [0, 100, 1000] {
+        let input = cases::input(len);
+        let expected = bsx::encode(&input).with_alphabet(alpha).into_string();
+        let mut output = String::from("prefix:");
+        let written = bsx::encode(&input)
+            .with_alphabet(alpha)
+            .into_fmt(&mut output)
+            .unwrap();
+        assert_eq!(expected.len(), written);
+        assert_eq!(format!("prefix:{}", expected), output);
+    }
+
+    struct Failing;
+
+    impl Write for Failing {
+        fn write_str(&mut self, _: &str) -> fmt::Result {
+            Err(fmt::Error)
+        }
+    }
+
+    assert_eq!(
+        bsx::encode::Error::Fmt,
+        bsx::encode([1, 2, 3])
+            .with_alphabet(alpha)
+            .into_fmt(&mut Failing)
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_encode_display() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+
+    for len in [0, 100, 1000] {
+        let input = cases::input(len);
+        let expected = bsx::encode(&input).with_alphabet(alpha).into_string();
+        let display = bsx::encode(&input).with_alphabet(alpha).into_display();
+        assert_eq!(expected, display.to_string());
+        // Formatting again re-encodes the same input
+        assert_eq!(format!("<{}>", expected), format!("<{}>", display));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_encode_writer() {
+    use std::io;
+
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+
+    for len in [0, 100, 1000] {
+        let input = cases::input(len);
+        let expected = bsx::encode(&input).with_alphabet(alpha).into_string();
+        let mut output = io::Cursor::new(Vec::new());
+        let written = bsx::encode(&input)
+            .with_alphabet(alpha)
+            .into_writer(&mut output)
+            .unwrap();
+        assert_eq!(expected.len(), written);
+        assert_eq!(expected.as_bytes(), &output.into_inner()[..]);
+    }
+
+    let mut output = [0; 4];
+    let err = bsx::encode([0xff; 8])
+        .with_alphabet(alpha)
+        .into_writer(&mut output[..])
+        .unwrap_err();
+    assert_eq!(io::ErrorKind::WriteZero, err.kind());
+
+    let err = bsx::encode([0; 100])
+        .with_alphabet(alpha)
+        .with_leading_zeros(bsx::encode::LeadingZeros::CountPrefix)
+        .into_writer(io::sink())
+        .unwrap_err();
+    assert_eq!(io::ErrorKind::InvalidInput, err.kind());
+}
+
+#[test]
+fn test_encode_uninit() {
+    use core::mem::MaybeUninit;
+
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let expected = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let mut output = [MaybeUninit::uninit(); 256];
+    let encoded = bsx::encode(&input)
+        .with_alphabet(alpha)
+        .into_uninit(&mut output)
+        .unwrap();
+    assert_eq!(expected.as_bytes(), encoded);
+
+    let mut small = [MaybeUninit::uninit(); 16];
+    assert_eq!(
+        bsx::encode::Error::BufferTooSmall { required: 137 },
+        bsx::encode(&input)
+            .with_alphabet(alpha)
+            .into(&mut small[..])
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn test_encode_onto() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+    let input = cases::input(100);
+    let expected = bsx::encode(&input).with_alphabet(alpha).into_string();
+
+    let mut output = String::from("prefix:");
+    bsx::encode(&input)
+        .with_alphabet(alpha)
+        .onto_string(&mut output)
+        .unwrap();
+    bsx::encode(&input)
+        .with_alphabet(alpha)
+        .onto_string(&mut output)
+        .unwrap();
+    assert_eq!(format!("prefix:{}{}", expected, expected), output);
+
+    let mut output = b"prefix:".to_vec();
+    bsx::encode(&input)
+        .with_alphabet(alpha)
+        .onto_vec(&mut output)
+        .unwrap();
+    assert_eq!(format!("prefix:{}", expected).as_bytes(), &output[..]);
+
+    let alpha = bsx::StaticAlphabet::new_extended(&[0x80, 0x81]).unwrap();
+    let mut output = String::from("prefix:");
+    assert_eq!(
+        bsx::encode::Error::InvalidUtf8,
+        bsx::encode([1])
+            .with_alphabet(&alpha)
+            .onto_string(&mut output)
+            .unwrap_err()
+    );
+    assert_eq!("prefix:", output);
+}
+
+#[test]
+fn test_encode_builder_clone_debug() {
+    let builder = bsx::encode([0xde, 0xad])
+        .with_alphabet(bsx::StaticAlphabet::BASE16)
+        .with_min_width(6);
+    let debug = format!("{:?}", builder);
+    assert!(debug.starts_with("EncodeBuilder {"), "{}", debug);
+    assert!(debug.contains("min_width: 6"), "{}", debug);
+
+    let upper = builder.clone().uppercase();
+    assert_eq!("00dead", builder.into_string());
+    assert_eq!("00DEAD", upper.into_string());
+
+    let display = bsx::encode([0xde, 0xad])
+        .with_alphabet(bsx::StaticAlphabet::BASE16)
+        .into_display();
+    let debug = format!("{:?}", display);
+    assert!(debug.starts_with("EncodeDisplay {"), "{}", debug);
+    assert_eq!(display.clone().to_string(), display.to_string());
+}
+
+#[test]
+fn test_encode_overflow() {
+    assert_eq!(usize::MAX, bsx::encoded_len(usize::MAX, 2));
+
+    let builder = bsx::encode([0x01])
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .with_min_width(usize::MAX)
+        .with_separator('-', 4);
+    assert_eq!(usize::MAX, builder.max_encoded_len());
+    assert_eq!(
+        bsx::encode::Error::Overflow,
+        builder.into(&mut String::new()).unwrap_err()
+    );
+
+    let builder = bsx::encode([0x01])
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .with_min_width(usize::MAX)
+        .with_check_symbol();
+    assert_eq!(
+        bsx::encode::Error::Overflow,
+        builder.into(&mut [0; 8][..]).unwrap_err()
+    );
+}
+
+#[test]
+fn test_encoder() {
+    let mut encoder = bsx::encode::Encoder::new(bsx::StaticAlphabet::BITCOIN);
+
+    // The buffer is reused, a shorter output must not keep a tail of the previous one
+    let long = cases::input(100);
+    let expected = bsx::encode(&long)
+        .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .into_string();
+    assert_eq!(expected, encoder.encode(&long));
+    assert_eq!("2g", encoder.encode([0x61]));
+    assert_eq!("", encoder.encode([]));
+    assert_eq!("111", encoder.encode([0, 0, 0]));
+
+    for &(val, s) in cases::TEST_CASES.iter() {
+        assert_eq!(s, encoder.encode(val));
+    }
+}
+
+#[test]
+fn test_encode_many() {
+    let alpha = bsx::StaticAlphabet::BITCOIN;
+
+    assert!(bsx::encode::encode_many::<&[u8]>(&[], alpha).is_empty());
+
+    let inputs: Vec<&[u8]> = cases::TEST_CASES.iter().map(|&(val, _)| val).collect();
+    let expected: Vec<&str> = cases::TEST_CASES.iter().map(|&(_, s)| s).collect();
+    assert_eq!(expected, bsx::encode::encode_many(&inputs, alpha));
+}
+
+#[test]
+#[should_panic(expected = "InvalidUtf8")]
+fn test_encoder_extended_panics() {
+    let alpha = bsx::StaticAlphabet::new_extended(&[0x80, 0x81]).unwrap();
+    bsx::encode::Encoder::new(alpha).encode([1]);
+}
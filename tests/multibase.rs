@@ -0,0 +1,47 @@
+use bsx::multibase::Base;
+
+#[test]
+fn test_multibase_base32() {
+    // https://datatracker.ietf.org/doc/html/rfc4648#section-10, lowercased and unpadded
+    let vectors: &[(&[u8], &str)] = &[
+        (b"", "b"),
+        (b"f", "bmy"),
+        (b"fo", "bmzxq"),
+        (b"foo", "bmzxw6"),
+        (b"foob", "bmzxw6yq"),
+        (b"fooba", "bmzxw6ytb"),
+        (b"foobar", "bmzxw6ytboi"),
+        (&[0x00, 0x00, 0x00, 0x00, 0x00], "baaaaaaaa"),
+        (&[0x00, 0x01], "baaaq"),
+    ];
+    for &(input, expected) in vectors {
+        let builder = bsx::encode(input).with_multibase_prefix(Base::Base32);
+        assert!(expected.len() <= builder.max_encoded_len());
+        assert_eq!(expected, builder.into_string());
+        assert_eq!(
+            (Base::Base32, input.to_vec()),
+            bsx::multibase::decode(expected).unwrap()
+        );
+    }
+
+    assert_eq!(Some(Base::Base32), Base::from_code('b'));
+    assert_eq!(
+        bsx::multibase::Error::Decode(bsx::decode::Error::InvalidCharacter {
+            character: '1',
+            index: 3
+        }),
+        bsx::multibase::decode("bmz1w6").unwrap_err()
+    );
+}
+
+#[test]
+fn test_multibase_with_alphabet_clears_prefix() {
+    let input = [0x04, 0x30, 0x5e, 0x2b, 0x24, 0x73, 0xf0, 0x58];
+    assert_eq!(
+        "he11owor1d",
+        bsx::encode(input)
+            .with_multibase_prefix(Base::Base58Flickr)
+            .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .into_string()
+    );
+}
@@ -0,0 +1,67 @@
+//! Cross-checks encoding and decoding against a straightforward reference implementation built on
+//! `num-bigint`, across random alphabets of every supported ASCII base.
+
+mod proptest {
+    use ::proptest::{collection::vec, prelude::*};
+    use num_bigint::BigUint;
+
+    /// A random alphabet of between 2 and 128 distinct ASCII characters.
+    fn alphabet() -> impl Strategy<Value = Vec<u8>> {
+        (2usize..=128).prop_flat_map(|base| {
+            Just((0u8..128).collect::<Vec<u8>>())
+                .prop_shuffle()
+                .prop_map(move |mut alphabet| {
+                    alphabet.truncate(base);
+                    alphabet
+                })
+        })
+    }
+
+    /// Random input, biased towards having some leading zeros.
+    fn input() -> impl Strategy<Value = Vec<u8>> {
+        (vec(Just(0u8), 0..4), vec(any::<u8>(), 0..64)).prop_map(|(mut zeros, rest)| {
+            zeros.extend(rest);
+            zeros
+        })
+    }
+
+    fn reference_encode(alphabet: &[u8], input: &[u8]) -> Vec<u8> {
+        let zeros = input.iter().take_while(|&&b| b == 0).count();
+        let mut output = vec![alphabet[0]; zeros];
+        if zeros < input.len() {
+            let value = BigUint::from_bytes_be(&input[zeros..]);
+            output.extend(
+                value
+                    .to_radix_be(alphabet.len() as u32)
+                    .into_iter()
+                    .map(|digit| alphabet[usize::from(digit)]),
+            );
+        }
+        output
+    }
+
+    proptest! {
+        #[test]
+        fn encode_matches_reference(alphabet in alphabet(), input in input()) {
+            let alpha = bsx::DynamicAlphabet::new(&alphabet[..]).unwrap();
+            let encoded = bsx::encode(&input).with_alphabet(&alpha).into_vec();
+            prop_assert_eq!(reference_encode(&alphabet, &input), encoded);
+        }
+
+        #[test]
+        fn decode_matches_reference(alphabet in alphabet(), input in input()) {
+            let alpha = bsx::DynamicAlphabet::new(&alphabet[..]).unwrap();
+            let encoded = reference_encode(&alphabet, &input);
+            let decoded = bsx::decode(&encoded).with_alphabet(&alpha).into_vec().unwrap();
+            prop_assert_eq!(input, decoded);
+        }
+
+        #[test]
+        fn max_lengths_are_upper_bounds(alphabet in alphabet(), input in input()) {
+            let alpha = bsx::DynamicAlphabet::new(&alphabet[..]).unwrap();
+            let encoded = reference_encode(&alphabet, &input);
+            prop_assert!(encoded.len() <= bsx::encode(&input).with_alphabet(&alpha).max_encoded_len());
+            prop_assert!(input.len() <= bsx::decode(&encoded).with_alphabet(&alpha).max_decoded_len());
+        }
+    }
+}
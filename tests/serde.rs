@@ -0,0 +1,126 @@
+#![cfg(feature = "serde")]
+
+mod cases;
+
+use bsx::DynamicAlphabet;
+
+#[test]
+fn test_roundtrip() {
+    let alpha = DynamicAlphabet::new("abcdefghijklmnopqrstuvwxyz").unwrap();
+    let json = serde_json::to_string(&alpha).unwrap();
+    assert_eq!(r#""abcdefghijklmnopqrstuvwxyz""#, json);
+    let alpha: DynamicAlphabet<Vec<u8>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        "hello",
+        bsx::encode([0x32, 0x00, 0x48])
+            .with_alphabet(&alpha)
+            .into_string()
+    );
+}
+
+#[test]
+fn test_errors() {
+    let err = serde_json::from_str::<DynamicAlphabet<Vec<u8>>>(r#""aé""#).unwrap_err();
+    assert_eq!(
+        "alphabet contained a non-ascii character at 1 at line 1 column 5",
+        err.to_string()
+    );
+    assert!(serde_json::from_str::<DynamicAlphabet<Vec<u8>>>("[97, 98]").is_err());
+
+    let extended = DynamicAlphabet::new_extended(vec![b'a', 0xFF]).unwrap();
+    assert!(serde_json::to_string(&extended).is_err());
+}
+
+#[test]
+fn test_with_modules() {
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Fields {
+        #[serde(with = "bsx::serde::bitcoin")]
+        array: [u8; 4],
+        #[serde(with = "bsx::serde::base16")]
+        vec: Vec<u8>,
+        #[serde(
+            serialize_with = "bsx::serde::serialize::<bsx::serde::Base64, _, _>",
+            deserialize_with = "bsx::serde::deserialize::<bsx::serde::Base64, _, _>"
+        )]
+        generic: Vec<u8>,
+    }
+
+    let input = cases::input(32);
+    let fields = Fields {
+        array: [0, 1, 2, 3],
+        vec: input.clone(),
+        generic: input.clone(),
+    };
+    let json = serde_json::to_string(&fields).unwrap();
+    assert_eq!(
+        format!(
+            r#"{{"array":"{}","vec":"{}","generic":"{}"}}"#,
+            bsx::encode([0, 1, 2, 3])
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .into_string(),
+            bsx::encode(&input)
+                .with_alphabet(bsx::StaticAlphabet::BASE16)
+                .into_string(),
+            bsx::encode(&input)
+                .with_alphabet(bsx::StaticAlphabet::BASE64)
+                .into_string(),
+        ),
+        json
+    );
+    assert_eq!(fields, serde_json::from_str(&json).unwrap());
+
+    // Fixed size arrays check the decoded length
+    let err = serde_json::from_str::<Fields>(r#"{"array":"he11owor1d","vec":"","generic":""}"#)
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("provided string did not decode to exactly 4 bytes"));
+
+    let err =
+        serde_json::from_str::<Fields>(r#"{"array":"1Ldp","vec":"0g","generic":""}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("provided string contained invalid character 'g' at byte 1"));
+}
+
+#[test]
+fn test_encoded() {
+    use bsx::serde::{Base62, Bitcoin, Encoded};
+    use std::convert::TryInto;
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Fields {
+        array: Encoded<Bitcoin, [u8; 32]>,
+        vec: Encoded<Base62, Vec<u8>>,
+    }
+
+    let input = cases::input(32);
+    let fields = Fields {
+        array: Encoded::new(input.clone().try_into().unwrap()),
+        vec: input.clone().into(),
+    };
+    let json = serde_json::to_string(&fields).unwrap();
+    assert_eq!(
+        format!(
+            r#"{{"array":"{}","vec":"{}"}}"#,
+            bsx::encode(&input)
+                .with_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .into_string(),
+            bsx::encode(&input)
+                .with_alphabet(bsx::StaticAlphabet::BASE62)
+                .into_string(),
+        ),
+        json
+    );
+    let decoded: Fields = serde_json::from_str(&json).unwrap();
+    assert_eq!(fields, decoded);
+    assert_eq!(input, *decoded.vec);
+    assert_eq!(&input[..], &decoded.array[..]);
+    assert_eq!(
+        bsx::encode(&input)
+            .with_alphabet(bsx::StaticAlphabet::BASE62)
+            .into_string(),
+        decoded.vec.to_string()
+    );
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "sqids")]
+
+use bsx::sqids::{Error, Sqids};
+
+#[test]
+fn test_vectors() {
+    let sqids = Sqids::new();
+    for (numbers, id) in [
+        (&[1, 2, 3][..], "86Rf07"),
+        (&[0], "bM"),
+        (&[u64::MAX], "eIkvoXH40Lmd"),
+        (&[], ""),
+    ] {
+        assert_eq!(id, sqids.encode(numbers).unwrap());
+        assert_eq!(numbers, sqids.decode(id).unwrap());
+    }
+
+    let sqids = Sqids::new().with_blocklist(["86Rf07", "SE8OJK"]);
+    assert_eq!("ARsz1p", sqids.encode([1, 2, 3]).unwrap());
+    assert_eq!(vec![1, 2, 3], sqids.decode("ARsz1p").unwrap());
+}
+
+#[test]
+fn test_roundtrip() {
+    let numbers: Vec<u64> = (0..100).map(|i| i * 7919 % 251 * (1 << (i % 50))).collect();
+    for min_length in [0, 10, 100, 255] {
+        let sqids = Sqids::new().with_min_length(min_length);
+        for len in [1, 2, 10, 100] {
+            let id = sqids.encode(&numbers[..len]).unwrap();
+            assert!(id.len() >= min_length.into());
+            assert_eq!(&numbers[..len], sqids.decode(&id).unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_errors() {
+    let sqids = Sqids::new();
+    assert_eq!(
+        Error::NonAsciiCharacter { index: 1 },
+        sqids.decode("8é").unwrap_err()
+    );
+    assert_eq!(
+        Error::Alphabet(bsx::alphabet::Error::NonAsciiCharacter { index: 1 }),
+        Sqids::new().with_alphabet("aéb").unwrap_err()
+    );
+}
@@ -0,0 +1,211 @@
+#![cfg(feature = "std")]
+
+mod cases;
+
+use std::io::{self, Read};
+
+/// A reader returning a few bytes at a time, to check units split across reads.
+struct Trickle<'a>(&'a [u8]);
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.0.len().min(buf.len()).min(3);
+        buf[..len].copy_from_slice(&self.0[..len]);
+        self.0 = &self.0[len..];
+        Ok(len)
+    }
+}
+
+#[test]
+fn test_encode_reader() {
+    let base2 = bsx::StaticAlphabet::new(b"01").unwrap();
+    let base4 = bsx::StaticAlphabet::new(b"0123").unwrap();
+    let base8 = bsx::StaticAlphabet::new(b"01234567").unwrap();
+    let base128: Vec<u8> = (0..128).collect();
+    let base128 = bsx::DynamicAlphabet::new(&base128[..]).unwrap();
+    let alphabets: [&dyn bsx::Alphabet; 7] = [
+        &base2,
+        &base4,
+        &base8,
+        bsx::StaticAlphabet::BASE16,
+        bsx::StaticAlphabet::ZBASE32,
+        bsx::StaticAlphabet::BASE64,
+        &base128,
+    ];
+
+    for &alpha in &alphabets {
+        for zeros in 0..3 {
+            for len in [0, 1, 2, 3, 5, 6, 7, 13, 100, 20000] {
+                let mut input = vec![0; zeros];
+                input.extend(cases::input(len));
+                let expected = bsx::encode(&input).with_alphabet(alpha).into_vec();
+
+                let mut output = Vec::new();
+                let written = bsx::encode_reader(Trickle(&input))
+                    .with_alphabet(alpha)
+                    .with_input_len(input.len() as u64)
+                    .into_writer(&mut output)
+                    .unwrap();
+                assert_eq!(expected, output, "{} {} {}", alpha.len(), zeros, len);
+                assert_eq!(expected.len() as u64, written);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_encode_reader_errors() {
+    let input = [1, 2, 3, 4];
+    let run = |alpha: &dyn bsx::Alphabet, len: Option<u64>| {
+        let builder = bsx::encode_reader(&input[..]).with_alphabet(alpha);
+        let builder = match len {
+            Some(len) => builder.with_input_len(len),
+            None => builder,
+        };
+        builder.into_writer(io::sink()).map_err(|e| e.kind())
+    };
+
+    assert_eq!(Ok(7), run(bsx::StaticAlphabet::BASE16, None));
+    assert_eq!(
+        Err(io::ErrorKind::InvalidInput),
+        run(bsx::StaticAlphabet::BITCOIN, Some(4))
+    );
+    assert_eq!(
+        Err(io::ErrorKind::InvalidInput),
+        run(bsx::StaticAlphabet::ZBASE32, None)
+    );
+    assert_eq!(
+        Err(io::ErrorKind::InvalidData),
+        run(bsx::StaticAlphabet::ZBASE32, Some(3))
+    );
+    assert_eq!(
+        Err(io::ErrorKind::UnexpectedEof),
+        run(bsx::StaticAlphabet::ZBASE32, Some(5))
+    );
+    // Bit-packed alphabets are aligned from the start, so don't need the length
+    assert_eq!(Ok(6), run(bsx::StaticAlphabet::BASE64, None));
+}
+
+#[test]
+fn test_encode_reader_builder_clone_debug() {
+    let builder = bsx::encode_reader(&[0xde, 0xad][..])
+        .with_alphabet(bsx::StaticAlphabet::BASE16)
+        .with_input_len(2);
+    let debug = format!("{:?}", builder);
+    assert!(debug.starts_with("EncodeReaderBuilder {"), "{}", debug);
+    assert!(debug.contains("len: Some(2)"), "{}", debug);
+
+    let mut output = Vec::new();
+    builder.clone().into_writer(&mut output).unwrap();
+    assert_eq!(b"dead", &output[..]);
+    output.clear();
+    builder.into_writer(&mut output).unwrap();
+    assert_eq!(b"dead", &output[..]);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn test_async_roundtrip() {
+    use bsx::stream::{DecodeReader, DecodeWriter, EncodeReader, EncodeWriter};
+    use futures::{
+        executor::block_on,
+        io::{AsyncReadExt, AsyncWriteExt},
+    };
+
+    let base8 = bsx::StaticAlphabet::new(b"01234567").unwrap();
+    let alphabets: [&dyn bsx::Alphabet; 4] = [
+        &base8,
+        bsx::StaticAlphabet::BASE16,
+        bsx::StaticAlphabet::ZBASE32,
+        bsx::StaticAlphabet::BASE64,
+    ];
+
+    block_on(async {
+        for &alpha in &alphabets {
+            for zeros in 0..3 {
+                for len in [0, 1, 2, 5, 7, 100, 20000] {
+                    let mut input = vec![0; zeros];
+                    input.extend(cases::input(len));
+                    let expected = bsx::encode(&input).with_alphabet(alpha).into_vec();
+                    let (input_len, encoded_len) = (input.len() as u64, expected.len() as u64);
+
+                    let mut writer = EncodeWriter::with_len(Vec::new(), alpha, input_len).unwrap();
+                    for chunk in input.chunks(3) {
+                        writer.write_all(chunk).await.unwrap();
+                    }
+                    writer.close().await.unwrap();
+                    assert_eq!(expected, writer.into_inner());
+
+                    let mut reader = EncodeReader::with_len(&input[..], alpha, input_len).unwrap();
+                    let mut output = Vec::new();
+                    reader.read_to_end(&mut output).await.unwrap();
+                    assert_eq!(expected, output);
+
+                    let mut writer =
+                        DecodeWriter::with_len(Vec::new(), alpha, encoded_len).unwrap();
+                    for chunk in expected.chunks(3) {
+                        writer.write_all(chunk).await.unwrap();
+                    }
+                    writer.close().await.unwrap();
+                    assert_eq!(input, writer.into_inner());
+
+                    let mut reader =
+                        DecodeReader::with_len(&expected[..], alpha, encoded_len).unwrap();
+                    let mut output = Vec::new();
+                    reader.read_to_end(&mut output).await.unwrap();
+                    assert_eq!(input, output);
+                }
+            }
+        }
+    });
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn test_async_errors() {
+    use bsx::stream::{DecodeReader, EncodeWriter};
+    use futures::{
+        executor::block_on,
+        io::{AsyncReadExt, AsyncWriteExt},
+    };
+
+    assert_eq!(
+        io::ErrorKind::InvalidInput,
+        DecodeReader::new(&b"00"[..], bsx::StaticAlphabet::BASE16)
+            .err()
+            .unwrap()
+            .kind()
+    );
+    assert_eq!(
+        io::ErrorKind::InvalidInput,
+        EncodeWriter::new(Vec::new(), bsx::StaticAlphabet::ZBASE32)
+            .err()
+            .unwrap()
+            .kind()
+    );
+
+    block_on(async {
+        let mut reader =
+            DecodeReader::with_len(&b"00fg"[..], bsx::StaticAlphabet::BASE16, 4).unwrap();
+        let err = reader.read_to_end(&mut Vec::new()).await.unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, err.kind());
+        let err = err
+            .into_inner()
+            .unwrap()
+            .downcast::<bsx::decode::Error>()
+            .unwrap();
+        assert_eq!(
+            bsx::decode::Error::InvalidCharacter {
+                character: 'g',
+                index: 3
+            },
+            *err
+        );
+
+        let mut writer =
+            EncodeWriter::with_len(Vec::new(), bsx::StaticAlphabet::BASE64, 4).unwrap();
+        writer.write_all(&[1, 2, 3]).await.unwrap();
+        let err = writer.close().await.unwrap_err();
+        assert_eq!(io::ErrorKind::UnexpectedEof, err.kind());
+    });
+}
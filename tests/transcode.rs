@@ -0,0 +1,150 @@
+mod cases;
+
+const ALPHABETS: &[&dyn bsx::Alphabet] = &[
+    bsx::StaticAlphabet::BITCOIN,
+    bsx::StaticAlphabet::RIPPLE,
+    bsx::StaticAlphabet::FLICKR,
+    bsx::multibase::Base::Base10.alphabet(),
+    bsx::multibase::Base::Base36.alphabet(),
+];
+
+#[test]
+fn test_transcode() {
+    for &(val, _) in cases::TEST_CASES.iter() {
+        for &from in ALPHABETS {
+            for &to in ALPHABETS {
+                let input = bsx::encode(val).with_alphabet(from).into_string();
+                assert_eq!(
+                    bsx::encode(val).with_alphabet(to).into_string(),
+                    bsx::transcode(&input)
+                        .from_alphabet(from)
+                        .to_alphabet(to)
+                        .into_string()
+                        .unwrap()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_transcode_long() {
+    let chars: Vec<u8> = (0..255).collect();
+    let binary = bsx::DynamicAlphabet::new("01").unwrap();
+    let extended = bsx::DynamicAlphabet::new_extended(&chars[..]).unwrap();
+    let alphabets: [&dyn bsx::Alphabet; 5] = [
+        &binary,
+        bsx::multibase::Base::Base10.alphabet(),
+        bsx::StaticAlphabet::BITCOIN,
+        bsx::StaticAlphabet::BASE91,
+        &extended,
+    ];
+
+    // Multiple limbs on both sides, with a partial final group of source digits
+    for len in [17, 100, 1000] {
+        let mut input = cases::input(len);
+        input[0] = 0;
+        for &from in &alphabets {
+            for &to in &alphabets {
+                let encoded = bsx::encode(&input).with_alphabet(from).into_vec();
+                assert_eq!(
+                    bsx::encode(&input).with_alphabet(to).into_vec(),
+                    bsx::transcode(&encoded)
+                        .from_alphabet(from)
+                        .to_alphabet(to)
+                        .into_vec()
+                        .unwrap(),
+                    "len {} from {} to {}",
+                    len,
+                    from.len(),
+                    to.len(),
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_transcode_small_buffer_err() {
+    let mut output = [0; 3];
+    assert_eq!(
+        bsx::transcode("a3gV")
+            .from_alphabet(bsx::StaticAlphabet::BITCOIN)
+            .to_alphabet(bsx::StaticAlphabet::FLICKR)
+            .into(&mut output[..]),
+        Err(bsx::transcode::Error::BufferTooSmall { required: 4 })
+    );
+}
+
+#[test]
+fn test_translate() {
+    for &(val, _) in cases::TEST_CASES.iter() {
+        let input = bsx::encode(val)
+            .with_alphabet(bsx::StaticAlphabet::RIPPLE)
+            .into_string();
+        assert_eq!(
+            bsx::encode(val)
+                .with_alphabet(bsx::StaticAlphabet::FLICKR)
+                .into_string(),
+            bsx::translate(&input)
+                .from(bsx::StaticAlphabet::RIPPLE)
+                .to(bsx::StaticAlphabet::FLICKR)
+                .into_string()
+                .unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_transcode_builder_clone_debug() {
+    let builder = bsx::transcode("he11owor1d")
+        .from_alphabet(bsx::StaticAlphabet::BITCOIN)
+        .to_alphabet(bsx::StaticAlphabet::FLICKR);
+    let debug = format!("{:?}", builder);
+    assert!(debug.starts_with("TranscodeBuilder {"), "{}", debug);
+    assert!(debug.contains("integer: false"), "{}", debug);
+    assert_eq!(
+        builder.clone().into_string().unwrap(),
+        builder.into_string().unwrap()
+    );
+
+    let builder = bsx::translate("he11owor1d")
+        .from(bsx::StaticAlphabet::BITCOIN)
+        .to(bsx::StaticAlphabet::FLICKR);
+    let debug = format!("{:?}", builder);
+    assert!(debug.starts_with("TranslateBuilder {"), "{}", debug);
+    assert_eq!(
+        builder.clone().into_string().unwrap(),
+        builder.into_string().unwrap()
+    );
+}
+
+#[test]
+fn test_transcode_integer() {
+    let decimal = bsx::multibase::Base::Base10;
+    for &(val, s) in &[
+        (0u64, "1"),
+        (57, "z"),
+        (58, "21"),
+        (u64::MAX, "jpXCZedGfVQ"),
+    ] {
+        assert_eq!(
+            s,
+            bsx::transcode(format!("000{}", val))
+                .from_alphabet(decimal)
+                .to_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .as_integer()
+                .into_string()
+                .unwrap()
+        );
+        assert_eq!(
+            val.to_string(),
+            bsx::transcode(s)
+                .from_alphabet(bsx::StaticAlphabet::BITCOIN)
+                .to_alphabet(decimal)
+                .as_integer()
+                .into_string()
+                .unwrap()
+        );
+    }
+}